@@ -0,0 +1,13 @@
+CREATE TABLE items_media (
+    id BIGSERIAL PRIMARY KEY,
+    item_id BIGINT NOT NULL,
+    url VARCHAR NOT NULL,
+    path VARCHAR,
+    alt_text VARCHAR NOT NULL DEFAULT '',
+    position INTEGER NOT NULL DEFAULT 0,
+    width INTEGER,
+    height INTEGER,
+    FOREIGN KEY (item_id) REFERENCES items(id) ON DELETE CASCADE
+);
+
+CREATE INDEX items_media_item_id_position_idx ON items_media (item_id, position);
@@ -0,0 +1,2 @@
+DROP TABLE item_price_schedules;
+DROP TABLE item_price_history;
@@ -0,0 +1,28 @@
+CREATE TABLE item_price_history (
+    id BIGSERIAL PRIMARY KEY,
+    item_id BIGINT NOT NULL,
+    price DOUBLE PRECISION NOT NULL,
+    valid_from TIMESTAMPTZ NOT NULL,
+    valid_to TIMESTAMPTZ,
+    FOREIGN KEY (item_id) REFERENCES items(id) ON DELETE CASCADE
+);
+
+CREATE INDEX item_price_history_item_id_valid_from_idx ON item_price_history (item_id, valid_from);
+CREATE UNIQUE INDEX item_price_history_open_idx ON item_price_history (item_id) WHERE valid_to IS NULL;
+
+CREATE TABLE item_price_schedules (
+    id BIGSERIAL PRIMARY KEY,
+    item_id BIGINT NOT NULL,
+    price DOUBLE PRECISION NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    applied_at TIMESTAMPTZ,
+    -- A schedule that comes due after a newer manual price is skipped, not applied.
+    superseded_at TIMESTAMPTZ,
+    FOREIGN KEY (item_id) REFERENCES items(id) ON DELETE CASCADE
+);
+
+CREATE INDEX item_price_schedules_pending_idx ON item_price_schedules (starts_at)
+    WHERE applied_at IS NULL AND superseded_at IS NULL;
+
+INSERT INTO item_price_history (item_id, price, valid_from)
+SELECT id, price, NOW() FROM items;
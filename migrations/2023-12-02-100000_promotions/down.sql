@@ -0,0 +1 @@
+DROP VIEW item_size_effective_prices;
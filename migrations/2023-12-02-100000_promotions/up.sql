@@ -0,0 +1,86 @@
+ALTER TABLE items ADD COLUMN category VARCHAR;
+
+CREATE TABLE promotions (
+    id BIGSERIAL PRIMARY KEY,
+    name VARCHAR NOT NULL,
+    kind VARCHAR NOT NULL CHECK (kind IN ('percentage', 'fixed')),
+    value DOUBLE PRECISION NOT NULL CHECK (value >= 0),
+    target VARCHAR NOT NULL CHECK (target IN ('item', 'category', 'size')),
+    item_id BIGINT REFERENCES items(id) ON DELETE CASCADE,
+    category VARCHAR,
+    size_id INTEGER REFERENCES sizes(id) ON DELETE CASCADE,
+    starts_at TIMESTAMPTZ,
+    ends_at TIMESTAMPTZ,
+    priority INTEGER NOT NULL DEFAULT 0,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    CHECK (
+        (target = 'item' AND item_id IS NOT NULL)
+        OR (target = 'category' AND category IS NOT NULL)
+        OR (target = 'size' AND size_id IS NOT NULL)
+    )
+);
+
+-- The single place where discount rules are evaluated: the applicable
+-- promotion with the highest priority wins, ties go to the lowest price.
+-- Size promotions only apply to the size, see item_size_effective_prices.
+CREATE VIEW item_effective_prices AS
+SELECT i.id AS item_id,
+       i.price AS price,
+       COALESCE(best.sale_price, i.price) AS sale_price,
+       best.promotion_id AS promotion_id
+FROM items i
+LEFT JOIN LATERAL (
+    SELECT p.id AS promotion_id,
+           GREATEST(
+               0,
+               CASE p.kind
+                   WHEN 'percentage' THEN i.price * (1 - p.value / 100)
+                   ELSE i.price - p.value
+               END
+           ) AS sale_price
+    FROM promotions p
+    WHERE p.active
+      AND (p.starts_at IS NULL OR p.starts_at <= NOW())
+      AND (p.ends_at IS NULL OR p.ends_at > NOW())
+      AND (
+          (p.target = 'item' AND p.item_id = i.id)
+          OR (p.target = 'category' AND p.category = i.category)
+      )
+    ORDER BY p.priority DESC, sale_price ASC, p.id ASC
+    LIMIT 1
+) best ON TRUE;
+
+-- Price of an item in each size an active size promotion targets, with the
+-- same rules plus the promotions of the size. Other sizes sell at the
+-- item_effective_prices price.
+CREATE VIEW item_size_effective_prices AS
+SELECT i.id AS item_id,
+       s.id AS size_id,
+       s.name AS size,
+       i.price AS price,
+       COALESCE(best.sale_price, i.price) AS sale_price,
+       best.promotion_id AS promotion_id
+FROM items i
+CROSS JOIN sizes s
+LEFT JOIN LATERAL (
+    SELECT p.id AS promotion_id,
+           GREATEST(
+               0,
+               CASE p.kind
+                   WHEN 'percentage' THEN i.price * (1 - p.value / 100)
+                   ELSE i.price - p.value
+               END
+           ) AS sale_price
+    FROM promotions p
+    WHERE p.active
+      AND (p.starts_at IS NULL OR p.starts_at <= NOW())
+      AND (p.ends_at IS NULL OR p.ends_at > NOW())
+      AND (
+          (p.target = 'item' AND p.item_id = i.id)
+          OR (p.target = 'category' AND p.category = i.category)
+          OR (p.target = 'size' AND p.size_id = s.id)
+      )
+    ORDER BY p.priority DESC, sale_price ASC, p.id ASC
+    LIMIT 1
+) best ON TRUE
+WHERE s.id IN (SELECT size_id FROM promotions WHERE target = 'size' AND active);
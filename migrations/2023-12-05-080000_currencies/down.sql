@@ -0,0 +1,4 @@
+DROP FUNCTION item_sale_price_in(BIGINT, VARCHAR, VARCHAR);
+DROP FUNCTION item_price_in(BIGINT, DOUBLE PRECISION, VARCHAR, VARCHAR);
+DROP TABLE item_currency_prices;
+DROP TABLE exchange_rates;
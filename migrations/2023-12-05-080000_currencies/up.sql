@@ -0,0 +1,38 @@
+-- Units of `currency` for one unit of the base currency `items.price` is in.
+CREATE TABLE exchange_rates (
+    currency VARCHAR(3) PRIMARY KEY,
+    rate DOUBLE PRECISION NOT NULL CHECK (rate > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Explicit per-currency prices; they take precedence over conversion. Price
+-- lists are per currency and region; '' holds the price for every region,
+-- which a regional price takes precedence over.
+CREATE TABLE item_currency_prices (
+    item_id BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    currency VARCHAR(3) NOT NULL,
+    region VARCHAR(8) NOT NULL DEFAULT '',
+    price DOUBLE PRECISION NOT NULL CHECK (price >= 0),
+    PRIMARY KEY (item_id, currency, region)
+);
+
+CREATE FUNCTION item_price_in(_item_id BIGINT, _base_price DOUBLE PRECISION, _currency VARCHAR, _region VARCHAR)
+RETURNS DOUBLE PRECISION AS $$
+    SELECT COALESCE(
+        (SELECT p.price FROM item_currency_prices p
+         WHERE p.item_id = _item_id AND p.currency = _currency AND p.region IN (COALESCE(_region, ''), '')
+         ORDER BY p.region = '' LIMIT 1),
+        _base_price * (SELECT r.rate FROM exchange_rates r WHERE r.currency = _currency)
+    )
+$$ LANGUAGE SQL STABLE;
+
+-- Keeps the discount ratio of the winning promotion in the target currency.
+CREATE FUNCTION item_sale_price_in(_item_id BIGINT, _currency VARCHAR, _region VARCHAR)
+RETURNS DOUBLE PRECISION AS $$
+    SELECT CASE
+        WHEN e.price > 0 THEN item_price_in(e.item_id, e.price, _currency, _region) * e.sale_price / e.price
+        ELSE item_price_in(e.item_id, e.price, _currency, _region)
+    END
+    FROM item_effective_prices e
+    WHERE e.item_id = _item_id
+$$ LANGUAGE SQL STABLE;
@@ -0,0 +1,3 @@
+ALTER TABLE items DROP COLUMN tax_class_id;
+DROP TABLE tax_rates;
+DROP TABLE tax_classes;
@@ -0,0 +1,14 @@
+CREATE TABLE tax_classes (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR NOT NULL UNIQUE
+);
+
+-- Percentages applied on top of net item prices.
+CREATE TABLE tax_rates (
+    tax_class_id INTEGER NOT NULL REFERENCES tax_classes(id) ON DELETE CASCADE,
+    region VARCHAR(8) NOT NULL,
+    rate DOUBLE PRECISION NOT NULL CHECK (rate >= 0 AND rate <= 100),
+    PRIMARY KEY (tax_class_id, region)
+);
+
+ALTER TABLE items ADD COLUMN tax_class_id INTEGER REFERENCES tax_classes(id) ON DELETE SET NULL;
@@ -0,0 +1,4 @@
+DROP TRIGGER warehouse_stock_sync_items_sizes ON warehouse_stock;
+DROP FUNCTION sync_items_sizes_quantity();
+DROP TABLE warehouse_stock;
+DROP TABLE warehouses;
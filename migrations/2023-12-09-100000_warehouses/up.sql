@@ -0,0 +1,61 @@
+CREATE TABLE warehouses (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR NOT NULL UNIQUE,
+    is_default BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+-- Receives the stock of items created without an explicit warehouse.
+CREATE UNIQUE INDEX warehouses_single_default ON warehouses (is_default) WHERE is_default;
+
+CREATE TABLE warehouse_stock (
+    warehouse_id INTEGER NOT NULL REFERENCES warehouses(id) ON DELETE CASCADE,
+    item_id BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    size_id INTEGER NOT NULL REFERENCES sizes(id) ON DELETE CASCADE,
+    quantity INTEGER NOT NULL CHECK (quantity >= 0),
+    PRIMARY KEY (warehouse_id, item_id, size_id)
+);
+
+CREATE INDEX warehouse_stock_item_id ON warehouse_stock (item_id);
+
+INSERT INTO warehouses (name, is_default) VALUES ('default', TRUE);
+
+INSERT INTO warehouse_stock (warehouse_id, item_id, size_id, quantity)
+SELECT w.id, s.item_id, s.size_id, GREATEST(SUM(s.quantity), 0)
+FROM items_sizes s CROSS JOIN warehouses w
+WHERE w.is_default
+GROUP BY w.id, s.item_id, s.size_id;
+
+-- items_sizes.quantity is kept as the total over all warehouses.
+CREATE FUNCTION sync_items_sizes_quantity() RETURNS TRIGGER AS $$
+DECLARE
+    _item_id BIGINT;
+    _size_id INTEGER;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        _item_id := OLD.item_id;
+        _size_id := OLD.size_id;
+    ELSE
+        _item_id := NEW.item_id;
+        _size_id := NEW.size_id;
+
+        INSERT INTO items_sizes (item_id, size_id, quantity)
+        SELECT _item_id, _size_id, 0
+        WHERE NOT EXISTS (
+            SELECT 1 FROM items_sizes WHERE item_id = _item_id AND size_id = _size_id
+        );
+    END IF;
+
+    UPDATE items_sizes
+    SET quantity = (
+        SELECT COALESCE(SUM(quantity), 0) FROM warehouse_stock
+        WHERE item_id = _item_id AND size_id = _size_id
+    )
+    WHERE item_id = _item_id AND size_id = _size_id;
+
+    RETURN NULL;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER warehouse_stock_sync_items_sizes
+AFTER INSERT OR UPDATE OR DELETE ON warehouse_stock
+FOR EACH ROW EXECUTE FUNCTION sync_items_sizes_quantity();
@@ -0,0 +1,2 @@
+DROP TABLE stock_movements;
+DROP FUNCTION reject_stock_movement_change();
@@ -0,0 +1,30 @@
+-- Append-only ledger of warehouse_stock changes, written in the same transaction.
+CREATE TABLE stock_movements (
+    id BIGSERIAL PRIMARY KEY,
+    warehouse_id INTEGER NOT NULL REFERENCES warehouses(id),
+    item_id BIGINT NOT NULL REFERENCES items(id),
+    size_id INTEGER NOT NULL REFERENCES sizes(id),
+    delta INTEGER NOT NULL,
+    quantity_after INTEGER NOT NULL,
+    reason VARCHAR NOT NULL CHECK (reason IN ('receipt', 'sale', 'reservation', 'adjustment', 'return')),
+    actor VARCHAR NOT NULL,
+    note VARCHAR,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX stock_movements_item_size ON stock_movements (item_id, size_id, id);
+
+CREATE FUNCTION reject_stock_movement_change() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'stock_movements is append-only';
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER stock_movements_append_only
+BEFORE UPDATE OR DELETE ON stock_movements
+FOR EACH ROW EXECUTE FUNCTION reject_stock_movement_change();
+
+INSERT INTO stock_movements (warehouse_id, item_id, size_id, delta, quantity_after, reason, actor, note)
+SELECT warehouse_id, item_id, size_id, quantity, quantity, 'adjustment', 'system', 'opening balance'
+FROM warehouse_stock
+WHERE quantity <> 0;
@@ -0,0 +1,7 @@
+-- Reorder threshold of an item size, compared against its total stock.
+CREATE TABLE stock_thresholds (
+    item_id BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    size_id INTEGER NOT NULL REFERENCES sizes(id),
+    threshold INTEGER NOT NULL CHECK (threshold >= 0),
+    PRIMARY KEY (item_id, size_id)
+);
@@ -0,0 +1,12 @@
+-- How an item size may be sold beyond its stock. Sizes without a rule are
+-- only sold from stock.
+CREATE TABLE backorder_rules (
+    item_id BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    size_id INTEGER NOT NULL REFERENCES sizes(id),
+    policy VARCHAR NOT NULL CHECK (policy IN ('deny', 'backorder', 'preorder')),
+    backorder_limit INTEGER CHECK (backorder_limit >= 0),
+    expected_date DATE,
+    backordered INTEGER NOT NULL DEFAULT 0 CHECK (backordered >= 0),
+    PRIMARY KEY (item_id, size_id),
+    CHECK (policy <> 'preorder' OR expected_date IS NOT NULL)
+);
@@ -0,0 +1,13 @@
+-- A bundle size is sold as a kit of other item sizes; its stock follows the
+-- stock of its components instead of being kept on its own.
+CREATE TABLE bundle_components (
+    bundle_id BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    bundle_size_id INTEGER NOT NULL REFERENCES sizes(id),
+    item_id BIGINT NOT NULL REFERENCES items(id),
+    size_id INTEGER NOT NULL REFERENCES sizes(id),
+    quantity INTEGER NOT NULL CHECK (quantity > 0),
+    PRIMARY KEY (bundle_id, bundle_size_id, item_id, size_id),
+    CHECK (bundle_id <> item_id)
+);
+
+CREATE INDEX bundle_components_item_idx ON bundle_components (item_id, size_id);
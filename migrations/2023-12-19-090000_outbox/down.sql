@@ -0,0 +1,2 @@
+DROP FUNCTION outbox_settled_txid();
+DROP TABLE outbox_events;
@@ -0,0 +1,28 @@
+-- Item change events, written in the same transaction as the change and
+-- relayed to the publisher afterwards. Rows outlive their item on purpose.
+-- Every replica runs the relay; an event is only published by the one that
+-- claimed it, until the claim runs out.
+CREATE TABLE outbox_events (
+    id BIGSERIAL PRIMARY KEY,
+    event_type VARCHAR NOT NULL,
+    item_id BIGINT NOT NULL,
+    payload JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    delivered_at TIMESTAMPTZ,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error VARCHAR,
+    claimed_until TIMESTAMPTZ,
+    -- The change feed follows events in the order their transactions started,
+    -- and only once every transaction started before them has ended; ids are
+    -- taken before commit, so they can become visible out of order.
+    txid BIGINT NOT NULL DEFAULT txid_current()
+);
+
+CREATE INDEX outbox_events_pending_idx ON outbox_events (id) WHERE delivered_at IS NULL;
+CREATE INDEX outbox_events_txid_idx ON outbox_events (txid, id);
+
+-- Oldest transaction still running; every one before it has committed or
+-- rolled back. Held back by any long transaction on the server.
+CREATE FUNCTION outbox_settled_txid() RETURNS BIGINT AS $$
+    SELECT txid_snapshot_xmin(txid_current_snapshot());
+$$ LANGUAGE SQL STABLE;
@@ -0,0 +1,11 @@
+-- Service-wide switches shared by every replica; there is only ever the
+-- row with id 1.
+CREATE TABLE service_contexts (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    maintenance BOOLEAN NOT NULL DEFAULT FALSE,
+    maintenance_scope VARCHAR NOT NULL DEFAULT 'writes' CHECK (maintenance_scope IN ('writes', 'all')),
+    retry_after_secs INTEGER NOT NULL DEFAULT 300 CHECK (retry_after_secs > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+INSERT INTO service_contexts (id) VALUES (1);
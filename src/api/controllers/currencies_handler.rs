@@ -0,0 +1,183 @@
+use crate::api::dto::currencies::{
+    ExchangeRateDTO, PriceListEntryDTO, PriceListQueryDTO, SetExchangeRateRequestDTO,
+    SetPriceListPriceRequestDTO,
+};
+use crate::domain::error::ApiError;
+use crate::domain::models::currencies::PriceListEntry;
+use crate::domain::services::currencies::CurrencyService;
+use actix_web::{web, HttpResponse, Result};
+
+pub async fn get_exchange_rates(
+    currency_service: web::Data<dyn CurrencyService>,
+) -> Result<web::Json<Vec<ExchangeRateDTO>>, ApiError> {
+    let rates = currency_service.get_exchange_rates().await?;
+
+    Ok(web::Json(rates.into_iter().map(|r| r.into()).collect()))
+}
+
+pub async fn set_exchange_rate(
+    currency_service: web::Data<dyn CurrencyService>,
+    currency: web::Path<String>,
+    data: web::Json<SetExchangeRateRequestDTO>,
+) -> Result<web::Json<ExchangeRateDTO>, ApiError> {
+    let rate = currency_service
+        .set_exchange_rate(currency.into_inner(), data.rate)
+        .await?;
+
+    Ok(web::Json(rate.into()))
+}
+
+pub async fn get_price_list(
+    currency_service: web::Data<dyn CurrencyService>,
+    currency: web::Path<String>,
+    params: web::Query<PriceListQueryDTO>,
+) -> Result<web::Json<Vec<PriceListEntryDTO>>, ApiError> {
+    let prices = currency_service
+        .get_price_list(currency.into_inner(), params.into_inner().region)
+        .await?;
+
+    Ok(web::Json(prices.into_iter().map(|p| p.into()).collect()))
+}
+
+pub async fn set_price_list_price(
+    currency_service: web::Data<dyn CurrencyService>,
+    path: web::Path<(String, i64)>,
+    params: web::Query<PriceListQueryDTO>,
+    data: web::Json<SetPriceListPriceRequestDTO>,
+) -> Result<web::Json<PriceListEntryDTO>, ApiError> {
+    let (currency, item_id) = path.into_inner();
+
+    let entry = currency_service
+        .set_price_list_price(PriceListEntry {
+            item_id,
+            currency,
+            region: params.into_inner().region,
+            price: data.price,
+        })
+        .await?;
+
+    Ok(web::Json(entry.into()))
+}
+
+pub async fn delete_price_list_price(
+    currency_service: web::Data<dyn CurrencyService>,
+    path: web::Path<(String, i64)>,
+    params: web::Query<PriceListQueryDTO>,
+) -> Result<HttpResponse, ApiError> {
+    let (currency, item_id) = path.into_inner();
+
+    currency_service
+        .delete_price_list_price(currency, params.into_inner().region, item_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::currencies_handler::{delete_price_list_price, set_price_list_price};
+    use crate::api::dto::currencies::PriceListEntryDTO;
+    use crate::domain::error::{CommonError, CommonErrorKind};
+    use crate::domain::models::currencies::PriceListEntry;
+    use crate::domain::services::currencies::{CurrencyService, MockCurrencyService};
+    use actix_web;
+    use actix_web::http::StatusCode;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_set_price_list_price() {
+        let _ = env_logger::try_init();
+
+        let mut mock_currencies = MockCurrencyService::new();
+
+        mock_currencies.expect_set_price_list_price().returning(|entry| -> _ {
+            assert_eq!(entry.item_id, 3);
+            assert_eq!(entry.currency, "eur");
+            assert_eq!(entry.region, Some("at".to_string()));
+            assert_eq!(entry.price, 9.5);
+
+            Box::pin(async move {
+                Ok(PriceListEntry {
+                    currency: "EUR".to_string(),
+                    region: Some("AT".to_string()),
+                    ..entry
+                })
+            })
+        });
+
+        let currency_service: Arc<dyn CurrencyService> = Arc::new(mock_currencies);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(currency_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/price-lists/{currency}/items/{id}",
+                    web::put().to(set_price_list_price),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/price-lists/eur/items/3?region=at")
+            .set_json(serde_json::json!({ "price": 9.5 }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: PriceListEntryDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(
+            resp_model,
+            PriceListEntryDTO {
+                item_id: 3,
+                currency: "EUR".to_string(),
+                region: Some("AT".to_string()),
+                price: 9.5,
+            }
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_not_delete_missing_price_list_price() {
+        let _ = env_logger::try_init();
+
+        let mut mock_currencies = MockCurrencyService::new();
+
+        mock_currencies
+            .expect_delete_price_list_price()
+            .returning(|_, _, _| -> _ {
+                Box::pin(async move {
+                    Err(CommonError {
+                        message: "x".to_string(),
+                        code: CommonErrorKind::NotFound,
+                    })
+                })
+            });
+
+        let currency_service: Arc<dyn CurrencyService> = Arc::new(mock_currencies);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(currency_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/price-lists/{currency}/items/{id}",
+                    web::delete().to(delete_price_list_price),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::delete()
+            .uri("/price-lists/EUR/items/3")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::GONE);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::api::dto::events::{sse_message, ItemEventsQueryDTO};
+use crate::domain::constants::{
+    ITEM_EVENTS_KEEP_ALIVE_SECS, ITEM_EVENTS_POLL_INTERVAL_MS, LAST_EVENT_ID_HEADER,
+};
+use crate::domain::error::{ApiError, CommonError, CommonErrorKind};
+use crate::domain::services::events::EventService;
+use actix_web::http::header;
+use actix_web::web::Bytes;
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use futures_util::stream;
+use log::error;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct Feed {
+    event_service: Arc<dyn EventService>,
+    item_ids: Vec<i64>,
+    last_id: i64,
+}
+
+/// Waits for the next events after `last_id`. Yields a keep-alive comment
+/// when nothing happens for a while and ends the stream on errors; the client
+/// then reconnects with its `Last-Event-ID`.
+async fn next_chunk(mut feed: Feed) -> Option<(Result<Bytes, Infallible>, Feed)> {
+    let poll = Duration::from_millis(ITEM_EVENTS_POLL_INTERVAL_MS);
+    let keep_alive = Duration::from_secs(ITEM_EVENTS_KEEP_ALIVE_SECS);
+    let mut idle = Duration::ZERO;
+
+    loop {
+        let events = match feed
+            .event_service
+            .get_events_after(feed.last_id, feed.item_ids.clone())
+            .await
+        {
+            Ok(events) => events,
+            Err(e) => {
+                error!("failed to read item events after {}: {}", feed.last_id, e);
+                return None;
+            }
+        };
+
+        if let Some(last) = events.last() {
+            feed.last_id = last.id;
+            let chunk: String = events.iter().map(sse_message).collect();
+
+            return Some((Ok(Bytes::from(chunk)), feed));
+        }
+
+        if idle >= keep_alive {
+            return Some((Ok(Bytes::from_static(b": keep-alive\n\n")), feed));
+        }
+
+        actix_web::rt::time::sleep(poll).await;
+        idle += poll;
+    }
+}
+
+pub async fn get_item_events(
+    req: HttpRequest,
+    event_service: web::Data<dyn EventService>,
+    params: web::Query<ItemEventsQueryDTO>,
+) -> Result<HttpResponse, ApiError> {
+    let item_ids = params.item_ids()?;
+    let last_event_id = req
+        .headers()
+        .get(LAST_EVENT_ID_HEADER)
+        .map(|v| {
+            v.to_str()
+                .ok()
+                .and_then(|v| v.trim().parse::<i64>().ok())
+                .ok_or_else(|| CommonError {
+                    message: format!("{} must be an event id", LAST_EVENT_ID_HEADER),
+                    code: CommonErrorKind::InvalidInput,
+                })
+        })
+        .transpose()?;
+
+    let last_id = match last_event_id {
+        Some(id) => id,
+        None => event_service.get_last_event_id().await?,
+    };
+    let feed = Feed {
+        event_service: event_service.into_inner(),
+        item_ids,
+        last_id,
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(stream::unfold(feed, next_chunk)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::events_handler::get_item_events;
+    use crate::domain::models::events::{ItemEvent, OutboxEvent};
+    use crate::domain::services::events::{EventService, MockEventService};
+    use actix_web;
+    use actix_web::body::MessageBody;
+    use actix_web::http::StatusCode;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use chrono::Utc;
+    use futures_util::future::poll_fn;
+    use std::pin::Pin;
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_resume_item_events_after_last_event_id() {
+        let _ = env_logger::try_init();
+
+        let mut mock_events = MockEventService::new();
+
+        mock_events.expect_get_last_event_id().never();
+        mock_events
+            .expect_get_events_after()
+            .times(1)
+            .returning(|after_id, item_ids| -> _ {
+                assert_eq!(after_id, 41);
+                assert_eq!(item_ids, vec![1, 3]);
+
+                Box::pin(async move {
+                    Ok(vec![
+                        OutboxEvent {
+                            id: 42,
+                            event: ItemEvent::PriceChanged {
+                                item_id: 1,
+                                price: 5.0,
+                            },
+                            created_at: Utc::now(),
+                            attempts: 0,
+                        },
+                        OutboxEvent {
+                            id: 44,
+                            event: ItemEvent::ItemUpdated { item_id: 3 },
+                            created_at: Utc::now(),
+                            attempts: 0,
+                        },
+                    ])
+                })
+            });
+
+        let event_service: Arc<dyn EventService> = Arc::new(mock_events);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(event_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/items/events", web::get().to(get_item_events))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/items/events?item_ids=1,3")
+            .insert_header(("Last-Event-ID", "41"))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "text/event-stream"
+        );
+
+        let mut body = resp.into_body();
+        let chunk = poll_fn(|cx| Pin::new(&mut body).poll_next(cx))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            chunk,
+            "id: 42\nevent: price_changed\ndata: {\"type\":\"price_changed\",\"item_id\":1,\"price\":5.0}\n\n\
+             id: 44\nevent: item_updated\ndata: {\"type\":\"item_updated\",\"item_id\":3}\n\n"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_reject_malformed_last_event_id() {
+        let _ = env_logger::try_init();
+
+        let event_service: Arc<dyn EventService> = Arc::new(MockEventService::new());
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(event_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/items/events", web::get().to(get_item_events))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/items/events")
+            .insert_header(("Last-Event-ID", "abc"))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::api::dto::health::{HealthReportDTO, HealthStatusDTO};
+use crate::domain::services::health::HealthService;
+use actix_web::http::header;
+use actix_web::{web, HttpResponse};
+
+/// Answers as long as the process serves requests, without touching any
+/// dependency, so a database outage does not get the pod restarted.
+pub async fn get_liveness() -> HttpResponse {
+    HttpResponse::Ok()
+        .insert_header((header::CACHE_CONTROL, "no-store"))
+        .json(HealthReportDTO {
+            status: HealthStatusDTO::Up,
+            checks: vec![],
+        })
+}
+
+/// 503 while any check is down, so the instance is taken out of rotation.
+pub async fn get_readiness(health_service: web::Data<dyn HealthService>) -> HttpResponse {
+    let report: HealthReportDTO = health_service.check_readiness().await.into();
+    let mut response = match report.status {
+        HealthStatusDTO::Up => HttpResponse::Ok(),
+        HealthStatusDTO::Down => HttpResponse::ServiceUnavailable(),
+    };
+
+    response
+        .insert_header((header::CACHE_CONTROL, "no-store"))
+        .json(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::health_handler::{get_liveness, get_readiness};
+    use crate::domain::models::health::{HealthCheck, HealthReport, HealthStatus};
+    use crate::domain::services::health::{HealthService, MockHealthService};
+    use actix_web;
+    use actix_web::http::StatusCode;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn report(database: HealthStatus) -> HealthReport {
+        HealthReport::new(vec![
+            HealthCheck {
+                name: "database".to_string(),
+                status: database,
+                details: match database {
+                    HealthStatus::Up => None,
+                    HealthStatus::Down => Some("connection refused".to_string()),
+                },
+                duration_ms: 3,
+            },
+            HealthCheck {
+                name: "migrations".to_string(),
+                status: HealthStatus::Up,
+                details: None,
+                duration_ms: 1,
+            },
+        ])
+    }
+
+    #[actix_web::test]
+    async fn test_should_report_liveness_without_checks() {
+        let _ = env_logger::try_init();
+
+        let mut mock_health = MockHealthService::new();
+        mock_health.expect_check_readiness().never();
+        let health_service: Arc<dyn HealthService> = Arc::new(mock_health);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(health_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/healthz", web::get().to(get_liveness))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get().uri("/healthz").to_request();
+        let resp: serde_json::Value = actix_web::test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp, json!({"status": "up"}));
+    }
+
+    #[actix_web::test]
+    async fn test_should_report_readiness_per_dependency() {
+        let _ = env_logger::try_init();
+
+        let mut mock_health = MockHealthService::new();
+        mock_health
+            .expect_check_readiness()
+            .times(1)
+            .returning(|| Box::pin(async { report(HealthStatus::Up) }));
+        mock_health
+            .expect_check_readiness()
+            .times(1)
+            .returning(|| Box::pin(async { report(HealthStatus::Down) }));
+        let health_service: Arc<dyn HealthService> = Arc::new(mock_health);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(health_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/readyz", web::get().to(get_readiness))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get().uri("/readyz").to_request();
+        let resp = actix_web::test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("cache-control").unwrap(), "no-store");
+
+        let req = actix_web::test::TestRequest::get().uri("/readyz").to_request();
+        let resp = actix_web::test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body: serde_json::Value = actix_web::test::read_body_json(resp).await;
+        assert_eq!(
+            body,
+            json!({
+                "status": "down",
+                "checks": [
+                    {"name": "database", "status": "down", "details": "connection refused", "duration_ms": 3},
+                    {"name": "migrations", "status": "up", "duration_ms": 1},
+                ]
+            })
+        );
+    }
+}
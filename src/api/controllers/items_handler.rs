@@ -1,15 +1,26 @@
+use crate::api::dto::bundles::{BundleDTO, SetBundleRequestDTO};
+use crate::api::dto::carts::{ValidateCartRequestDTO, ValidateCartResponseDTO};
 use crate::api::dto::item::{CreateItemResponseDTO, GetItemsRequestDTO, ItemDTO};
 use crate::domain::error::ApiError;
+use crate::domain::models::items::GetItemsQuery;
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::services::order::CoreService;
 use actix_web::{web, Result};
+use tracing::instrument;
 
+#[instrument(skip_all)]
 pub async fn get_items(
     core_service: web::Data<dyn CoreService>,
     data: web::Json<GetItemsRequestDTO>,
 ) -> Result<web::Json<ResultPaging<ItemDTO>>, ApiError> {
     let data = data.into_inner();
-    let query = data.query.clone().map(|q| q.into());
+    let mut query: Option<GetItemsQuery> = data.query.clone().map(|q| q.into());
+    if let Some(currency) = data.currency.clone() {
+        query.get_or_insert_with(Default::default).currency = Some(currency);
+    }
+    if let Some(region) = data.region.clone() {
+        query.get_or_insert_with(Default::default).region = Some(region);
+    }
     let sort_by = data.sort_by.clone().map(|q| q.into());
 
     let selection = core_service
@@ -19,6 +30,7 @@ pub async fn get_items(
     Ok(web::Json(selection.into()))
 }
 
+#[instrument(skip_all)]
 pub async fn create_item(
     core_service: web::Data<dyn CoreService>,
     data: web::Json<ItemDTO>,
@@ -30,11 +42,59 @@ pub async fn create_item(
     Ok(web::Json(CreateItemResponseDTO { id }))
 }
 
+#[instrument(skip_all)]
+pub async fn validate_cart(
+    core_service: web::Data<dyn CoreService>,
+    data: web::Json<ValidateCartRequestDTO>,
+) -> Result<web::Json<ValidateCartResponseDTO>, ApiError> {
+    let data = data.into_inner();
+
+    let results = core_service
+        .validate_cart(data.lines.into_iter().map(|l| l.into()).collect())
+        .await?;
+
+    Ok(web::Json(results.into()))
+}
+
+#[instrument(skip_all)]
+pub async fn set_bundle(
+    core_service: web::Data<dyn CoreService>,
+    path: web::Path<(i64, String)>,
+    data: web::Json<SetBundleRequestDTO>,
+) -> Result<web::Json<BundleDTO>, ApiError> {
+    let (item_id, size) = path.into_inner();
+    let data = data.into_inner();
+
+    let bundle = core_service
+        .set_bundle(
+            item_id,
+            size,
+            data.components.into_iter().map(|c| c.into()).collect(),
+        )
+        .await?;
+
+    Ok(web::Json(bundle.into()))
+}
+
+#[instrument(skip_all)]
+pub async fn get_bundles(
+    core_service: web::Data<dyn CoreService>,
+    item_id: web::Path<i64>,
+) -> Result<web::Json<Vec<BundleDTO>>, ApiError> {
+    let bundles = core_service.get_bundles(item_id.into_inner()).await?;
+
+    Ok(web::Json(bundles.into_iter().map(|b| b.into()).collect()))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::api::controllers::items_handler::{create_item, get_items};
+    use crate::api::controllers::items_handler::{create_item, get_items, set_bundle, validate_cart};
+    use crate::api::dto::bundles::BundleDTO;
+    use crate::domain::models::bundles::{Bundle, BundleComponent};
+    use crate::api::dto::carts::{CartLineStatusDTO, ValidateCartResponseDTO};
+    use crate::domain::models::carts::{CartLineResult, CartLineStatus};
     use crate::api::dto::item::{CreateItemResponseDTO, GetItemsRequestDTO, ItemDTO};
-    use crate::domain::models::items::Item;
+    use crate::domain::models::items::{Item, Size};
     use crate::domain::repositories::repository::ResultPaging;
     use crate::domain::services::order::{CoreService, MockCoreService};
     use actix_web;
@@ -46,7 +106,7 @@ mod tests {
     #[actix_web::test]
     async fn test_should_get_items() {
         std::env::set_var("RUST_LOG", "debug");
-        env_logger::init();
+        let _ = env_logger::try_init();
 
         let mut mock_core = MockCoreService::new();
 
@@ -64,6 +124,19 @@ mod tests {
                         price: 0.0,
                         description: String::from("test"),
                         sizes: vec![],
+                        media: vec![],
+
+                        category: None,
+
+                        sale_price: None,
+
+                        promotion_id: None,
+                        size_sale_prices: vec![],
+                        currency: None,
+                        tax: None,
+                        tax_class_id: None,
+                        availability: None,
+                        expected_ship_date: None,
                     }],
                     total: 1,
                     offset: 0,
@@ -84,8 +157,10 @@ mod tests {
         let req_model = GetItemsRequestDTO {
             query: None,
             sort_by: None,
+            currency: None,
             offset: 0,
             limit: 10,
+            region: None,
         };
         let want_resp = ResultPaging {
             items: vec![ItemDTO {
@@ -94,6 +169,18 @@ mod tests {
                 price: 0.0,
                 description: String::from("test"),
                 sizes: vec![],
+                media: vec![],
+
+                category: None,
+
+                sale_price: None,
+
+                promotion_id: None,
+                currency: None,
+                tax: None,
+                tax_class_id: None,
+                availability: None,
+                expected_ship_date: None,
             }],
             total: 1,
             offset: 0,
@@ -119,7 +206,7 @@ mod tests {
     #[actix_web::test]
     async fn test_should_not_get_items() {
         std::env::set_var("RUST_LOG", "debug");
-        env_logger::init();
+        let _ = env_logger::try_init();
 
         let mut mock_core = MockCoreService::new();
 
@@ -151,8 +238,10 @@ mod tests {
         let req_model = GetItemsRequestDTO {
             query: None,
             sort_by: None,
+            currency: None,
             offset: 0,
             limit: 10,
+            region: None,
         };
         let want_resp = ResultPaging {
             items: vec![],
@@ -180,7 +269,7 @@ mod tests {
     #[actix_web::test]
     async fn test_err_unknown_get_items() {
         std::env::set_var("RUST_LOG", "debug");
-        env_logger::init();
+        let _ = env_logger::try_init();
 
         let mut mock_core = MockCoreService::new();
 
@@ -208,8 +297,10 @@ mod tests {
         let req_model = GetItemsRequestDTO {
             query: None,
             sort_by: None,
+            currency: None,
             offset: 0,
             limit: 10,
+            region: None,
         };
         let want_resp = ApiError::from(CommonError{code: CommonErrorKind::Unknown, message: "x".to_string()});
 
@@ -233,7 +324,7 @@ mod tests {
     #[actix_web::test]
     async fn test_should_create_item() {
         std::env::set_var("RUST_LOG", "debug");
-        env_logger::init();
+        let _ = env_logger::try_init();
 
         let mut mock_core = MockCoreService::new();
 
@@ -243,6 +334,18 @@ mod tests {
             price: 0.0,
             description: String::from("test"),
             sizes: vec![],
+            media: vec![],
+
+            category: None,
+
+            sale_price: None,
+
+            promotion_id: None,
+            currency: None,
+            tax: None,
+            tax_class_id: None,
+            availability: None,
+            expected_ship_date: None,
         };
         let input_clone: Item = input.clone().into();
 
@@ -288,7 +391,7 @@ mod tests {
     #[actix_web::test]
     async fn test_should_not_create_item() {
         std::env::set_var("RUST_LOG", "debug");
-        env_logger::init();
+        let _ = env_logger::try_init();
 
         let mut mock_core = MockCoreService::new();
 
@@ -298,6 +401,18 @@ mod tests {
             price: 0.0,
             description: String::from("test"),
             sizes: vec![],
+            media: vec![],
+
+            category: None,
+
+            sale_price: None,
+
+            promotion_id: None,
+            currency: None,
+            tax: None,
+            tax_class_id: None,
+            availability: None,
+            expected_ship_date: None,
         };
         let input_clone: Item = input.clone().into();
 
@@ -339,4 +454,122 @@ mod tests {
 
         assert_eq!(resp_model, want_resp);
     }
+
+    #[actix_web::test]
+    async fn test_should_validate_cart() {
+        let _ = env_logger::try_init();
+
+        let mut mock_core = MockCoreService::new();
+
+        mock_core.expect_validate_cart().returning(|lines| -> _ {
+            assert_eq!(lines.len(), 2);
+
+            Box::pin(async move {
+                Ok(vec![
+                    CartLineResult {
+                        line: lines[0].clone(),
+                        status: CartLineStatus::Ok,
+                    },
+                    CartLineResult {
+                        line: lines[1].clone(),
+                        status: CartLineStatus::InsufficientStock { available: 1 },
+                    },
+                ])
+            })
+        });
+
+        let core_service: Arc<dyn CoreService> = Arc::new(mock_core);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(core_service))
+                .wrap(Logger::default())
+                .service(
+                    web::scope("").route("/items/validate-cart", web::post().to(validate_cart)),
+                ),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/items/validate-cart")
+            .set_json(serde_json::json!({
+                "lines": [
+                    { "item_id": 1, "size": "S", "quantity": 1, "expected_price": 10.0 },
+                    { "item_id": 2, "size": "M", "quantity": 3, "expected_price": 20.0 }
+                ]
+            }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: ValidateCartResponseDTO = actix_web::test::read_body_json(resp).await;
+
+        assert!(!resp_model.valid);
+        assert_eq!(resp_model.lines[0].status, CartLineStatusDTO::Ok);
+        assert_eq!(resp_model.lines[1].status, CartLineStatusDTO::InsufficientStock);
+        assert_eq!(resp_model.lines[1].available, Some(1));
+    }
+
+    #[actix_web::test]
+    async fn test_should_set_bundle() {
+        let _ = env_logger::try_init();
+
+        let mut mock_core = MockCoreService::new();
+
+        mock_core.expect_set_bundle().returning(|item_id, size, components| -> _ {
+            assert_eq!(item_id, 4);
+            assert_eq!(size, "M");
+            assert_eq!(
+                components,
+                vec![BundleComponent {
+                    item_id: 1,
+                    size: "M".to_string(),
+                    quantity: 3,
+                    available: 0,
+                }]
+            );
+
+            Box::pin(async move {
+                Ok(Bundle {
+                    item_id,
+                    size: Size { id: 2, name: size },
+                    components: components
+                        .into_iter()
+                        .map(|c| BundleComponent { available: 7, ..c })
+                        .collect(),
+                })
+            })
+        });
+
+        let core_service: Arc<dyn CoreService> = Arc::new(mock_core);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(core_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/sizes/{size}/components",
+                    web::put().to(set_bundle),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/items/4/sizes/M/components")
+            .set_json(serde_json::json!({
+                "components": [{ "item_id": 1, "size": "M", "quantity": 3 }]
+            }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: BundleDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model.stock, 2);
+        assert_eq!(resp_model.components[0].available, 7);
+    }
 }
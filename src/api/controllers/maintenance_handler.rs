@@ -0,0 +1,121 @@
+use crate::api::dto::service_context::{MaintenanceDTO, SetMaintenanceRequestDTO};
+use crate::api::request::request_actor;
+use crate::domain::error::ApiError;
+use crate::domain::models::service_context::ServiceContext;
+use crate::domain::services::service_context::ServiceContextService;
+use actix_web::{web, HttpRequest, Result};
+use log::warn;
+
+pub async fn get_maintenance(
+    service_context_service: web::Data<dyn ServiceContextService>,
+) -> Result<web::Json<MaintenanceDTO>, ApiError> {
+    let service_context = service_context_service.get_service_context().await?;
+
+    Ok(web::Json(service_context.into()))
+}
+
+pub async fn set_maintenance(
+    req: HttpRequest,
+    service_context_service: web::Data<dyn ServiceContextService>,
+    data: web::Json<SetMaintenanceRequestDTO>,
+) -> Result<web::Json<MaintenanceDTO>, ApiError> {
+    let data = data.into_inner();
+    let current = service_context_service.get_service_context().await?;
+
+    let service_context = service_context_service
+        .update(ServiceContext {
+            maintenance: data.maintenance,
+            maintenance_scope: data.scope.map_or(current.maintenance_scope, Into::into),
+            retry_after_secs: data.retry_after_secs.unwrap_or(current.retry_after_secs),
+            ..current
+        })
+        .await?;
+    warn!(
+        actor = request_actor(&req).as_str(),
+        scope = format!("{:?}", service_context.maintenance_scope).as_str();
+        "maintenance mode turned {}", if service_context.maintenance { "on" } else { "off" }
+    );
+
+    Ok(web::Json(service_context.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::maintenance_handler::{get_maintenance, set_maintenance};
+    use crate::domain::models::service_context::{MaintenanceScope, ServiceContext};
+    use crate::domain::services::service_context::{
+        MockServiceContextService, ServiceContextService,
+    };
+    use actix_web;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn service_context(maintenance: bool, maintenance_scope: MaintenanceScope) -> ServiceContext {
+        ServiceContext {
+            id: 1,
+            maintenance,
+            maintenance_scope,
+            retry_after_secs: 300,
+            updated_at: Utc.with_ymd_and_hms(2023, 12, 23, 9, 0, 0).unwrap(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_should_toggle_maintenance() {
+        let _ = env_logger::try_init();
+
+        let mut mock_context = MockServiceContextService::new();
+        mock_context
+            .expect_get_service_context()
+            .times(2)
+            .returning(|| Box::pin(async { Ok(service_context(false, MaintenanceScope::Writes)) }));
+        mock_context
+            .expect_update()
+            .withf(|sc| {
+                sc.maintenance
+                    && sc.maintenance_scope == MaintenanceScope::All
+                    && sc.retry_after_secs == 300
+            })
+            .times(1)
+            .returning(|sc| Box::pin(async move { Ok(sc) }));
+        let service_context_service: Arc<dyn ServiceContextService> = Arc::new(mock_context);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(service_context_service))
+                .wrap(Logger::default())
+                .service(
+                    web::scope("")
+                        .route("/v1/admin/maintenance", web::get().to(get_maintenance))
+                        .route("/v1/admin/maintenance", web::put().to(set_maintenance)),
+                ),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/v1/admin/maintenance")
+            .to_request();
+        let resp: serde_json::Value = actix_web::test::call_and_read_body_json(&app, req).await;
+        assert_eq!(
+            resp,
+            json!({
+                "maintenance": false,
+                "scope": "writes",
+                "retry_after_secs": 300,
+                "updated_at": "2023-12-23T09:00:00Z",
+            })
+        );
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/v1/admin/maintenance")
+            .set_json(json!({"maintenance": true, "scope": "all"}))
+            .to_request();
+        let resp: serde_json::Value = actix_web::test::call_and_read_body_json(&app, req).await;
+        assert_eq!(resp["maintenance"], true);
+        assert_eq!(resp["scope"], "all");
+        assert_eq!(resp["retry_after_secs"], 300);
+    }
+}
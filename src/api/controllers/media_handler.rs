@@ -0,0 +1,157 @@
+use crate::api::dto::media::{MediaDTO, UploadMediaQueryDTO};
+use crate::domain::error::ApiError;
+use crate::domain::models::media::MediaUpload;
+use crate::domain::services::media::MediaService;
+use actix_web::http::header;
+use actix_web::{web, HttpRequest, Result};
+
+pub async fn upload_item_media(
+    media_service: web::Data<dyn MediaService>,
+    item_id: web::Path<i64>,
+    params: web::Query<UploadMediaQueryDTO>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> Result<web::Json<MediaDTO>, ApiError> {
+    let params = params.into_inner();
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+
+    let media = media_service
+        .upload_item_media(MediaUpload {
+            item_id: item_id.into_inner(),
+            content_type,
+            content: body.to_vec(),
+            alt_text: params.alt.unwrap_or_default(),
+            position: params.position,
+        })
+        .await?;
+
+    Ok(web::Json(media.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::media_handler::upload_item_media;
+    use crate::api::dto::media::MediaDTO;
+    use crate::domain::error::{ApiError, CommonError, CommonErrorKind};
+    use crate::domain::models::media::ItemMedia;
+    use crate::domain::services::media::{MediaService, MockMediaService};
+    use actix_web;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_upload_item_media() {
+        let _ = env_logger::try_init();
+
+        let mut mock_media = MockMediaService::new();
+
+        mock_media.expect_upload_item_media().returning(|u| -> _ {
+            assert_eq!(u.item_id, 3);
+            assert_eq!(u.content_type, "image/png");
+            assert_eq!(u.content, vec![1, 2, 3]);
+            assert_eq!(u.alt_text, "front side");
+            assert_eq!(u.position, Some(2));
+
+            Box::pin(async move {
+                Ok(ItemMedia {
+                    id: 1,
+                    item_id: 3,
+                    url: "/media/items/3/a.png".to_string(),
+                    path: Some("items/3/a.png".to_string()),
+                    alt_text: u.alt_text,
+                    position: 2,
+                    width: Some(640),
+                    height: Some(480),
+                })
+            })
+        });
+
+        let media_service: Arc<dyn MediaService> = Arc::new(mock_media);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(media_service))
+                .wrap(Logger::default())
+                .service(
+                    web::scope("").route("/items/{id}/media", web::post().to(upload_item_media)),
+                ),
+        )
+        .await;
+
+        let want_resp = MediaDTO {
+            id: Some(1),
+            url: "/media/items/3/a.png".to_string(),
+            alt_text: "front side".to_string(),
+            position: 2,
+            width: Some(640),
+            height: Some(480),
+        };
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/items/3/media?alt=front%20side&position=2")
+            .insert_header(("Content-Type", "image/png"))
+            .set_payload(vec![1, 2, 3])
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: MediaDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model, want_resp);
+    }
+
+    #[actix_web::test]
+    async fn test_should_not_upload_item_media() {
+        let _ = env_logger::try_init();
+
+        let mut mock_media = MockMediaService::new();
+
+        mock_media.expect_upload_item_media().returning(|_| -> _ {
+            Box::pin(async move {
+                Err(CommonError {
+                    message: "unsupported content type: text/plain".to_string(),
+                    code: CommonErrorKind::InvalidInput,
+                })
+            })
+        });
+
+        let media_service: Arc<dyn MediaService> = Arc::new(mock_media);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(media_service))
+                .wrap(Logger::default())
+                .service(
+                    web::scope("").route("/items/{id}/media", web::post().to(upload_item_media)),
+                ),
+        )
+        .await;
+
+        let want_resp = ApiError::from(CommonError {
+            message: "unsupported content type: text/plain".to_string(),
+            code: CommonErrorKind::InvalidInput,
+        });
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/items/3/media")
+            .insert_header(("Content-Type", "text/plain"))
+            .set_payload("hello")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_client_error());
+
+        let resp_model: ApiError = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model, want_resp);
+    }
+}
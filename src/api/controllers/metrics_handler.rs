@@ -0,0 +1,48 @@
+use crate::infrastructure::metrics::Metrics;
+use actix_web::{web, HttpResponse};
+use prometheus::TEXT_FORMAT;
+
+pub async fn get_metrics(metrics: web::Data<Metrics>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(TEXT_FORMAT)
+        .body(metrics.render())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::metrics_handler::get_metrics;
+    use crate::infrastructure::metrics::Metrics;
+    use actix_web;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_render_metrics() {
+        let _ = env_logger::try_init();
+
+        let metrics = Arc::new(Metrics::new());
+        metrics.catalog_out_of_stock_sizes.set(3);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(metrics))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/metrics", web::get().to(get_metrics))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get().uri("/metrics").to_request();
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "text/plain; version=0.0.4"
+        );
+
+        let body = actix_web::test::read_body(resp).await;
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("catalog_out_of_stock_sizes 3"));
+    }
+}
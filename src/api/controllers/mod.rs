@@ -1 +1,12 @@
+pub mod currencies_handler;
+pub mod events_handler;
+pub mod health_handler;
 pub mod items_handler;
+pub mod maintenance_handler;
+pub mod media_handler;
+pub mod metrics_handler;
+pub mod prices_handler;
+pub mod promotions_handler;
+pub mod taxes_handler;
+pub mod warehouses_handler;
+pub mod webhooks_handler;
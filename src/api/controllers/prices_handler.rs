@@ -0,0 +1,256 @@
+use crate::api::dto::prices::{ItemPricesDTO, PriceChangeDTO, SetPriceRequestDTO};
+use crate::domain::error::ApiError;
+use crate::domain::services::prices::PriceService;
+use actix_web::{web, HttpResponse, Result};
+
+pub async fn get_item_prices(
+    price_service: web::Data<dyn PriceService>,
+    item_id: web::Path<i64>,
+) -> Result<web::Json<ItemPricesDTO>, ApiError> {
+    let prices = price_service.get_item_prices(item_id.into_inner()).await?;
+
+    Ok(web::Json(prices.into()))
+}
+
+pub async fn set_item_price(
+    price_service: web::Data<dyn PriceService>,
+    item_id: web::Path<i64>,
+    data: web::Json<SetPriceRequestDTO>,
+) -> Result<web::Json<PriceChangeDTO>, ApiError> {
+    let data = data.into_inner();
+
+    let change = price_service
+        .set_item_price(item_id.into_inner(), data.price, data.starts_at)
+        .await?;
+
+    Ok(web::Json(change.into()))
+}
+
+pub async fn cancel_scheduled_price(
+    price_service: web::Data<dyn PriceService>,
+    path: web::Path<(i64, i64)>,
+) -> Result<HttpResponse, ApiError> {
+    let (item_id, schedule_id) = path.into_inner();
+
+    price_service
+        .cancel_scheduled_price(item_id, schedule_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::prices_handler::{
+        cancel_scheduled_price, get_item_prices, set_item_price,
+    };
+    use crate::api::dto::prices::{
+        ItemPricesDTO, PriceChangeDTO, PriceRecordDTO, ScheduledPriceDTO, SetPriceRequestDTO,
+    };
+    use crate::domain::error::{ApiError, CommonError, CommonErrorKind};
+    use crate::domain::models::prices::{ItemPrices, PriceChange, PriceRecord, ScheduledPrice};
+    use crate::domain::services::prices::{MockPriceService, PriceService};
+    use actix_web;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use chrono::{TimeZone, Utc};
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_get_item_prices() {
+        let _ = env_logger::try_init();
+
+        let mut mock_prices = MockPriceService::new();
+        let from = Utc.with_ymd_and_hms(2023, 11, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2023, 11, 24, 0, 0, 0).unwrap();
+        let sale = Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
+
+        mock_prices.expect_get_item_prices().returning(move |id| -> _ {
+            assert_eq!(id, 1);
+
+            Box::pin(async move {
+                Ok(ItemPrices {
+                    item_id: 1,
+                    history: vec![
+                        PriceRecord {
+                            id: 2,
+                            item_id: 1,
+                            price: 150.0,
+                            valid_from: to,
+                            valid_to: None,
+                        },
+                        PriceRecord {
+                            id: 1,
+                            item_id: 1,
+                            price: 200.0,
+                            valid_from: from,
+                            valid_to: Some(to),
+                        },
+                    ],
+                    scheduled: vec![ScheduledPrice {
+                        id: 1,
+                        item_id: 1,
+                        price: 100.0,
+                        starts_at: sale,
+                        applied_at: None,
+                    }],
+                })
+            })
+        });
+
+        let price_service: Arc<dyn PriceService> = Arc::new(mock_prices);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(price_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/items/{id}/prices", web::get().to(get_item_prices))),
+        )
+        .await;
+
+        let want_resp = ItemPricesDTO {
+            item_id: 1,
+            history: vec![
+                PriceRecordDTO {
+                    price: 150.0,
+                    valid_from: to,
+                    valid_to: None,
+                },
+                PriceRecordDTO {
+                    price: 200.0,
+                    valid_from: from,
+                    valid_to: Some(to),
+                },
+            ],
+            scheduled: vec![ScheduledPriceDTO {
+                id: 1,
+                price: 100.0,
+                starts_at: sale,
+            }],
+        };
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/items/1/prices")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: ItemPricesDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model, want_resp);
+    }
+
+    #[actix_web::test]
+    async fn test_should_schedule_item_price() {
+        let _ = env_logger::try_init();
+
+        let mut mock_prices = MockPriceService::new();
+        let sale = Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
+
+        mock_prices
+            .expect_set_item_price()
+            .returning(|id, price, starts_at| -> _ {
+                assert_eq!(id, 1);
+                assert_eq!(price, 100.0);
+
+                Box::pin(async move {
+                    Ok(PriceChange::Scheduled(ScheduledPrice {
+                        id: 4,
+                        item_id: id,
+                        price,
+                        starts_at: starts_at.unwrap(),
+                        applied_at: None,
+                    }))
+                })
+            });
+
+        let price_service: Arc<dyn PriceService> = Arc::new(mock_prices);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(price_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/items/{id}/prices", web::post().to(set_item_price))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/items/1/prices")
+            .set_json(SetPriceRequestDTO {
+                price: 100.0,
+                starts_at: Some(sale),
+            })
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let body = actix_web::test::read_body(resp).await;
+        let raw: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(raw["status"], "scheduled");
+
+        let resp_model: PriceChangeDTO = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            resp_model,
+            PriceChangeDTO::Scheduled(ScheduledPriceDTO {
+                id: 4,
+                price: 100.0,
+                starts_at: sale,
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_not_cancel_unknown_scheduled_price() {
+        let _ = env_logger::try_init();
+
+        let mut mock_prices = MockPriceService::new();
+
+        mock_prices
+            .expect_cancel_scheduled_price()
+            .returning(|item_id, schedule_id| -> _ {
+                assert_eq!(item_id, 1);
+                assert_eq!(schedule_id, 9);
+
+                Box::pin(async move {
+                    Err(CommonError {
+                        message: "x".to_string(),
+                        code: CommonErrorKind::NotFound,
+                    })
+                })
+            });
+
+        let price_service: Arc<dyn PriceService> = Arc::new(mock_prices);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(price_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/prices/scheduled/{schedule_id}",
+                    web::delete().to(cancel_scheduled_price),
+                )),
+        )
+        .await;
+
+        let want_resp = ApiError::from(CommonError {
+            message: "x".to_string(),
+            code: CommonErrorKind::NotFound,
+        });
+
+        let req = actix_web::test::TestRequest::delete()
+            .uri("/items/1/prices/scheduled/9")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_client_error());
+
+        let resp_model: ApiError = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model, want_resp);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::api::dto::promotions::PromotionDTO;
+use crate::domain::error::ApiError;
+use crate::domain::services::promotions::PromotionService;
+use actix_web::{web, HttpResponse, Result};
+
+pub async fn get_promotions(
+    promotion_service: web::Data<dyn PromotionService>,
+) -> Result<web::Json<Vec<PromotionDTO>>, ApiError> {
+    let promotions = promotion_service.get_promotions().await?;
+
+    Ok(web::Json(promotions.into_iter().map(|p| p.into()).collect()))
+}
+
+pub async fn create_promotion(
+    promotion_service: web::Data<dyn PromotionService>,
+    data: web::Json<PromotionDTO>,
+) -> Result<web::Json<PromotionDTO>, ApiError> {
+    let promotion = promotion_service
+        .create_promotion(data.into_inner().into())
+        .await?;
+
+    Ok(web::Json(promotion.into()))
+}
+
+pub async fn delete_promotion(
+    promotion_service: web::Data<dyn PromotionService>,
+    promotion_id: web::Path<i64>,
+) -> Result<HttpResponse, ApiError> {
+    promotion_service
+        .delete_promotion(promotion_id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::promotions_handler::{create_promotion, get_promotions};
+    use crate::api::dto::promotions::{DiscountKindDTO, PromotionDTO, PromotionTargetDTO};
+    use crate::domain::error::{ApiError, CommonError, CommonErrorKind};
+    use crate::domain::models::promotions::{DiscountKind, Promotion, PromotionTarget};
+    use crate::domain::services::promotions::{MockPromotionService, PromotionService};
+    use actix_web;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use std::sync::Arc;
+
+    fn promotion() -> Promotion {
+        Promotion {
+            id: 1,
+            name: "XL sale".to_string(),
+            kind: DiscountKind::Fixed,
+            value: 10.0,
+            target: PromotionTarget::Size("XL".to_string()),
+            starts_at: None,
+            ends_at: None,
+            priority: 0,
+            active: true,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_should_get_promotions() {
+        let _ = env_logger::try_init();
+
+        let mut mock_promotions = MockPromotionService::new();
+
+        mock_promotions
+            .expect_get_promotions()
+            .returning(|| -> _ { Box::pin(async move { Ok(vec![promotion()]) }) });
+
+        let promotion_service: Arc<dyn PromotionService> = Arc::new(mock_promotions);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(promotion_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/promotions", web::get().to(get_promotions))),
+        )
+        .await;
+
+        let want_resp = vec![PromotionDTO {
+            id: Some(1),
+            name: "XL sale".to_string(),
+            kind: DiscountKindDTO::Fixed,
+            value: 10.0,
+            target: PromotionTargetDTO::Size {
+                size: "XL".to_string(),
+            },
+            starts_at: None,
+            ends_at: None,
+            priority: 0,
+            active: true,
+        }];
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/promotions")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: Vec<PromotionDTO> = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model, want_resp);
+    }
+
+    #[actix_web::test]
+    async fn test_should_create_promotion() {
+        let _ = env_logger::try_init();
+
+        let mut mock_promotions = MockPromotionService::new();
+
+        mock_promotions.expect_create_promotion().returning(|p| -> _ {
+            assert_eq!(p, Promotion { id: 0, ..promotion() });
+
+            Box::pin(async move { Ok(Promotion { id: 1, ..p }) })
+        });
+
+        let promotion_service: Arc<dyn PromotionService> = Arc::new(mock_promotions);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(promotion_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/promotions", web::post().to(create_promotion))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/promotions")
+            .set_json(serde_json::json!({
+                "name": "XL sale",
+                "kind": "fixed",
+                "value": 10.0,
+                "target": { "type": "size", "size": "XL" }
+            }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: PromotionDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model, promotion().into());
+    }
+
+    #[actix_web::test]
+    async fn test_should_not_create_invalid_promotion() {
+        let _ = env_logger::try_init();
+
+        let mut mock_promotions = MockPromotionService::new();
+
+        mock_promotions.expect_create_promotion().returning(|_| -> _ {
+            Box::pin(async move {
+                Err(CommonError {
+                    message: "x".to_string(),
+                    code: CommonErrorKind::InvalidInput,
+                })
+            })
+        });
+
+        let promotion_service: Arc<dyn PromotionService> = Arc::new(mock_promotions);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(promotion_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/promotions", web::post().to(create_promotion))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/promotions")
+            .set_json(PromotionDTO::from(promotion()))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_client_error());
+
+        let resp_model: ApiError = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(
+            resp_model,
+            ApiError::from(CommonError {
+                message: "x".to_string(),
+                code: CommonErrorKind::InvalidInput,
+            })
+        );
+    }
+}
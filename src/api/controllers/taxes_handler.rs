@@ -0,0 +1,179 @@
+use crate::api::dto::taxes::{
+    CreateTaxClassRequestDTO, SetItemTaxClassRequestDTO, SetTaxRateRequestDTO, TaxClassDTO,
+    TaxRateDTO,
+};
+use crate::domain::error::ApiError;
+use crate::domain::models::taxes::TaxRate;
+use crate::domain::services::taxes::TaxService;
+use actix_web::{web, HttpResponse, Result};
+
+pub async fn get_tax_classes(
+    tax_service: web::Data<dyn TaxService>,
+) -> Result<web::Json<Vec<TaxClassDTO>>, ApiError> {
+    let classes = tax_service.get_tax_classes().await?;
+
+    Ok(web::Json(classes.into_iter().map(|c| c.into()).collect()))
+}
+
+pub async fn create_tax_class(
+    tax_service: web::Data<dyn TaxService>,
+    data: web::Json<CreateTaxClassRequestDTO>,
+) -> Result<web::Json<TaxClassDTO>, ApiError> {
+    let class = tax_service.create_tax_class(data.into_inner().name).await?;
+
+    Ok(web::Json(class.into()))
+}
+
+pub async fn set_tax_rate(
+    tax_service: web::Data<dyn TaxService>,
+    path: web::Path<(i32, String)>,
+    data: web::Json<SetTaxRateRequestDTO>,
+) -> Result<web::Json<TaxRateDTO>, ApiError> {
+    let (tax_class_id, region) = path.into_inner();
+
+    let rate = tax_service
+        .set_tax_rate(TaxRate {
+            tax_class_id,
+            region,
+            rate: data.rate,
+        })
+        .await?;
+
+    Ok(web::Json(rate.into()))
+}
+
+pub async fn delete_tax_rate(
+    tax_service: web::Data<dyn TaxService>,
+    path: web::Path<(i32, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (tax_class_id, region) = path.into_inner();
+
+    tax_service.delete_tax_rate(tax_class_id, region).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn set_item_tax_class(
+    tax_service: web::Data<dyn TaxService>,
+    item_id: web::Path<i64>,
+    data: web::Json<SetItemTaxClassRequestDTO>,
+) -> Result<HttpResponse, ApiError> {
+    tax_service
+        .set_item_tax_class(item_id.into_inner(), data.tax_class_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::taxes_handler::{set_item_tax_class, set_tax_rate};
+    use crate::api::dto::taxes::TaxRateDTO;
+    use crate::domain::error::{ApiError, CommonError, CommonErrorKind};
+    use crate::domain::services::taxes::{MockTaxService, TaxService};
+    use actix_web;
+    use actix_web::http::StatusCode;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_set_tax_rate() {
+        let _ = env_logger::try_init();
+
+        let mut mock_taxes = MockTaxService::new();
+
+        mock_taxes.expect_set_tax_rate().returning(|rate| -> _ {
+            assert_eq!(rate.tax_class_id, 1);
+            assert_eq!(rate.region, "DE");
+            assert_eq!(rate.rate, 19.0);
+
+            Box::pin(async move { Ok(rate) })
+        });
+
+        let tax_service: Arc<dyn TaxService> = Arc::new(mock_taxes);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(tax_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/tax-classes/{id}/rates/{region}",
+                    web::put().to(set_tax_rate),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/tax-classes/1/rates/DE")
+            .set_json(serde_json::json!({ "rate": 19.0 }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: TaxRateDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(
+            resp_model,
+            TaxRateDTO {
+                region: "DE".to_string(),
+                rate: 19.0,
+            }
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_not_assign_missing_tax_class() {
+        let _ = env_logger::try_init();
+
+        let mut mock_taxes = MockTaxService::new();
+
+        mock_taxes
+            .expect_set_item_tax_class()
+            .returning(|item_id, tax_class_id| -> _ {
+                assert_eq!(item_id, 7);
+                assert_eq!(tax_class_id, Some(42));
+
+                Box::pin(async move {
+                    Err(CommonError {
+                        message: "x".to_string(),
+                        code: CommonErrorKind::NotFound,
+                    })
+                })
+            });
+
+        let tax_service: Arc<dyn TaxService> = Arc::new(mock_taxes);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(tax_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/tax-class",
+                    web::put().to(set_item_tax_class),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/items/7/tax-class")
+            .set_json(serde_json::json!({ "tax_class_id": 42 }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::GONE);
+
+        let resp_model: ApiError = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(
+            resp_model,
+            ApiError::from(CommonError {
+                message: "x".to_string(),
+                code: CommonErrorKind::NotFound,
+            })
+        );
+    }
+}
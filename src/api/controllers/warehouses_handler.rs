@@ -0,0 +1,602 @@
+use crate::api::dto::backorders::{BackorderRuleDTO, SetBackorderRuleRequestDTO};
+use crate::api::dto::warehouses::{
+    AdjustStockRequestDTO, CreateWarehouseRequestDTO, LowStockDTO, SetStockThresholdRequestDTO,
+    StockChangeQueryDTO, StockLevelDTO, StockMovementDTO, WarehouseDTO,
+};
+use crate::api::request::request_actor;
+use crate::domain::error::ApiError;
+use crate::domain::models::backorders::BackorderRule;
+use crate::domain::models::warehouses::{MovementMeta, StockThreshold};
+use crate::domain::repositories::repository::{QueryParams, QueryParamsImpl, ResultPaging};
+use crate::domain::services::warehouses::WarehouseService;
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+
+pub async fn get_warehouses(
+    warehouse_service: web::Data<dyn WarehouseService>,
+) -> Result<web::Json<Vec<WarehouseDTO>>, ApiError> {
+    let warehouses = warehouse_service.get_warehouses().await?;
+
+    Ok(web::Json(warehouses.into_iter().map(|w| w.into()).collect()))
+}
+
+pub async fn create_warehouse(
+    warehouse_service: web::Data<dyn WarehouseService>,
+    data: web::Json<CreateWarehouseRequestDTO>,
+) -> Result<web::Json<WarehouseDTO>, ApiError> {
+    let warehouse = warehouse_service
+        .create_warehouse(data.into_inner().name)
+        .await?;
+
+    Ok(web::Json(warehouse.into()))
+}
+
+pub async fn get_item_stock(
+    warehouse_service: web::Data<dyn WarehouseService>,
+    item_id: web::Path<i64>,
+) -> Result<web::Json<Vec<StockLevelDTO>>, ApiError> {
+    let stock = warehouse_service
+        .get_item_stock(item_id.into_inner())
+        .await?;
+
+    Ok(web::Json(stock.into_iter().map(|s| s.into()).collect()))
+}
+
+pub async fn set_stock_levels(
+    req: HttpRequest,
+    warehouse_service: web::Data<dyn WarehouseService>,
+    warehouse_id: web::Path<i32>,
+    params: web::Query<StockChangeQueryDTO>,
+    data: web::Json<Vec<StockLevelDTO>>,
+) -> Result<web::Json<Vec<StockLevelDTO>>, ApiError> {
+    let params = params.into_inner();
+    let meta = MovementMeta {
+        reason: params.reason.into(),
+        actor: request_actor(&req),
+        note: params.note,
+    };
+
+    let stock = warehouse_service
+        .set_stock_levels(
+            warehouse_id.into_inner(),
+            data.into_inner().into_iter().map(|s| s.into()).collect(),
+            meta,
+        )
+        .await?;
+
+    Ok(web::Json(stock.into_iter().map(|s| s.into()).collect()))
+}
+
+pub async fn adjust_stock(
+    req: HttpRequest,
+    warehouse_service: web::Data<dyn WarehouseService>,
+    path: web::Path<(i64, String)>,
+    params: web::Query<StockChangeQueryDTO>,
+    data: web::Json<AdjustStockRequestDTO>,
+) -> Result<web::Json<StockLevelDTO>, ApiError> {
+    let (item_id, size) = path.into_inner();
+    let params = params.into_inner();
+    let data = data.into_inner();
+    let meta = MovementMeta {
+        reason: params.reason.into(),
+        actor: request_actor(&req),
+        note: params.note,
+    };
+
+    let stock = warehouse_service
+        .adjust_stock(item_id, size, data.warehouse_id, data.operation.into(), meta)
+        .await?;
+
+    Ok(web::Json(stock.into()))
+}
+
+pub async fn set_stock_threshold(
+    warehouse_service: web::Data<dyn WarehouseService>,
+    path: web::Path<(i64, String)>,
+    data: web::Json<SetStockThresholdRequestDTO>,
+) -> Result<HttpResponse, ApiError> {
+    let (item_id, size) = path.into_inner();
+
+    warehouse_service
+        .set_stock_threshold(StockThreshold {
+            item_id,
+            size,
+            threshold: data.threshold,
+        })
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn delete_stock_threshold(
+    warehouse_service: web::Data<dyn WarehouseService>,
+    path: web::Path<(i64, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (item_id, size) = path.into_inner();
+
+    warehouse_service.delete_stock_threshold(item_id, size).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn set_backorder_rule(
+    warehouse_service: web::Data<dyn WarehouseService>,
+    path: web::Path<(i64, String)>,
+    data: web::Json<SetBackorderRuleRequestDTO>,
+) -> Result<web::Json<BackorderRuleDTO>, ApiError> {
+    let (item_id, size) = path.into_inner();
+    let data = data.into_inner();
+
+    let rule = warehouse_service
+        .set_backorder_rule(BackorderRule {
+            item_id,
+            size,
+            policy: data.policy.into(),
+            limit: data.limit,
+            expected_date: data.expected_date,
+            backordered: 0,
+        })
+        .await?;
+
+    Ok(web::Json(rule.into()))
+}
+
+pub async fn delete_backorder_rule(
+    warehouse_service: web::Data<dyn WarehouseService>,
+    path: web::Path<(i64, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (item_id, size) = path.into_inner();
+
+    warehouse_service.delete_backorder_rule(item_id, size).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn get_low_stock(
+    warehouse_service: web::Data<dyn WarehouseService>,
+) -> Result<web::Json<Vec<LowStockDTO>>, ApiError> {
+    let low = warehouse_service.get_low_stock().await?;
+
+    Ok(web::Json(low.into_iter().map(|l| l.into()).collect()))
+}
+
+pub async fn get_stock_movements(
+    warehouse_service: web::Data<dyn WarehouseService>,
+    path: web::Path<(i64, String)>,
+    params: web::Query<QueryParamsImpl>,
+) -> Result<web::Json<ResultPaging<StockMovementDTO>>, ApiError> {
+    let (item_id, size) = path.into_inner();
+
+    let movements = warehouse_service
+        .get_stock_movements(item_id, size, params.offset(), params.limit())
+        .await?;
+
+    Ok(web::Json(ResultPaging {
+        offset: params.offset(),
+        total: movements.len() as i64,
+        items: movements.into_iter().map(|m| m.into()).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::warehouses_handler::{
+        adjust_stock, get_item_stock, get_low_stock, get_stock_movements, set_backorder_rule,
+        set_stock_levels, set_stock_threshold,
+    };
+    use crate::api::dto::backorders::{BackorderPolicyDTO, BackorderRuleDTO};
+    use crate::api::dto::warehouses::{
+        LowStockDTO, MovementReasonDTO, StockLevelDTO, StockMovementDTO,
+    };
+    use crate::domain::error::{CommonError, CommonErrorKind};
+    use crate::domain::models::backorders::{BackorderPolicy, BackorderRule};
+    use crate::domain::models::warehouses::{
+        LowStock, MovementMeta, MovementReason, StockLevel, StockMovement, StockOperation,
+        StockThreshold,
+    };
+    use crate::domain::repositories::repository::ResultPaging;
+    use crate::domain::services::warehouses::{MockWarehouseService, WarehouseService};
+    use actix_web;
+    use actix_web::http::StatusCode;
+    use actix_web::middleware::Logger;
+    use actix_web::web;
+    use chrono::{NaiveDate, Utc};
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_set_stock_levels() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+
+        mock_warehouses
+            .expect_set_stock_levels()
+            .returning(|warehouse_id, levels, meta| -> _ {
+                assert_eq!(warehouse_id, 2);
+                assert_eq!(
+                    meta,
+                    MovementMeta {
+                        reason: MovementReason::Receipt,
+                        actor: "unverified:alice".to_string(),
+                        note: Some("po-17".to_string()),
+                    }
+                );
+                assert_eq!(
+                    levels,
+                    vec![StockLevel {
+                        warehouse_id: 0,
+                        item_id: 1,
+                        size: "M".to_string(),
+                        quantity: 5,
+                    }]
+                );
+
+                Box::pin(async move {
+                    Ok(levels
+                        .into_iter()
+                        .map(|l| StockLevel { warehouse_id, ..l })
+                        .collect())
+                })
+            });
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/warehouses/{id}/stock",
+                    web::put().to(set_stock_levels),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/warehouses/2/stock?reason=receipt&note=po-17")
+            .insert_header(("X-Actor", "alice"))
+            .set_json(serde_json::json!([{ "item_id": 1, "size": "M", "quantity": 5 }]))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: Vec<StockLevelDTO> = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(
+            resp_model,
+            vec![StockLevelDTO {
+                warehouse_id: Some(2),
+                item_id: 1,
+                size: "M".to_string(),
+                quantity: 5,
+            }]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_not_get_stock_of_missing_item() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+
+        mock_warehouses.expect_get_item_stock().returning(|_| -> _ {
+            Box::pin(async move {
+                Err(CommonError {
+                    message: "x".to_string(),
+                    code: CommonErrorKind::NotFound,
+                })
+            })
+        });
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/items/{id}/stock", web::get().to(get_item_stock))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/items/9/stock")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::GONE);
+    }
+
+    #[actix_web::test]
+    async fn test_should_get_stock_movements() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+        let now = Utc::now();
+
+        mock_warehouses.expect_get_stock_movements().returning(
+            move |item_id, size, offset, limit| -> _ {
+                assert_eq!(item_id, 1);
+                assert_eq!(size, "M");
+                assert_eq!(offset, 0);
+                assert_eq!(limit, 25);
+
+                Box::pin(async move {
+                    Ok(vec![StockMovement {
+                        id: 3,
+                        warehouse_id: 1,
+                        item_id,
+                        size,
+                        delta: -2,
+                        quantity_after: 8,
+                        reason: MovementReason::Sale,
+                        actor: "shop".to_string(),
+                        note: None,
+                        created_at: now,
+                    }])
+                })
+            },
+        );
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/sizes/{size}/movements",
+                    web::get().to(get_stock_movements),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/items/1/sizes/M/movements")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: ResultPaging<StockMovementDTO> =
+            actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model.total, 1);
+        assert_eq!(resp_model.items[0].reason, MovementReasonDTO::Sale);
+        assert_eq!(resp_model.items[0].delta, -2);
+    }
+
+    #[actix_web::test]
+    async fn test_should_adjust_stock() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+
+        mock_warehouses
+            .expect_adjust_stock()
+            .returning(|item_id, size, warehouse_id, operation, meta| -> _ {
+                assert_eq!(item_id, 1);
+                assert_eq!(size, "M");
+                assert_eq!(warehouse_id, None);
+                assert_eq!(operation, StockOperation::Delta(-2));
+                assert_eq!(meta.reason, MovementReason::Sale);
+
+                Box::pin(async move {
+                    Ok(StockLevel {
+                        warehouse_id: 1,
+                        item_id,
+                        size,
+                        quantity: 8,
+                    })
+                })
+            });
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/sizes/{size}",
+                    web::patch().to(adjust_stock),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::patch()
+            .uri("/items/1/sizes/M?reason=sale")
+            .set_json(serde_json::json!({"op": "delta", "delta": -2}))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: StockLevelDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model.warehouse_id, Some(1));
+        assert_eq!(resp_model.quantity, 8);
+    }
+
+    #[actix_web::test]
+    async fn test_should_reject_stock_below_zero() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+
+        mock_warehouses
+            .expect_adjust_stock()
+            .returning(|_, _, warehouse_id, operation, _| -> _ {
+                assert_eq!(warehouse_id, Some(2));
+                assert_eq!(operation, StockOperation::Set(0));
+
+                Box::pin(async move {
+                    Err(CommonError {
+                        message: "stock would drop below zero".to_string(),
+                        code: CommonErrorKind::Conflict,
+                    })
+                })
+            });
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/sizes/{size}",
+                    web::patch().to(adjust_stock),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::patch()
+            .uri("/items/1/sizes/M")
+            .set_json(serde_json::json!({"op": "set", "quantity": 0, "warehouse_id": 2}))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_should_set_stock_threshold() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+
+        mock_warehouses
+            .expect_set_stock_threshold()
+            .returning(|threshold| -> _ {
+                assert_eq!(
+                    threshold,
+                    StockThreshold {
+                        item_id: 1,
+                        size: "M".to_string(),
+                        threshold: 3,
+                    }
+                );
+
+                Box::pin(async move { Ok(threshold) })
+            });
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/sizes/{size}/threshold",
+                    web::put().to(set_stock_threshold),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/items/1/sizes/M/threshold")
+            .set_json(serde_json::json!({ "threshold": 3 }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_should_set_backorder_rule() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+
+        mock_warehouses
+            .expect_set_backorder_rule()
+            .returning(|rule| -> _ {
+                assert_eq!(rule.policy, BackorderPolicy::Preorder);
+                assert_eq!(rule.expected_date, NaiveDate::from_ymd_opt(2024, 3, 1));
+
+                Box::pin(async move { Ok(BackorderRule { backordered: 2, ..rule }) })
+            });
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/items/{id}/sizes/{size}/backorder",
+                    web::put().to(set_backorder_rule),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::put()
+            .uri("/items/1/sizes/M/backorder")
+            .set_json(serde_json::json!({ "policy": "preorder", "expected_date": "2024-03-01" }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp_model: BackorderRuleDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(
+            resp_model,
+            BackorderRuleDTO {
+                item_id: 1,
+                size: "M".to_string(),
+                policy: BackorderPolicyDTO::Preorder,
+                limit: None,
+                expected_date: NaiveDate::from_ymd_opt(2024, 3, 1),
+                backordered: 2,
+            }
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_get_low_stock() {
+        let _ = env_logger::try_init();
+
+        let mut mock_warehouses = MockWarehouseService::new();
+
+        mock_warehouses.expect_get_low_stock().returning(|| -> _ {
+            Box::pin(async move {
+                Ok(vec![LowStock {
+                    item_id: 2,
+                    size: "M".to_string(),
+                    quantity: 0,
+                    threshold: 1,
+                }])
+            })
+        });
+
+        let warehouse_service: Arc<dyn WarehouseService> = Arc::new(mock_warehouses);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(warehouse_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/stock/low", web::get().to(get_low_stock))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get().uri("/stock/low").to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: Vec<LowStockDTO> = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model.len(), 1);
+        assert_eq!(resp_model[0].item_id, 2);
+        assert_eq!(resp_model[0].threshold, 1);
+    }
+}
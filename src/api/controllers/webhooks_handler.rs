@@ -0,0 +1,354 @@
+use crate::api::dto::webhooks::{
+    WebhookDeliveryDTO, WebhookSubscriptionDTO, WebhookSubscriptionRequestDTO,
+};
+use crate::domain::error::ApiError;
+use crate::domain::repositories::repository::{QueryParams, QueryParamsImpl, ResultPaging};
+use crate::domain::services::webhooks::WebhookService;
+use actix_web::{web, HttpResponse, Result};
+
+pub async fn get_webhooks(
+    webhook_service: web::Data<dyn WebhookService>,
+) -> Result<web::Json<Vec<WebhookSubscriptionDTO>>, ApiError> {
+    let subscriptions = webhook_service.get_subscriptions().await?;
+
+    Ok(web::Json(subscriptions.into_iter().map(|s| s.into()).collect()))
+}
+
+pub async fn get_webhook(
+    webhook_service: web::Data<dyn WebhookService>,
+    subscription_id: web::Path<i64>,
+) -> Result<web::Json<WebhookSubscriptionDTO>, ApiError> {
+    let subscription = webhook_service
+        .get_subscription(subscription_id.into_inner())
+        .await?;
+
+    Ok(web::Json(subscription.into()))
+}
+
+/// The secret is only shown here, so the partner can verify signatures.
+pub async fn create_webhook(
+    webhook_service: web::Data<dyn WebhookService>,
+    data: web::Json<WebhookSubscriptionRequestDTO>,
+) -> Result<web::Json<WebhookSubscriptionDTO>, ApiError> {
+    let subscription = webhook_service
+        .create_subscription(data.into_inner().into_subscription(0))
+        .await?;
+    let secret = subscription.secret.clone();
+
+    Ok(web::Json(WebhookSubscriptionDTO {
+        secret: Some(secret),
+        ..subscription.into()
+    }))
+}
+
+pub async fn update_webhook(
+    webhook_service: web::Data<dyn WebhookService>,
+    subscription_id: web::Path<i64>,
+    data: web::Json<WebhookSubscriptionRequestDTO>,
+) -> Result<web::Json<WebhookSubscriptionDTO>, ApiError> {
+    let subscription = webhook_service
+        .update_subscription(data.into_inner().into_subscription(subscription_id.into_inner()))
+        .await?;
+
+    Ok(web::Json(subscription.into()))
+}
+
+pub async fn delete_webhook(
+    webhook_service: web::Data<dyn WebhookService>,
+    subscription_id: web::Path<i64>,
+) -> Result<HttpResponse, ApiError> {
+    webhook_service
+        .delete_subscription(subscription_id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn get_webhook_deliveries(
+    webhook_service: web::Data<dyn WebhookService>,
+    subscription_id: web::Path<i64>,
+    params: web::Query<QueryParamsImpl>,
+) -> Result<web::Json<ResultPaging<WebhookDeliveryDTO>>, ApiError> {
+    let deliveries = webhook_service
+        .get_deliveries(subscription_id.into_inner(), params.offset(), params.limit())
+        .await?;
+
+    Ok(web::Json(ResultPaging {
+        offset: params.offset(),
+        total: deliveries.len() as i64,
+        items: deliveries.into_iter().map(|d| d.into()).collect(),
+    }))
+}
+
+pub async fn retry_webhook_delivery(
+    webhook_service: web::Data<dyn WebhookService>,
+    path: web::Path<(i64, i64)>,
+) -> Result<web::Json<WebhookDeliveryDTO>, ApiError> {
+    let (subscription_id, delivery_id) = path.into_inner();
+
+    let delivery = webhook_service
+        .retry_delivery(subscription_id, delivery_id)
+        .await?;
+
+    Ok(web::Json(delivery.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::controllers::webhooks_handler::{
+        create_webhook, get_webhook, get_webhook_deliveries, retry_webhook_delivery,
+    };
+    use crate::api::dto::webhooks::{DeliveryStatusDTO, WebhookDeliveryDTO, WebhookSubscriptionDTO};
+    use crate::api::middleware::{admin_auth, AdminToken};
+    use crate::domain::error::{CommonError, CommonErrorKind};
+    use crate::domain::models::webhooks::{DeliveryStatus, WebhookDelivery, WebhookSubscription};
+    use crate::domain::repositories::repository::ResultPaging;
+    use crate::domain::services::webhooks::{MockWebhookService, WebhookService};
+    use actix_web;
+    use actix_web::http::StatusCode;
+    use actix_web::middleware::{from_fn, Logger};
+    use actix_web::web;
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    fn delivery(status: DeliveryStatus) -> WebhookDelivery {
+        WebhookDelivery {
+            id: 7,
+            subscription_id: 1,
+            event_id: 42,
+            event_type: "price_changed".to_string(),
+            payload: serde_json::json!({ "id": 42 }),
+            status,
+            attempts: 8,
+            next_attempt_at: Utc::now(),
+            last_status_code: Some(500),
+            last_error: None,
+            created_at: Utc::now(),
+            delivered_at: None,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_should_create_webhook_and_return_secret() {
+        let _ = env_logger::try_init();
+
+        let mut mock_webhooks = MockWebhookService::new();
+
+        mock_webhooks
+            .expect_create_subscription()
+            .returning(|subscription| -> _ {
+                assert_eq!(subscription.url, "https://partner.example/hooks");
+                assert_eq!(subscription.event_types, vec!["price_changed".to_string()]);
+                assert!(subscription.active);
+                assert!(subscription.secret.is_empty());
+
+                Box::pin(async move {
+                    Ok(WebhookSubscription {
+                        id: 1,
+                        secret: "generated-secret-value".to_string(),
+                        ..subscription
+                    })
+                })
+            });
+
+        let webhook_service: Arc<dyn WebhookService> = Arc::new(mock_webhooks);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(webhook_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/webhooks", web::post().to(create_webhook))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/webhooks")
+            .set_json(serde_json::json!({
+                "url": "https://partner.example/hooks",
+                "event_types": ["price_changed"]
+            }))
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: WebhookSubscriptionDTO = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model.id, 1);
+        assert_eq!(resp_model.secret, Some("generated-secret-value".to_string()));
+    }
+
+    #[actix_web::test]
+    async fn test_should_refuse_webhook_requests_without_the_admin_token() {
+        let _ = env_logger::try_init();
+
+        // Nothing reaches the service without the token.
+        let webhook_service: Arc<dyn WebhookService> = Arc::new(MockWebhookService::new());
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(webhook_service))
+                .app_data(web::Data::new(AdminToken(Some("0123456789abcdef".to_string()))))
+                .service(
+                    web::scope("/v1/webhooks")
+                        .wrap(from_fn(admin_auth))
+                        .route("", web::post().to(create_webhook))
+                        .route("/{id}", web::get().to(get_webhook)),
+                ),
+        )
+        .await;
+
+        for (method, uri) in [
+            ("POST", "/v1/webhooks"),
+            ("POST", "/v1/%77ebhooks"),
+            ("POST", "/v1/%77%65%62%68%6f%6f%6b%73"),
+            ("GET", "/v1/webhooks/1"),
+            ("GET", "/v1/%77ebhooks/%31"),
+        ] {
+            let req = actix_web::test::TestRequest::default()
+                .method(method.parse().unwrap())
+                .uri(uri)
+                .set_json(serde_json::json!({
+                    "url": "https://partner.example/hooks",
+                    "event_types": ["price_changed"]
+                }))
+                .to_request();
+
+            let resp = actix_web::test::call_service(&app, req).await;
+
+            assert_eq!(resp.status(), StatusCode::UNAUTHORIZED, "{} {}", method, uri);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_should_hide_secret_of_existing_webhook() {
+        let _ = env_logger::try_init();
+
+        let mut mock_webhooks = MockWebhookService::new();
+
+        mock_webhooks.expect_get_subscription().returning(|id| -> _ {
+            Box::pin(async move {
+                Ok(WebhookSubscription {
+                    id,
+                    url: "https://partner.example/hooks".to_string(),
+                    secret: "generated-secret-value".to_string(),
+                    event_types: vec![],
+                    active: true,
+                    created_at: Utc::now(),
+                })
+            })
+        });
+
+        let webhook_service: Arc<dyn WebhookService> = Arc::new(mock_webhooks);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(webhook_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route("/webhooks/{id}", web::get().to(get_webhook))),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/webhooks/3")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let body: serde_json::Value = actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(body["id"], 3);
+        assert!(body.get("secret").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_should_get_webhook_deliveries() {
+        let _ = env_logger::try_init();
+
+        let mut mock_webhooks = MockWebhookService::new();
+
+        mock_webhooks
+            .expect_get_deliveries()
+            .returning(|subscription_id, offset, limit| -> _ {
+                assert_eq!(subscription_id, 1);
+                assert_eq!(offset, 10);
+                assert_eq!(limit, 5);
+
+                Box::pin(async move { Ok(vec![delivery(DeliveryStatus::Dead)]) })
+            });
+
+        let webhook_service: Arc<dyn WebhookService> = Arc::new(mock_webhooks);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(webhook_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/webhooks/{id}/deliveries",
+                    web::get().to(get_webhook_deliveries),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/webhooks/1/deliveries?offset=10&limit=5")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+
+        let resp_model: ResultPaging<WebhookDeliveryDTO> =
+            actix_web::test::read_body_json(resp).await;
+
+        assert_eq!(resp_model.offset, 10);
+        assert_eq!(resp_model.total, 1);
+        assert_eq!(resp_model.items[0].status, DeliveryStatusDTO::Dead);
+        assert_eq!(resp_model.items[0].last_status_code, Some(500));
+    }
+
+    #[actix_web::test]
+    async fn test_should_not_retry_pending_delivery() {
+        let _ = env_logger::try_init();
+
+        let mut mock_webhooks = MockWebhookService::new();
+
+        mock_webhooks
+            .expect_retry_delivery()
+            .returning(|subscription_id, delivery_id| -> _ {
+                assert_eq!(subscription_id, 1);
+                assert_eq!(delivery_id, 7);
+
+                Box::pin(async move {
+                    Err(CommonError {
+                        message: "delivery 7 is not dead".to_string(),
+                        code: CommonErrorKind::Conflict,
+                    })
+                })
+            });
+
+        let webhook_service: Arc<dyn WebhookService> = Arc::new(mock_webhooks);
+
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(web::Data::from(webhook_service))
+                .wrap(Logger::default())
+                .service(web::scope("").route(
+                    "/webhooks/{id}/deliveries/{delivery_id}/retry",
+                    web::post().to(retry_webhook_delivery),
+                )),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/webhooks/1/deliveries/7/retry")
+            .to_request();
+
+        let resp = actix_web::test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::domain::models::backorders::{Availability, BackorderPolicy, BackorderRule};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum AvailabilityDTO {
+    InStock,
+    Backorder,
+    Preorder,
+    OutOfStock,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BackorderPolicyDTO {
+    Deny,
+    Backorder,
+    Preorder,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct SetBackorderRuleRequestDTO {
+    pub policy: BackorderPolicyDTO,
+    #[serde(default)]
+    pub limit: Option<i32>,
+    #[serde(default)]
+    pub expected_date: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct BackorderRuleDTO {
+    pub item_id: i64,
+    pub size: String,
+    pub policy: BackorderPolicyDTO,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_date: Option<NaiveDate>,
+    pub backordered: i32,
+}
+
+impl From<Availability> for AvailabilityDTO {
+    fn from(availability: Availability) -> AvailabilityDTO {
+        match availability {
+            Availability::InStock => AvailabilityDTO::InStock,
+            Availability::Backorder => AvailabilityDTO::Backorder,
+            Availability::Preorder => AvailabilityDTO::Preorder,
+            Availability::OutOfStock => AvailabilityDTO::OutOfStock,
+        }
+    }
+}
+
+impl From<BackorderPolicyDTO> for BackorderPolicy {
+    fn from(dto: BackorderPolicyDTO) -> BackorderPolicy {
+        match dto {
+            BackorderPolicyDTO::Deny => BackorderPolicy::Deny,
+            BackorderPolicyDTO::Backorder => BackorderPolicy::Backorder,
+            BackorderPolicyDTO::Preorder => BackorderPolicy::Preorder,
+        }
+    }
+}
+
+impl From<BackorderPolicy> for BackorderPolicyDTO {
+    fn from(policy: BackorderPolicy) -> BackorderPolicyDTO {
+        match policy {
+            BackorderPolicy::Deny => BackorderPolicyDTO::Deny,
+            BackorderPolicy::Backorder => BackorderPolicyDTO::Backorder,
+            BackorderPolicy::Preorder => BackorderPolicyDTO::Preorder,
+        }
+    }
+}
+
+impl From<BackorderRule> for BackorderRuleDTO {
+    fn from(rule: BackorderRule) -> BackorderRuleDTO {
+        BackorderRuleDTO {
+            item_id: rule.item_id,
+            size: rule.size,
+            policy: rule.policy.into(),
+            limit: rule.limit,
+            expected_date: rule.expected_date,
+            backordered: rule.backordered,
+        }
+    }
+}
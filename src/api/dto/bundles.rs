@@ -0,0 +1,63 @@
+use crate::domain::models::bundles::{Bundle, BundleComponent};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct BundleComponentRequestDTO {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct SetBundleRequestDTO {
+    pub components: Vec<BundleComponentRequestDTO>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct BundleComponentDTO {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+    pub available: i32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct BundleDTO {
+    pub item_id: i64,
+    pub size: String,
+    pub stock: i32,
+    pub components: Vec<BundleComponentDTO>,
+}
+
+impl From<BundleComponentRequestDTO> for BundleComponent {
+    fn from(dto: BundleComponentRequestDTO) -> BundleComponent {
+        BundleComponent {
+            item_id: dto.item_id,
+            size: dto.size,
+            quantity: dto.quantity,
+            available: 0,
+        }
+    }
+}
+
+impl From<BundleComponent> for BundleComponentDTO {
+    fn from(component: BundleComponent) -> BundleComponentDTO {
+        BundleComponentDTO {
+            item_id: component.item_id,
+            size: component.size,
+            quantity: component.quantity,
+            available: component.available,
+        }
+    }
+}
+
+impl From<Bundle> for BundleDTO {
+    fn from(bundle: Bundle) -> BundleDTO {
+        BundleDTO {
+            item_id: bundle.item_id,
+            stock: bundle.stock(),
+            size: bundle.size.name,
+            components: bundle.components.into_iter().map(|c| c.into()).collect(),
+        }
+    }
+}
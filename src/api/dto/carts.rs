@@ -0,0 +1,89 @@
+use crate::domain::models::carts::{CartLine, CartLineResult, CartLineStatus};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct CartLineDTO {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+    pub expected_price: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ValidateCartRequestDTO {
+    pub lines: Vec<CartLineDTO>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum CartLineStatusDTO {
+    Ok,
+    InsufficientStock,
+    PriceChanged,
+    ItemMissing,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct CartLineResultDTO {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+    pub status: CartLineStatusDTO,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_price: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ValidateCartResponseDTO {
+    /// Whether every line is `ok`.
+    pub valid: bool,
+    pub lines: Vec<CartLineResultDTO>,
+}
+
+impl From<CartLineDTO> for CartLine {
+    fn from(dto: CartLineDTO) -> CartLine {
+        CartLine {
+            item_id: dto.item_id,
+            size: dto.size,
+            quantity: dto.quantity,
+            expected_price: dto.expected_price,
+        }
+    }
+}
+
+impl From<CartLineResult> for CartLineResultDTO {
+    fn from(result: CartLineResult) -> CartLineResultDTO {
+        let (status, available, current_price) = match result.status {
+            CartLineStatus::Ok => (CartLineStatusDTO::Ok, None, None),
+            CartLineStatus::InsufficientStock { available } => {
+                (CartLineStatusDTO::InsufficientStock, Some(available), None)
+            }
+            CartLineStatus::PriceChanged { current_price } => {
+                (CartLineStatusDTO::PriceChanged, None, Some(current_price))
+            }
+            CartLineStatus::ItemMissing => (CartLineStatusDTO::ItemMissing, None, None),
+        };
+
+        CartLineResultDTO {
+            item_id: result.line.item_id,
+            size: result.line.size,
+            quantity: result.line.quantity,
+            status,
+            available,
+            current_price,
+        }
+    }
+}
+
+impl From<Vec<CartLineResult>> for ValidateCartResponseDTO {
+    fn from(results: Vec<CartLineResult>) -> ValidateCartResponseDTO {
+        let lines: Vec<CartLineResultDTO> = results.into_iter().map(|r| r.into()).collect();
+
+        ValidateCartResponseDTO {
+            valid: lines.iter().all(|l| l.status == CartLineStatusDTO::Ok),
+            lines,
+        }
+    }
+}
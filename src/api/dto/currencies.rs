@@ -0,0 +1,57 @@
+use crate::domain::models::currencies::{ExchangeRate, PriceListEntry};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ExchangeRateDTO {
+    pub currency: String,
+    pub rate: f64,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub struct SetExchangeRateRequestDTO {
+    pub rate: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct PriceListEntryDTO {
+    pub item_id: i64,
+    pub currency: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    pub price: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub struct SetPriceListPriceRequestDTO {
+    pub price: f64,
+}
+
+/// Region of a price list entry. Entries without one apply in every region
+/// that has no price of its own; listing without it returns all regions.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct PriceListQueryDTO {
+    pub region: Option<String>,
+}
+
+impl From<ExchangeRate> for ExchangeRateDTO {
+    fn from(rate: ExchangeRate) -> ExchangeRateDTO {
+        ExchangeRateDTO {
+            currency: rate.currency,
+            rate: rate.rate,
+            updated_at: rate.updated_at,
+        }
+    }
+}
+
+impl From<PriceListEntry> for PriceListEntryDTO {
+    fn from(entry: PriceListEntry) -> PriceListEntryDTO {
+        PriceListEntryDTO {
+            item_id: entry.item_id,
+            currency: entry.currency,
+            region: entry.region,
+            price: entry.price,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::events::OutboxEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct ItemEventsQueryDTO {
+    /// Comma separated item ids; every item when left out.
+    pub item_ids: Option<String>,
+}
+
+impl ItemEventsQueryDTO {
+    pub fn item_ids(&self) -> Result<Vec<i64>, CommonError> {
+        let Some(ids) = &self.item_ids else {
+            return Ok(vec![]);
+        };
+
+        ids.split(',')
+            .map(|id| id.trim())
+            .filter(|id| !id.is_empty())
+            .map(|id| {
+                id.parse::<i64>().map_err(|_| CommonError {
+                    message: format!("invalid item id {:?}", id),
+                    code: CommonErrorKind::InvalidInput,
+                })
+            })
+            .collect()
+    }
+}
+
+/// One `text/event-stream` message: the outbox id, the event type and the
+/// event as json.
+pub fn sse_message(event: &OutboxEvent) -> String {
+    format!(
+        "id: {}\nevent: {}\ndata: {}\n\n",
+        event.id,
+        event.event.event_type(),
+        serde_json::to_string(&event.event).expect("item events serialize to json"),
+    )
+}
@@ -0,0 +1,54 @@
+use crate::domain::models::health::{HealthCheck, HealthReport, HealthStatus};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatusDTO {
+    Up,
+    Down,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct HealthCheckDTO {
+    pub name: String,
+    pub status: HealthStatusDTO,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    pub duration_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct HealthReportDTO {
+    pub status: HealthStatusDTO,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<HealthCheckDTO>,
+}
+
+impl From<HealthStatus> for HealthStatusDTO {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Up => HealthStatusDTO::Up,
+            HealthStatus::Down => HealthStatusDTO::Down,
+        }
+    }
+}
+
+impl From<HealthCheck> for HealthCheckDTO {
+    fn from(check: HealthCheck) -> Self {
+        HealthCheckDTO {
+            name: check.name,
+            status: check.status.into(),
+            details: check.details,
+            duration_ms: check.duration_ms,
+        }
+    }
+}
+
+impl From<HealthReport> for HealthReportDTO {
+    fn from(report: HealthReport) -> Self {
+        HealthReportDTO {
+            status: report.status.into(),
+            checks: report.checks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use crate::domain::models::items::{
     GetItemsQuery, GetItemsSortBy, Item, NamesGetItemsQuery, PriceGetItemsQuery, Size,
 };
+use crate::api::dto::backorders::AvailabilityDTO;
+use crate::api::dto::media::MediaDTO;
+use crate::api::dto::taxes::ItemTaxDTO;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Copy, Clone, Default)]
 pub struct PriceGetItemsQueryDTO {
     pub from: f64,
     pub to: f64,
+    #[serde(default)]
+    pub effective: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -20,6 +26,8 @@ pub struct GetItemsQueryDTO {
     pub ids: Option<Vec<i64>>,
     pub price: Option<PriceGetItemsQueryDTO>,
     pub names: Option<NamesGetItemsQueryDTO>,
+    #[serde(default)]
+    pub warehouse_id: Option<i32>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -28,6 +36,12 @@ pub struct GetItemsRequestDTO {
     pub limit: i64,
     pub query: Option<GetItemsQueryDTO>,
     pub sort_by: Option<GetItemsSortByDTO>,
+    /// ISO 4217 code prices are filtered, sorted and returned in.
+    #[serde(default)]
+    pub currency: Option<String>,
+    /// Region code taxes are calculated for.
+    #[serde(default)]
+    pub region: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -40,6 +54,11 @@ pub struct GetItemsSortByDTO {
 pub struct SizeDTO {
     pub count: i32,
     pub name: String,
+    /// Set when a size promotion prices the size apart from the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sale_price: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion_id: Option<i64>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
@@ -49,7 +68,26 @@ pub struct ItemDTO {
     pub name: String,
     pub description: String,
     pub price: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sale_price: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax: Option<ItemTaxDTO>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_class_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub availability: Option<AvailabilityDTO>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_ship_date: Option<NaiveDate>,
     pub sizes: Vec<SizeDTO>,
+    /// Read-only: media is managed through the media endpoints.
+    #[serde(default, skip_deserializing)]
+    pub media: Vec<MediaDTO>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
@@ -57,6 +95,7 @@ pub struct CreateItemResponseDTO {
     pub id: i64,
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<GetItemsQuery> for GetItemsQueryDTO {
     fn into(self) -> GetItemsQuery {
         let mut query = GetItemsQuery {
@@ -64,11 +103,13 @@ impl Into<GetItemsQuery> for GetItemsQueryDTO {
         };
 
         query.ids = self.ids;
+        query.warehouse_id = self.warehouse_id;
 
         if let Some(price) = self.price {
             query.price = Some(PriceGetItemsQuery {
                 from: Some(price.from),
                 to: Some(price.to),
+                effective: price.effective,
             });
         }
 
@@ -83,6 +124,7 @@ impl Into<GetItemsQuery> for GetItemsQueryDTO {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<GetItemsSortBy> for GetItemsSortByDTO {
     fn into(self) -> GetItemsSortBy {
         GetItemsSortBy {
@@ -92,6 +134,7 @@ impl Into<GetItemsSortBy> for GetItemsSortByDTO {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<Item> for ItemDTO {
     fn into(self) -> Item {
         Item {
@@ -99,6 +142,8 @@ impl Into<Item> for ItemDTO {
             name: self.name,
             description: self.description,
             price: self.price,
+            category: self.category,
+            tax_class_id: self.tax_class_id,
             sizes: self
                 .sizes
                 .into_iter()
@@ -112,6 +157,14 @@ impl Into<Item> for ItemDTO {
                     )
                 })
                 .collect(),
+            media: vec![],
+            sale_price: None,
+            promotion_id: None,
+            size_sale_prices: vec![],
+            currency: self.currency,
+            tax: None,
+            availability: None,
+            expected_ship_date: None,
         }
     }
 }
@@ -123,14 +176,29 @@ impl From<Item> for ItemDTO {
             name: item.name,
             description: item.description,
             price: item.price,
+            sale_price: item.sale_price,
+            promotion_id: item.promotion_id,
+            currency: item.currency,
+            tax: item.tax.map(|t| t.into()),
+            category: item.category,
+            tax_class_id: item.tax_class_id,
+            availability: item.availability.map(|a| a.into()),
+            expected_ship_date: item.expected_ship_date,
             sizes: item
                 .sizes
                 .into_iter()
-                .map(|(size, count)| SizeDTO {
-                    name: size.name,
-                    count,
+                .map(|(size, count)| {
+                    let price = item.size_sale_prices.iter().find(|p| p.size == size.name);
+
+                    SizeDTO {
+                        sale_price: price.map(|p| p.sale_price),
+                        promotion_id: price.and_then(|p| p.promotion_id),
+                        name: size.name,
+                        count,
+                    }
                 })
                 .collect(),
+            media: item.media.into_iter().map(|m| m.into()).collect(),
         }
     }
 }
@@ -0,0 +1,51 @@
+use crate::domain::models::media::ItemMedia;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct MediaDTO {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub url: String,
+    #[serde(default)]
+    pub alt_text: String,
+    #[serde(default)]
+    pub position: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct UploadMediaQueryDTO {
+    pub alt: Option<String>,
+    pub position: Option<i32>,
+}
+
+impl From<MediaDTO> for ItemMedia {
+    fn from(dto: MediaDTO) -> ItemMedia {
+        ItemMedia {
+            id: dto.id.unwrap_or(0),
+            item_id: 0,
+            url: dto.url,
+            path: None,
+            alt_text: dto.alt_text,
+            position: dto.position,
+            width: dto.width,
+            height: dto.height,
+        }
+    }
+}
+
+impl From<ItemMedia> for MediaDTO {
+    fn from(media: ItemMedia) -> MediaDTO {
+        MediaDTO {
+            id: Some(media.id),
+            url: media.url,
+            alt_text: media.alt_text,
+            position: media.position,
+            width: media.width,
+            height: media.height,
+        }
+    }
+}
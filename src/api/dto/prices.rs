@@ -0,0 +1,77 @@
+use crate::domain::models::prices::{ItemPrices, PriceChange, PriceRecord, ScheduledPrice};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct PriceRecordDTO {
+    pub price: f64,
+    pub valid_from: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ScheduledPriceDTO {
+    pub id: i64,
+    pub price: f64,
+    pub starts_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ItemPricesDTO {
+    pub item_id: i64,
+    pub history: Vec<PriceRecordDTO>,
+    pub scheduled: Vec<ScheduledPriceDTO>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct SetPriceRequestDTO {
+    pub price: f64,
+    pub starts_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PriceChangeDTO {
+    Applied(PriceRecordDTO),
+    Scheduled(ScheduledPriceDTO),
+}
+
+impl From<PriceRecord> for PriceRecordDTO {
+    fn from(record: PriceRecord) -> PriceRecordDTO {
+        PriceRecordDTO {
+            price: record.price,
+            valid_from: record.valid_from,
+            valid_to: record.valid_to,
+        }
+    }
+}
+
+impl From<ScheduledPrice> for ScheduledPriceDTO {
+    fn from(scheduled: ScheduledPrice) -> ScheduledPriceDTO {
+        ScheduledPriceDTO {
+            id: scheduled.id,
+            price: scheduled.price,
+            starts_at: scheduled.starts_at,
+        }
+    }
+}
+
+impl From<ItemPrices> for ItemPricesDTO {
+    fn from(prices: ItemPrices) -> ItemPricesDTO {
+        ItemPricesDTO {
+            item_id: prices.item_id,
+            history: prices.history.into_iter().map(|r| r.into()).collect(),
+            scheduled: prices.scheduled.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+impl From<PriceChange> for PriceChangeDTO {
+    fn from(change: PriceChange) -> PriceChangeDTO {
+        match change {
+            PriceChange::Applied(record) => PriceChangeDTO::Applied(record.into()),
+            PriceChange::Scheduled(scheduled) => PriceChangeDTO::Scheduled(scheduled.into()),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::domain::models::promotions::{DiscountKind, Promotion, PromotionTarget};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountKindDTO {
+    Percentage,
+    Fixed,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PromotionTargetDTO {
+    Item { item_id: i64 },
+    Category { category: String },
+    Size { size: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct PromotionDTO {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    pub kind: DiscountKindDTO,
+    pub value: f64,
+    pub target: PromotionTargetDTO,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl From<PromotionDTO> for Promotion {
+    fn from(dto: PromotionDTO) -> Promotion {
+        Promotion {
+            id: dto.id.unwrap_or(0),
+            name: dto.name,
+            kind: match dto.kind {
+                DiscountKindDTO::Percentage => DiscountKind::Percentage,
+                DiscountKindDTO::Fixed => DiscountKind::Fixed,
+            },
+            value: dto.value,
+            target: match dto.target {
+                PromotionTargetDTO::Item { item_id } => PromotionTarget::Item(item_id),
+                PromotionTargetDTO::Category { category } => PromotionTarget::Category(category),
+                PromotionTargetDTO::Size { size } => PromotionTarget::Size(size),
+            },
+            starts_at: dto.starts_at,
+            ends_at: dto.ends_at,
+            priority: dto.priority,
+            active: dto.active,
+        }
+    }
+}
+
+impl From<Promotion> for PromotionDTO {
+    fn from(promotion: Promotion) -> PromotionDTO {
+        PromotionDTO {
+            id: Some(promotion.id),
+            name: promotion.name,
+            kind: match promotion.kind {
+                DiscountKind::Percentage => DiscountKindDTO::Percentage,
+                DiscountKind::Fixed => DiscountKindDTO::Fixed,
+            },
+            value: promotion.value,
+            target: match promotion.target {
+                PromotionTarget::Item(item_id) => PromotionTargetDTO::Item { item_id },
+                PromotionTarget::Category(category) => PromotionTargetDTO::Category { category },
+                PromotionTarget::Size(size) => PromotionTargetDTO::Size { size },
+            },
+            starts_at: promotion.starts_at,
+            ends_at: promotion.ends_at,
+            priority: promotion.priority,
+            active: promotion.active,
+        }
+    }
+}
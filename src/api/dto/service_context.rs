@@ -0,0 +1,57 @@
+use crate::domain::models::service_context::{MaintenanceScope, ServiceContext};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceScopeDTO {
+    Writes,
+    All,
+}
+
+/// Omitted fields keep their current value.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct SetMaintenanceRequestDTO {
+    pub maintenance: bool,
+    #[serde(default)]
+    pub scope: Option<MaintenanceScopeDTO>,
+    #[serde(default)]
+    pub retry_after_secs: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct MaintenanceDTO {
+    pub maintenance: bool,
+    pub scope: MaintenanceScopeDTO,
+    pub retry_after_secs: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<MaintenanceScopeDTO> for MaintenanceScope {
+    fn from(dto: MaintenanceScopeDTO) -> MaintenanceScope {
+        match dto {
+            MaintenanceScopeDTO::Writes => MaintenanceScope::Writes,
+            MaintenanceScopeDTO::All => MaintenanceScope::All,
+        }
+    }
+}
+
+impl From<MaintenanceScope> for MaintenanceScopeDTO {
+    fn from(scope: MaintenanceScope) -> MaintenanceScopeDTO {
+        match scope {
+            MaintenanceScope::Writes => MaintenanceScopeDTO::Writes,
+            MaintenanceScope::All => MaintenanceScopeDTO::All,
+        }
+    }
+}
+
+impl From<ServiceContext> for MaintenanceDTO {
+    fn from(service_context: ServiceContext) -> MaintenanceDTO {
+        MaintenanceDTO {
+            maintenance: service_context.maintenance,
+            scope: service_context.maintenance_scope.into(),
+            retry_after_secs: service_context.retry_after_secs,
+            updated_at: service_context.updated_at,
+        }
+    }
+}
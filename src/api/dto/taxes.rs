@@ -0,0 +1,72 @@
+use crate::domain::models::taxes::{ItemTax, TaxClass, TaxRate};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct TaxRateDTO {
+    pub region: String,
+    pub rate: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct TaxClassDTO {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    pub name: String,
+    #[serde(default)]
+    pub rates: Vec<TaxRateDTO>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct CreateTaxClassRequestDTO {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub struct SetTaxRateRequestDTO {
+    pub rate: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub struct SetItemTaxClassRequestDTO {
+    pub tax_class_id: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ItemTaxDTO {
+    pub region: String,
+    pub rate: f64,
+    pub net: f64,
+    pub tax: f64,
+    pub gross: f64,
+}
+
+impl From<TaxRate> for TaxRateDTO {
+    fn from(rate: TaxRate) -> TaxRateDTO {
+        TaxRateDTO {
+            region: rate.region,
+            rate: rate.rate,
+        }
+    }
+}
+
+impl From<TaxClass> for TaxClassDTO {
+    fn from(class: TaxClass) -> TaxClassDTO {
+        TaxClassDTO {
+            id: Some(class.id),
+            name: class.name,
+            rates: class.rates.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
+
+impl From<ItemTax> for ItemTaxDTO {
+    fn from(tax: ItemTax) -> ItemTaxDTO {
+        ItemTaxDTO {
+            region: tax.region,
+            rate: tax.rate,
+            net: tax.net,
+            tax: tax.tax,
+            gross: tax.gross,
+        }
+    }
+}
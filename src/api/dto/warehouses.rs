@@ -0,0 +1,179 @@
+use crate::domain::models::warehouses::{
+    LowStock, MovementReason, StockLevel, StockMovement, StockOperation, Warehouse,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MovementReasonDTO {
+    Receipt,
+    Sale,
+    Reservation,
+    #[default]
+    Adjustment,
+    Return,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct StockChangeQueryDTO {
+    #[serde(default)]
+    pub reason: MovementReasonDTO,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum StockOperationDTO {
+    Set { quantity: i32 },
+    Delta { delta: i32 },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct AdjustStockRequestDTO {
+    /// Defaults to the default warehouse.
+    #[serde(default)]
+    pub warehouse_id: Option<i32>,
+    #[serde(flatten)]
+    pub operation: StockOperationDTO,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub struct SetStockThresholdRequestDTO {
+    pub threshold: i32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LowStockDTO {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+    pub threshold: i32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct StockMovementDTO {
+    pub id: i64,
+    pub warehouse_id: i32,
+    pub size: String,
+    pub delta: i32,
+    pub quantity_after: i32,
+    pub reason: MovementReasonDTO,
+    pub actor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct WarehouseDTO {
+    pub id: i32,
+    pub name: String,
+    pub is_default: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct CreateWarehouseRequestDTO {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct StockLevelDTO {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warehouse_id: Option<i32>,
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+}
+
+impl From<Warehouse> for WarehouseDTO {
+    fn from(warehouse: Warehouse) -> WarehouseDTO {
+        WarehouseDTO {
+            id: warehouse.id,
+            name: warehouse.name,
+            is_default: warehouse.is_default,
+        }
+    }
+}
+
+impl From<StockLevel> for StockLevelDTO {
+    fn from(level: StockLevel) -> StockLevelDTO {
+        StockLevelDTO {
+            warehouse_id: Some(level.warehouse_id),
+            item_id: level.item_id,
+            size: level.size,
+            quantity: level.quantity,
+        }
+    }
+}
+
+impl From<StockLevelDTO> for StockLevel {
+    fn from(dto: StockLevelDTO) -> StockLevel {
+        StockLevel {
+            warehouse_id: dto.warehouse_id.unwrap_or(0),
+            item_id: dto.item_id,
+            size: dto.size,
+            quantity: dto.quantity,
+        }
+    }
+}
+
+impl From<LowStock> for LowStockDTO {
+    fn from(low: LowStock) -> LowStockDTO {
+        LowStockDTO {
+            item_id: low.item_id,
+            size: low.size,
+            quantity: low.quantity,
+            threshold: low.threshold,
+        }
+    }
+}
+
+impl From<StockOperationDTO> for StockOperation {
+    fn from(dto: StockOperationDTO) -> StockOperation {
+        match dto {
+            StockOperationDTO::Set { quantity } => StockOperation::Set(quantity),
+            StockOperationDTO::Delta { delta } => StockOperation::Delta(delta),
+        }
+    }
+}
+
+impl From<MovementReasonDTO> for MovementReason {
+    fn from(dto: MovementReasonDTO) -> MovementReason {
+        match dto {
+            MovementReasonDTO::Receipt => MovementReason::Receipt,
+            MovementReasonDTO::Sale => MovementReason::Sale,
+            MovementReasonDTO::Reservation => MovementReason::Reservation,
+            MovementReasonDTO::Adjustment => MovementReason::Adjustment,
+            MovementReasonDTO::Return => MovementReason::Return,
+        }
+    }
+}
+
+impl From<MovementReason> for MovementReasonDTO {
+    fn from(reason: MovementReason) -> MovementReasonDTO {
+        match reason {
+            MovementReason::Receipt => MovementReasonDTO::Receipt,
+            MovementReason::Sale => MovementReasonDTO::Sale,
+            MovementReason::Reservation => MovementReasonDTO::Reservation,
+            MovementReason::Adjustment => MovementReasonDTO::Adjustment,
+            MovementReason::Return => MovementReasonDTO::Return,
+        }
+    }
+}
+
+impl From<StockMovement> for StockMovementDTO {
+    fn from(movement: StockMovement) -> StockMovementDTO {
+        StockMovementDTO {
+            id: movement.id,
+            warehouse_id: movement.warehouse_id,
+            size: movement.size,
+            delta: movement.delta,
+            quantity_after: movement.quantity_after,
+            reason: movement.reason.into(),
+            actor: movement.actor,
+            note: movement.note,
+            created_at: movement.created_at,
+        }
+    }
+}
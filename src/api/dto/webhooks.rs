@@ -0,0 +1,112 @@
+use crate::domain::models::webhooks::{DeliveryStatus, WebhookDelivery, WebhookSubscription};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_active() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct WebhookSubscriptionRequestDTO {
+    pub url: String,
+    /// Generated on create and kept on update when left out.
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Empty subscribes to every event type.
+    #[serde(default)]
+    pub event_types: Vec<String>,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct WebhookSubscriptionDTO {
+    pub id: i64,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    /// Only returned when the subscription is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatusDTO {
+    Pending,
+    Delivered,
+    Dead,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct WebhookDeliveryDTO {
+    pub id: i64,
+    pub event_id: i64,
+    pub event_type: String,
+    pub status: DeliveryStatusDTO,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_status_code: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub payload: serde_json::Value,
+}
+
+impl WebhookSubscriptionRequestDTO {
+    pub fn into_subscription(self, id: i64) -> WebhookSubscription {
+        WebhookSubscription {
+            id,
+            url: self.url,
+            secret: self.secret.unwrap_or_default(),
+            event_types: self.event_types,
+            active: self.active,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionDTO {
+    fn from(subscription: WebhookSubscription) -> WebhookSubscriptionDTO {
+        WebhookSubscriptionDTO {
+            id: subscription.id,
+            url: subscription.url,
+            event_types: subscription.event_types,
+            active: subscription.active,
+            created_at: subscription.created_at,
+            secret: None,
+        }
+    }
+}
+
+impl From<DeliveryStatus> for DeliveryStatusDTO {
+    fn from(status: DeliveryStatus) -> DeliveryStatusDTO {
+        match status {
+            DeliveryStatus::Pending => DeliveryStatusDTO::Pending,
+            DeliveryStatus::Delivered => DeliveryStatusDTO::Delivered,
+            DeliveryStatus::Dead => DeliveryStatusDTO::Dead,
+        }
+    }
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDTO {
+    fn from(delivery: WebhookDelivery) -> WebhookDeliveryDTO {
+        WebhookDeliveryDTO {
+            id: delivery.id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            status: delivery.status.into(),
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at,
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+            payload: delivery.payload,
+        }
+    }
+}
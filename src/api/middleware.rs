@@ -1 +1,653 @@
-// for middleware
+use crate::api::request::Principal;
+use crate::domain::constants::{REQUEST_ID_HEADER, REQUEST_ID_MAX_LENGTH};
+use crate::domain::error::{ApiError, CommonError, CommonErrorKind};
+use crate::domain::models::service_context::ServiceContext;
+use crate::domain::services::service_context::ServiceContextService;
+use crate::infrastructure::metrics::Metrics;
+use crate::logging::{current_request_id, REQUEST_ID};
+use crate::telemetry::HeaderExtractor;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue, AUTHORIZATION, RETRY_AFTER, WWW_AUTHENTICATE};
+use actix_web::http::Method;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpMessage, HttpResponse};
+use log::{log, warn, Level};
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::sync::RwLock;
+use std::time::Instant;
+use tracing::{field, info_span, Instrument};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+/// Label for requests no route matches, so scans of random paths cannot
+/// blow up the number of series.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Only these routes are subject to maintenance; the admin endpoints are not,
+/// so maintenance can always be turned off again.
+const MAINTENANCE_PREFIX: &str = "/v1/";
+const MAINTENANCE_EXEMPT_PREFIX: &str = "/v1/admin/";
+
+/// Routes that take a POST but change nothing, so stay up during maintenance
+/// of writes.
+const READ_ONLY_POST_ROUTES: &[&str] = &["/v1/items/validate-cart"];
+
+/// Takes the client's `X-Request-Id` when it is sane, generates one
+/// otherwise, echoes it on the response and scopes the request's task to it,
+/// so every log line and error body written while serving it carries it.
+/// Wrap it outside of the other middleware.
+pub async fn request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let request_id = req
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| {
+            !v.is_empty()
+                && v.len() <= REQUEST_ID_MAX_LENGTH
+                && v.bytes().all(|b| b.is_ascii_graphic())
+        })
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let header_value = HeaderValue::from_str(&request_id).unwrap();
+
+    let mut result = REQUEST_ID.scope(request_id, next.call(req)).await;
+
+    if let Ok(res) = &mut result {
+        res.headers_mut().insert(
+            HeaderName::from_bytes(REQUEST_ID_HEADER.as_bytes()).unwrap(),
+            header_value,
+        );
+    }
+    result
+}
+
+/// Opens the server span of a request, continuing the caller's trace when it
+/// sends a W3C `traceparent` header. Spans of handlers, services and queries
+/// nest under it. Wrap it inside `request_id` and outside `access_log`, so
+/// access lines carry the trace id.
+pub async fn trace_request(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let parent = TraceContextPropagator::new().extract(&HeaderExtractor(req.headers()));
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+    let span = info_span!(
+        "http.request",
+        otel.name = format!("{} {}", req.method(), route),
+        otel.kind = "server",
+        http.request.method = req.method().as_str(),
+        http.route = route,
+        url.path = req.path(),
+        request_id = current_request_id(),
+        http.response.status_code = field::Empty,
+        otel.status_code = field::Empty,
+    );
+    span.set_parent(parent);
+
+    let result = next.call(req).instrument(span.clone()).await;
+
+    let status = match &result {
+        Ok(res) => res.status(),
+        Err(e) => e.as_response_error().status_code(),
+    };
+    span.record("http.response.status_code", i64::from(status.as_u16()));
+    if status.is_server_error() {
+        span.record("otel.status_code", "ERROR");
+    }
+    result
+}
+
+/// Logs one line per request with route, status, latency and, for failed
+/// ones, the error code. Replaces `middleware::Logger`, whose lines carry no
+/// fields.
+pub async fn access_log(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let method = req.method().to_string();
+    let path = req.path().to_string();
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+    let started = Instant::now();
+
+    let result = next.call(req).await;
+
+    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+    let (status, error) = match &result {
+        Ok(res) => (res.status(), res.response().error()),
+        Err(e) => (e.as_response_error().status_code(), Some(e)),
+    };
+    let level = if status.is_server_error() { Level::Error } else { Level::Info };
+
+    match error {
+        Some(error) => {
+            // Errors not raised by our handlers, like malformed JSON bodies.
+            let error_code = match error.as_error::<ApiError>() {
+                Some(e) => format!("{:?}", e.code()),
+                None => "Other".to_string(),
+            };
+            log!(
+                target: "access",
+                level,
+                method = method.as_str(),
+                route = route.as_str(),
+                status = status.as_u16(),
+                latency_ms = latency_ms,
+                error_code = error_code.as_str(),
+                error = error.to_string().as_str();
+                "{} {} {}", method, path, status.as_u16()
+            );
+        }
+        None => log!(
+            target: "access",
+            level,
+            method = method.as_str(),
+            route = route.as_str(),
+            status = status.as_u16(),
+            latency_ms = latency_ms;
+            "{} {} {}", method, path, status.as_u16()
+        ),
+    }
+
+    result
+}
+
+/// Counts requests and records their latency by route pattern, when the app
+/// has `Metrics` registered. Use with `middleware::from_fn`.
+pub async fn record_metrics(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let metrics = match req.app_data::<web::Data<Metrics>>() {
+        Some(metrics) => metrics.clone(),
+        None => return next.call(req).await,
+    };
+    let method = req.method().to_string();
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+    let started = Instant::now();
+
+    let result = next.call(req).await;
+
+    let status = match &result {
+        Ok(res) => res.status(),
+        Err(e) => e.as_response_error().status_code(),
+    };
+    metrics
+        .http_requests
+        .with_label_values(&[&method, &route, status.as_str()])
+        .inc();
+    metrics
+        .http_request_duration
+        .with_label_values(&[&method, &route])
+        .observe(started.elapsed().as_secs_f64());
+
+    result
+}
+
+/// Bearer token of the admin routes; `None` refuses every admin request.
+#[derive(Clone, Debug)]
+pub struct AdminToken(pub Option<String>);
+
+/// Compares in constant time, so response times leak nothing about the token.
+fn token_matches(expected: &str, given: &str) -> bool {
+    expected.len() == given.len()
+        && expected
+            .bytes()
+            .zip(given.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// Answers 401 to requests without `Authorization: Bearer` and the
+/// configured [`AdminToken`], and marks the others as [`Principal::Admin`].
+/// Without a token, or when none is registered, every request is refused.
+/// Wrap the scopes of the routes only operators may call with it, so the
+/// router decides what is guarded.
+pub async fn admin_auth(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let expected = req
+        .app_data::<web::Data<AdminToken>>()
+        .and_then(|token| token.0.clone());
+    let given = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    let message = match (expected, given) {
+        (Some(expected), Some(given)) if token_matches(&expected, given.trim()) => {
+            req.extensions_mut().insert(Principal::Admin);
+            return next.call(req).await.map(ServiceResponse::map_into_left_body);
+        }
+        (None, _) => "admin routes are off: no admin token is configured",
+        (Some(_), _) => "a valid admin bearer token is required",
+    };
+
+    let mut res = HttpResponse::from_error(ApiError::from(CommonError {
+        message: message.to_string(),
+        code: CommonErrorKind::Unauthorized,
+    }));
+    res.headers_mut()
+        .insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+
+    Ok(req.into_response(res).map_into_right_body())
+}
+
+/// Route pattern the router will match the request to. `match_pattern`
+/// looks at the raw path before routing, so percent-encoding dodges it; the
+/// router matches the decoded path.
+fn routed_pattern(req: &ServiceRequest) -> Option<String> {
+    req.resource_map().match_pattern(req.match_info().as_str())
+}
+
+/// Service context the maintenance check last read, used while it cannot be
+/// read again.
+#[derive(Default)]
+pub struct LastServiceContext(RwLock<Option<ServiceContext>>);
+
+/// Refuses requests with 503 and `Retry-After` while maintenance is on:
+/// every API request, or only those changing data, depending on its scope.
+/// Health, metrics and admin endpoints stay up. When the service context
+/// cannot be read, the [`LastServiceContext`] is used; requests are only let
+/// through when none was read yet.
+pub async fn maintenance(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let service_context_service = match req.app_data::<web::Data<dyn ServiceContextService>>() {
+        Some(service) => service.clone(),
+        None => return next.call(req).await.map(ServiceResponse::map_into_left_body),
+    };
+    let route = match routed_pattern(&req) {
+        Some(route)
+            if route.starts_with(MAINTENANCE_PREFIX)
+                && !route.starts_with(MAINTENANCE_EXEMPT_PREFIX) =>
+        {
+            route
+        }
+        _ => return next.call(req).await.map(ServiceResponse::map_into_left_body),
+    };
+
+    let last = req.app_data::<web::Data<LastServiceContext>>().cloned();
+    let service_context = match service_context_service.get_service_context().await {
+        Ok(service_context) => {
+            if let Some(last) = &last {
+                *last.0.write().unwrap() = Some(service_context.clone());
+            }
+            service_context
+        }
+        Err(e) => match last.and_then(|last| last.0.read().unwrap().clone()) {
+            Some(service_context) => {
+                warn!("cannot read the service context, using the last known one: {}", e);
+                service_context
+            }
+            None => {
+                warn!("cannot read the service context, skipping the maintenance check: {}", e);
+                return next.call(req).await.map(ServiceResponse::map_into_left_body);
+            }
+        },
+    };
+    let read_only = matches!(*req.method(), Method::GET | Method::HEAD | Method::OPTIONS)
+        || (*req.method() == Method::POST && READ_ONLY_POST_ROUTES.contains(&route.as_str()));
+    if !service_context.refuses(read_only) {
+        return next.call(req).await.map(ServiceResponse::map_into_left_body);
+    }
+
+    let mut res = HttpResponse::from_error(ApiError::from(CommonError {
+        message: "the service is under maintenance".to_string(),
+        code: CommonErrorKind::Unavailable,
+    }));
+    res.headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(service_context.retry_after_secs));
+
+    Ok(req.into_response(res).map_into_right_body())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::service_context::{MaintenanceScope, ServiceContext};
+    use crate::domain::services::service_context::MockServiceContextService;
+    use crate::logging::current_request_id;
+    use actix_web::middleware::from_fn;
+    use actix_web::App;
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    #[actix_web::test]
+    async fn test_should_tag_requests_with_an_id() {
+        let _ = env_logger::try_init();
+
+        let app = actix_web::test::init_service(
+            App::new()
+                .wrap(from_fn(access_log))
+                .wrap(from_fn(request_id))
+                .service(
+                    web::scope("")
+                        .route(
+                            "/id",
+                            web::get().to(|| async { current_request_id().unwrap_or_default() }),
+                        )
+                        .route(
+                            "/gone",
+                            web::get().to(|| async {
+                                Err::<HttpResponse, ApiError>(
+                                    CommonError {
+                                        message: "gone".to_string(),
+                                        code: CommonErrorKind::NotFound,
+                                    }
+                                    .into(),
+                                )
+                            }),
+                        ),
+                ),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/id")
+            .insert_header((REQUEST_ID_HEADER, "abc-123"))
+            .to_request();
+        let resp = actix_web::test::call_service(&app, req).await;
+        assert_eq!(resp.headers().get(REQUEST_ID_HEADER).unwrap(), "abc-123");
+        assert_eq!(actix_web::test::read_body(resp).await, "abc-123");
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/id")
+            .insert_header((REQUEST_ID_HEADER, "has spaces"))
+            .to_request();
+        let resp = actix_web::test::call_service(&app, req).await;
+        let generated = resp.headers().get(REQUEST_ID_HEADER).unwrap().to_str().unwrap().to_string();
+        assert!(Uuid::parse_str(&generated).is_ok());
+        assert_eq!(actix_web::test::read_body(resp).await, generated.as_str());
+
+        let req = actix_web::test::TestRequest::get().uri("/gone").to_request();
+        let resp = actix_web::test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 410);
+        let request_id = resp.headers().get(REQUEST_ID_HEADER).unwrap().to_str().unwrap().to_string();
+        let body: serde_json::Value = actix_web::test::read_body_json(resp).await;
+        assert_eq!(
+            body,
+            serde_json::json!({"message": "gone", "code": "NotFound", "request_id": request_id})
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_continue_the_callers_trace() {
+        let (subscriber, provider, buffer) = crate::telemetry::tests::buffered_subscriber();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let app = actix_web::test::init_service(
+            App::new()
+                .wrap(from_fn(trace_request))
+                .wrap(from_fn(request_id))
+                .service(
+                    web::scope("")
+                        .route(
+                            "/items/{id}",
+                            web::get().to(|| async {
+                                info_span!("handler").in_scope(|| HttpResponse::Ok().finish())
+                            }),
+                        )
+                        .route(
+                            "/broken",
+                            web::get().to(|| async { HttpResponse::InternalServerError().finish() }),
+                        ),
+                ),
+        )
+        .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/items/7")
+            .insert_header((
+                "traceparent",
+                "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            ))
+            .insert_header((REQUEST_ID_HEADER, "abc-123"))
+            .to_request();
+        actix_web::test::call_service(&app, req).await;
+        let req = actix_web::test::TestRequest::get().uri("/broken").to_request();
+        actix_web::test::call_service(&app, req).await;
+        provider.force_flush();
+
+        let spans = buffer.spans();
+        assert_eq!(spans.len(), 3);
+        let (handler, request, broken) = (&spans[0], &spans[1], &spans[2]);
+        assert_eq!(handler["parent_span_id"], request["span_id"]);
+        assert_eq!(request["name"], "GET /items/{id}");
+        assert_eq!(request["kind"], "server");
+        assert_eq!(request["trace_id"], "0af7651916cd43dd8448eb211c80319c");
+        assert_eq!(request["parent_span_id"], "b7ad6b7169203331");
+        assert_eq!(request["attributes"]["http.route"], "/items/{id}");
+        assert_eq!(request["attributes"]["http.response.status_code"], 200);
+        assert_eq!(request["attributes"]["request_id"], "abc-123");
+        assert!(broken.get("parent_span_id").is_none());
+        assert_ne!(broken["trace_id"], request["trace_id"]);
+        assert_eq!(broken["status"], "error");
+    }
+
+    #[actix_web::test]
+    async fn test_should_refuse_requests_during_maintenance() {
+        let _ = env_logger::try_init();
+
+        for (scope, refused) in [
+            (
+                MaintenanceScope::Writes,
+                vec!["POST /v1/items", "POST /%761/items", "PUT /v1/warehouses/1/stock"],
+            ),
+            (
+                MaintenanceScope::All,
+                vec![
+                    "GET /v1/items",
+                    "POST /v1/items/validate-cart",
+                    "POST /v1/items",
+                    "POST /%761/items",
+                    "PUT /v1/warehouses/1/stock",
+                ],
+            ),
+        ] {
+            let mut mock_context = MockServiceContextService::new();
+            mock_context.expect_get_service_context().returning(move || {
+                Box::pin(async move {
+                    Ok(ServiceContext {
+                        id: 1,
+                        maintenance: true,
+                        maintenance_scope: scope,
+                        retry_after_secs: 120,
+                        updated_at: Utc::now(),
+                    })
+                })
+            });
+            let service_context_service: Arc<dyn ServiceContextService> = Arc::new(mock_context);
+            let ok = || async { HttpResponse::Ok().finish() };
+            let app = actix_web::test::init_service(
+                App::new()
+                    .app_data(web::Data::from(service_context_service))
+                    .wrap(from_fn(maintenance))
+                    .service(
+                        web::scope("")
+                            .route("/healthz", web::get().to(ok))
+                            .route("/v1/items", web::get().to(ok))
+                            .route("/v1/items", web::post().to(ok))
+                            .route("/v1/items/validate-cart", web::post().to(ok))
+                            .route("/v1/warehouses/{id}/stock", web::put().to(ok))
+                            .route("/v1/admin/maintenance", web::put().to(ok)),
+                    ),
+            )
+            .await;
+
+            for request in [
+                "GET /healthz",
+                "GET /v1/items",
+                "POST /v1/items",
+                "POST /%761/items",
+                "POST /v1/items/validate-cart",
+                "PUT /v1/warehouses/1/stock",
+                "PUT /v1/admin/maintenance",
+                "PUT /v1/%61dmin/maintenance",
+            ] {
+                let (method, uri) = request.split_once(' ').unwrap();
+                let req = actix_web::test::TestRequest::default()
+                    .method(Method::from_bytes(method.as_bytes()).unwrap())
+                    .uri(uri)
+                    .to_request();
+                let resp = actix_web::test::call_service(&app, req).await;
+
+                if refused.contains(&request) {
+                    assert_eq!(resp.status(), 503, "{:?} {}", scope, request);
+                    assert_eq!(resp.headers().get(RETRY_AFTER).unwrap(), "120");
+                    let body: serde_json::Value = actix_web::test::read_body_json(resp).await;
+                    assert_eq!(body["code"], "Unavailable");
+                } else {
+                    assert_eq!(resp.status(), 200, "{:?} {}", scope, request);
+                }
+            }
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_should_use_the_last_known_context_when_it_cannot_be_read() {
+        let _ = env_logger::try_init();
+
+        let mut mock_context = MockServiceContextService::new();
+        let mut reads = 0;
+        mock_context.expect_get_service_context().returning(move || {
+            reads += 1;
+            let read = reads;
+            Box::pin(async move {
+                match read {
+                    1 => Ok(ServiceContext {
+                        id: 1,
+                        maintenance: true,
+                        maintenance_scope: MaintenanceScope::All,
+                        retry_after_secs: 120,
+                        updated_at: Utc::now(),
+                    }),
+                    _ => Err(CommonError {
+                        message: "database is down".to_string(),
+                        code: CommonErrorKind::Unknown,
+                    }),
+                }
+            })
+        });
+        let service_context_service: Arc<dyn ServiceContextService> = Arc::new(mock_context);
+        let new_app = |last: Option<web::Data<LastServiceContext>>| {
+            let app = App::new().app_data(web::Data::from(service_context_service.clone()));
+            match last {
+                Some(last) => app.app_data(last),
+                None => app,
+            }
+            .wrap(from_fn(maintenance))
+            .service(web::scope("").route("/v1/items", web::get().to(|| async { HttpResponse::Ok().finish() })))
+        };
+
+        let remembering =
+            actix_web::test::init_service(new_app(Some(web::Data::new(LastServiceContext::default())))).await;
+        for _ in 0..2 {
+            let req = actix_web::test::TestRequest::get().uri("/v1/items").to_request();
+            assert_eq!(actix_web::test::call_service(&remembering, req).await.status(), 503);
+        }
+
+        // Nothing was read before, so there is nothing to refuse on.
+        let forgetful = actix_web::test::init_service(new_app(None)).await;
+        let req = actix_web::test::TestRequest::get().uri("/v1/items").to_request();
+        assert_eq!(actix_web::test::call_service(&forgetful, req).await.status(), 200);
+    }
+
+    #[actix_web::test]
+    async fn test_should_count_requests_by_route_pattern() {
+        let _ = env_logger::try_init();
+
+        let metrics = Arc::new(Metrics::new());
+        let app = actix_web::test::init_service(
+            App::new()
+                .app_data(web::Data::from(metrics.clone()))
+                .wrap(from_fn(record_metrics))
+                .service(web::scope("").route(
+                    "/items/{id}",
+                    web::get().to(|| async { HttpResponse::Ok().finish() }),
+                )),
+        )
+        .await;
+
+        for uri in ["/items/1", "/items/2", "/nope"] {
+            let req = actix_web::test::TestRequest::get().uri(uri).to_request();
+            actix_web::test::call_service(&app, req).await;
+        }
+
+        let counted = |route: &str, status: &str| {
+            metrics
+                .http_requests
+                .with_label_values(&["GET", route, status])
+                .get()
+        };
+        assert_eq!(counted("/items/{id}", "200"), 2);
+        assert_eq!(counted(UNMATCHED_ROUTE, "404"), 1);
+        assert_eq!(
+            metrics
+                .http_request_duration
+                .with_label_values(&["GET", "/items/{id}"])
+                .get_sample_count(),
+            2
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_should_guard_admin_routes_with_the_token() {
+        let ok = || async { HttpResponse::Ok().finish() };
+        let app = |token: Option<&str>| {
+            App::new()
+                .app_data(web::Data::new(AdminToken(token.map(str::to_string))))
+                .service(
+                    web::scope("/v1/admin")
+                        .wrap(from_fn(admin_auth))
+                        .route("/maintenance", web::put().to(ok)),
+                )
+                .service(web::scope("").route("/v1/items", web::get().to(ok)))
+        };
+        let put = |authorization: Option<&str>| {
+            let req = actix_web::test::TestRequest::put().uri("/v1/admin/maintenance");
+            match authorization {
+                Some(value) => req.insert_header((AUTHORIZATION, value)),
+                None => req,
+            }
+            .to_request()
+        };
+
+        let guarded = actix_web::test::init_service(app(Some("0123456789abcdef"))).await;
+        for (authorization, status) in [
+            (Some("Bearer 0123456789abcdef"), 200),
+            (Some("Bearer 0123456789abcdeF"), 401),
+            (Some("0123456789abcdef"), 401),
+            (None, 401),
+        ] {
+            let resp = actix_web::test::call_service(&guarded, put(authorization)).await;
+            assert_eq!(resp.status(), status, "{:?}", authorization);
+        }
+        let req = actix_web::test::TestRequest::get().uri("/v1/items").to_request();
+        assert_eq!(actix_web::test::call_service(&guarded, req).await.status(), 200);
+        // The router decodes paths before matching, so must the guard.
+        for uri in ["/v1/%61dmin/maintenance", "/v1/admin/%6daintenance", "/v1/%61%64%6d%69%6e/maintenance"] {
+            let req = actix_web::test::TestRequest::put().uri(uri).to_request();
+            assert_eq!(actix_web::test::call_service(&guarded, req).await.status(), 401, "{}", uri);
+        }
+
+        let off = actix_web::test::init_service(app(None)).await;
+        let resp = actix_web::test::call_service(&off, put(Some("Bearer 0123456789abcdef"))).await;
+        assert_eq!(resp.status(), 401);
+        assert_eq!(resp.headers().get(WWW_AUTHENTICATE).unwrap(), "Bearer");
+        let body: serde_json::Value = actix_web::test::read_body_json(resp).await;
+        assert_eq!(body["code"], "Unauthorized");
+    }
+}
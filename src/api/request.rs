@@ -0,0 +1,47 @@
+use crate::domain::constants::{ACTOR_ADMIN, ACTOR_DEFAULT, ACTOR_HEADER, ACTOR_UNVERIFIED_PREFIX};
+use actix_web::{HttpMessage, HttpRequest};
+
+/// Caller a request was authenticated as, set by the auth middleware.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Principal {
+    /// Holder of the admin token.
+    Admin,
+}
+
+/// Who performs a change: the authenticated principal if there is one.
+/// Other requests can't be authenticated yet, so the actor header they send
+/// is recorded as is, marked as unverified.
+pub fn request_actor(req: &HttpRequest) -> String {
+    if let Some(Principal::Admin) = req.extensions().get::<Principal>() {
+        return ACTOR_ADMIN.to_string();
+    }
+
+    let claimed = req
+        .headers()
+        .get(ACTOR_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| ACTOR_DEFAULT.to_string());
+
+    format!("{}{}", ACTOR_UNVERIFIED_PREFIX, claimed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn test_should_mark_claimed_actors_unverified() {
+        let req = TestRequest::default().insert_header((ACTOR_HEADER, " alice ")).to_http_request();
+        assert_eq!(request_actor(&req), "unverified:alice");
+
+        let req = TestRequest::default().to_http_request();
+        assert_eq!(request_actor(&req), "unverified:anonymous");
+
+        let req = TestRequest::default().insert_header((ACTOR_HEADER, "alice")).to_http_request();
+        req.extensions_mut().insert(Principal::Admin);
+        assert_eq!(request_actor(&req), "admin");
+    }
+}
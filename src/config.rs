@@ -0,0 +1,623 @@
+use crate::domain::constants::{
+    ADMIN_TOKEN, ADMIN_TOKEN_MIN_LEN, BASE_CURRENCY, BASE_CURRENCY_DEFAULT, CONFIG_FILE, EVENT_RELAY_INTERVAL_SECS,
+    EVENT_RELAY_INTERVAL_SECS_DEFAULT, ITEM_CACHE_CAPACITY, ITEM_CACHE_CAPACITY_DEFAULT,
+    ITEM_CACHE_TTL_SECS, ITEM_CACHE_TTL_SECS_DEFAULT, LOG_FORMAT, LOG_LEVEL, LOG_LEVEL_DEFAULT,
+    LOW_STOCK_WEBHOOK_URL, MEDIA_BASE_URL, MEDIA_BASE_URL_DEFAULT, MEDIA_STORAGE_PATH,
+    MEDIA_STORAGE_PATH_DEFAULT, POSTGRESQL_CONNECTION_TIMEOUT_SECS,
+    POSTGRESQL_CONNECTION_TIMEOUT_SECS_DEFAULT, POSTGRESQL_DB_URI, POSTGRESQL_POOL_SIZE,
+    POSTGRESQL_POOL_SIZE_DEFAULT, PRICE_SCHEDULER_INTERVAL_SECS,
+    PRICE_SCHEDULER_INTERVAL_SECS_DEFAULT, RUN_MIGRATIONS, SERVER_HOST, SERVER_HOST_DEFAULT,
+    SERVER_KEEP_ALIVE_SECS, SERVER_KEEP_ALIVE_SECS_DEFAULT, SERVER_PORT, SERVER_PORT_DEFAULT,
+    SERVER_REQUEST_TIMEOUT_MS, SERVER_REQUEST_TIMEOUT_MS_DEFAULT, SERVER_SHUTDOWN_TIMEOUT_SECS,
+    SERVER_SHUTDOWN_TIMEOUT_SECS_DEFAULT, SERVER_WORKERS, SERVER_WORKERS_DEFAULT,
+    TRACING_EXPORTER, TRACING_OTLP_ENDPOINT, TRACING_OTLP_ENDPOINT_DEFAULT, TRACING_SAMPLE_RATIO,
+    TRACING_SAMPLE_RATIO_DEFAULT, TRACING_SERVICE_NAME, TRACING_SERVICE_NAME_DEFAULT,
+    WEBHOOK_DISPATCH_INTERVAL_SECS, WEBHOOK_DISPATCH_INTERVAL_SECS_DEFAULT,
+};
+use crate::logging::LogFormat;
+use crate::services::currencies::normalize_currency;
+use crate::telemetry::TraceExporter;
+use clap::{Parser, Subcommand};
+use log::LevelFilter;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fmt, fs};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Items service API")]
+pub struct Cli {
+    /// TOML configuration file; env vars and flags override its values.
+    #[arg(short, long, env = CONFIG_FILE)]
+    pub config: Option<PathBuf>,
+    /// Address the HTTP server binds to.
+    #[arg(long)]
+    pub host: Option<String>,
+    #[arg(long)]
+    pub port: Option<u16>,
+    /// HTTP worker threads, 0 for one per physical core.
+    #[arg(long)]
+    pub workers: Option<usize>,
+    /// Log filter such as `info` or `info,actix_web=debug`.
+    #[arg(long)]
+    pub log_level: Option<String>,
+    /// `text` or `json`.
+    #[arg(long)]
+    pub log_format: Option<LogFormat>,
+    #[arg(long)]
+    pub database_url: Option<String>,
+    /// Maximum connections in the database pool.
+    #[arg(long)]
+    pub pool_size: Option<u32>,
+    /// `none`, `stdout` or `otlp`.
+    #[arg(long)]
+    pub trace_exporter: Option<TraceExporter>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Manages the embedded database migrations.
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum MigrateAction {
+    /// Applies every pending migration.
+    Up,
+    /// Reverts the newest applied migration.
+    Down,
+    /// Lists migrations and whether they are applied.
+    Status,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub base_currency: String,
+    /// Url receiving low-stock events; they are only logged when unset.
+    pub low_stock_webhook_url: Option<String>,
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub media: MediaConfig,
+    pub cache: CacheConfig,
+    pub jobs: JobsConfig,
+    pub tracing: TracingConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    pub workers: usize,
+    pub log_level: String,
+    pub log_format: LogFormat,
+    pub keep_alive_secs: u64,
+    pub request_timeout_ms: u64,
+    pub shutdown_timeout_secs: u64,
+    /// Bearer token of the admin routes, which are refused while it is unset.
+    pub admin_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub pool_size: u32,
+    pub connection_timeout_secs: u64,
+    pub run_migrations: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MediaConfig {
+    pub storage_path: String,
+    /// Prefix of media URLs. A path such as `/media` is served by this service
+    /// from `storage_path`; an absolute URL leaves that to another server.
+    pub base_url: String,
+}
+
+impl MediaConfig {
+    pub fn is_served_locally(&self) -> bool {
+        self.base_url.starts_with('/')
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Entries of the item read cache; 0 turns it off.
+    pub capacity: usize,
+    pub ttl_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JobsConfig {
+    pub price_scheduler_interval_secs: u64,
+    pub event_relay_interval_secs: u64,
+    pub webhook_dispatch_interval_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TracingConfig {
+    pub exporter: TraceExporter,
+    pub otlp_endpoint: String,
+    pub service_name: String,
+    /// Share of traces started here that are recorded, from 0 to 1.
+    pub sample_ratio: f64,
+}
+
+/// Every problem found while loading the configuration, so they can all be
+/// fixed in one go.
+#[derive(Debug, PartialEq)]
+pub struct ConfigError {
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid configuration:")?;
+        for error in &self.errors {
+            write!(f, "\n  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_currency: BASE_CURRENCY_DEFAULT.to_string(),
+            low_stock_webhook_url: None,
+            server: ServerConfig::default(),
+            database: DatabaseConfig::default(),
+            media: MediaConfig::default(),
+            cache: CacheConfig::default(),
+            jobs: JobsConfig::default(),
+            tracing: TracingConfig::default(),
+        }
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            host: SERVER_HOST_DEFAULT.to_string(),
+            port: SERVER_PORT_DEFAULT,
+            workers: SERVER_WORKERS_DEFAULT,
+            log_level: LOG_LEVEL_DEFAULT.to_string(),
+            log_format: LogFormat::Text,
+            keep_alive_secs: SERVER_KEEP_ALIVE_SECS_DEFAULT,
+            request_timeout_ms: SERVER_REQUEST_TIMEOUT_MS_DEFAULT,
+            shutdown_timeout_secs: SERVER_SHUTDOWN_TIMEOUT_SECS_DEFAULT,
+            admin_token: None,
+        }
+    }
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        DatabaseConfig {
+            url: String::new(),
+            pool_size: POSTGRESQL_POOL_SIZE_DEFAULT,
+            connection_timeout_secs: POSTGRESQL_CONNECTION_TIMEOUT_SECS_DEFAULT,
+            run_migrations: false,
+        }
+    }
+}
+
+impl Default for MediaConfig {
+    fn default() -> Self {
+        MediaConfig {
+            storage_path: MEDIA_STORAGE_PATH_DEFAULT.to_string(),
+            base_url: MEDIA_BASE_URL_DEFAULT.to_string(),
+        }
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            capacity: ITEM_CACHE_CAPACITY_DEFAULT,
+            ttl_secs: ITEM_CACHE_TTL_SECS_DEFAULT,
+        }
+    }
+}
+
+impl Default for JobsConfig {
+    fn default() -> Self {
+        JobsConfig {
+            price_scheduler_interval_secs: PRICE_SCHEDULER_INTERVAL_SECS_DEFAULT,
+            event_relay_interval_secs: EVENT_RELAY_INTERVAL_SECS_DEFAULT,
+            webhook_dispatch_interval_secs: WEBHOOK_DISPATCH_INTERVAL_SECS_DEFAULT,
+        }
+    }
+}
+
+impl Default for TracingConfig {
+    fn default() -> Self {
+        TracingConfig {
+            exporter: TraceExporter::None,
+            otlp_endpoint: TRACING_OTLP_ENDPOINT_DEFAULT.to_string(),
+            service_name: TRACING_SERVICE_NAME_DEFAULT.to_string(),
+            sample_ratio: TRACING_SAMPLE_RATIO_DEFAULT,
+        }
+    }
+}
+
+impl Config {
+    /// Builds the configuration from defaults, the config file, env vars and
+    /// command-line flags, later ones winning.
+    pub fn load(cli: &Cli) -> Result<Self, ConfigError> {
+        let mut config = match &cli.config {
+            Some(path) => Self::from_file(path).map_err(|e| ConfigError { errors: vec![e] })?,
+            None => Self::default(),
+        };
+        let mut errors = config.apply_env(|name| env::var(name).ok());
+        config.apply_cli(cli);
+        errors.extend(config.validate());
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError { errors })
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Overrides settings with the env vars `lookup` finds, returning the
+    /// ones that could not be parsed.
+    pub fn apply_env(&mut self, lookup: impl Fn(&str) -> Option<String>) -> Vec<String> {
+        let mut errors = vec![];
+        let mut set = |name: &str, apply: &mut dyn FnMut(&str) -> Result<(), String>| {
+            if let Some(raw) = lookup(name) {
+                if let Err(expected) = apply(&raw) {
+                    errors.push(format!("{} must be {}, got {:?}", name, expected, raw));
+                }
+            }
+        };
+
+        set(SERVER_HOST, &mut |v| assign(&mut self.server.host, v));
+        set(SERVER_PORT, &mut |v| parse(&mut self.server.port, v, "a port number"));
+        set(SERVER_WORKERS, &mut |v| parse(&mut self.server.workers, v, "a uint number"));
+        set(LOG_LEVEL, &mut |v| assign(&mut self.server.log_level, v));
+        set(LOG_FORMAT, &mut |v| parse(&mut self.server.log_format, v, "text or json"));
+        set(SERVER_KEEP_ALIVE_SECS, &mut |v| {
+            parse(&mut self.server.keep_alive_secs, v, "a uint64 number")
+        });
+        set(SERVER_REQUEST_TIMEOUT_MS, &mut |v| {
+            parse(&mut self.server.request_timeout_ms, v, "a uint64 number")
+        });
+        set(SERVER_SHUTDOWN_TIMEOUT_SECS, &mut |v| {
+            parse(&mut self.server.shutdown_timeout_secs, v, "a uint64 number")
+        });
+        set(ADMIN_TOKEN, &mut |v| {
+            self.server.admin_token = Some(v.to_string());
+            Ok(())
+        });
+        set(POSTGRESQL_DB_URI, &mut |v| assign(&mut self.database.url, v));
+        set(POSTGRESQL_POOL_SIZE, &mut |v| {
+            parse(&mut self.database.pool_size, v, "a uint32 number")
+        });
+        set(POSTGRESQL_CONNECTION_TIMEOUT_SECS, &mut |v| {
+            parse(&mut self.database.connection_timeout_secs, v, "a uint64 number")
+        });
+        set(RUN_MIGRATIONS, &mut |v| parse(&mut self.database.run_migrations, v, "true or false"));
+        set(MEDIA_STORAGE_PATH, &mut |v| assign(&mut self.media.storage_path, v));
+        set(MEDIA_BASE_URL, &mut |v| assign(&mut self.media.base_url, v));
+        set(ITEM_CACHE_CAPACITY, &mut |v| parse(&mut self.cache.capacity, v, "a uint number"));
+        set(ITEM_CACHE_TTL_SECS, &mut |v| parse(&mut self.cache.ttl_secs, v, "a uint64 number"));
+        set(PRICE_SCHEDULER_INTERVAL_SECS, &mut |v| {
+            parse(&mut self.jobs.price_scheduler_interval_secs, v, "a uint64 number")
+        });
+        set(EVENT_RELAY_INTERVAL_SECS, &mut |v| {
+            parse(&mut self.jobs.event_relay_interval_secs, v, "a uint64 number")
+        });
+        set(WEBHOOK_DISPATCH_INTERVAL_SECS, &mut |v| {
+            parse(&mut self.jobs.webhook_dispatch_interval_secs, v, "a uint64 number")
+        });
+        set(TRACING_EXPORTER, &mut |v| {
+            parse(&mut self.tracing.exporter, v, "none, stdout or otlp")
+        });
+        set(TRACING_OTLP_ENDPOINT, &mut |v| assign(&mut self.tracing.otlp_endpoint, v));
+        set(TRACING_SERVICE_NAME, &mut |v| assign(&mut self.tracing.service_name, v));
+        set(TRACING_SAMPLE_RATIO, &mut |v| {
+            parse(&mut self.tracing.sample_ratio, v, "a number from 0 to 1")
+        });
+        set(BASE_CURRENCY, &mut |v| assign(&mut self.base_currency, v));
+        set(LOW_STOCK_WEBHOOK_URL, &mut |v| {
+            self.low_stock_webhook_url = Some(v.to_string());
+            Ok(())
+        });
+
+        errors
+    }
+
+    pub fn apply_cli(&mut self, cli: &Cli) {
+        if let Some(host) = &cli.host {
+            self.server.host = host.clone();
+        }
+        if let Some(port) = cli.port {
+            self.server.port = port;
+        }
+        if let Some(workers) = cli.workers {
+            self.server.workers = workers;
+        }
+        if let Some(log_level) = &cli.log_level {
+            self.server.log_level = log_level.clone();
+        }
+        if let Some(log_format) = cli.log_format {
+            self.server.log_format = log_format;
+        }
+        if let Some(database_url) = &cli.database_url {
+            self.database.url = database_url.clone();
+        }
+        if let Some(pool_size) = cli.pool_size {
+            self.database.pool_size = pool_size;
+        }
+        if let Some(exporter) = cli.trace_exporter {
+            self.tracing.exporter = exporter;
+        }
+    }
+
+    /// Checks values that parse but cannot work, normalizing the base
+    /// currency on the way.
+    pub fn validate(&mut self) -> Vec<String> {
+        let mut errors = vec![];
+
+        if self.server.host.trim().is_empty() {
+            errors.push("server.host must not be empty".to_string());
+        }
+        if !is_log_filter(&self.server.log_level) {
+            errors.push(format!(
+                "server.log_level must be a level (off, error, warn, info, debug, trace) \
+                 or module=level directives, got {:?}",
+                self.server.log_level
+            ));
+        }
+        if self
+            .server
+            .admin_token
+            .as_ref()
+            .is_some_and(|token| token.trim().len() < ADMIN_TOKEN_MIN_LEN)
+        {
+            errors.push(format!(
+                "server.admin_token must have at least {} characters",
+                ADMIN_TOKEN_MIN_LEN
+            ));
+        }
+        if self.database.url.trim().is_empty() {
+            errors.push(format!("database.url must be set (or {})", POSTGRESQL_DB_URI));
+        }
+        if self.database.pool_size == 0 {
+            errors.push("database.pool_size must be at least 1".to_string());
+        }
+        if self.database.connection_timeout_secs == 0 {
+            errors.push("database.connection_timeout_secs must be at least 1".to_string());
+        }
+        if self.media.storage_path.trim().is_empty() {
+            errors.push("media.storage_path must not be empty".to_string());
+        }
+        let base_path = self.media.base_url.trim_end_matches('/');
+        if self.media.is_served_locally() && (base_path.is_empty() || base_path.starts_with("/v1")) {
+            errors.push(format!(
+                "media.base_url must not shadow the API routes, got {:?}",
+                self.media.base_url
+            ));
+        } else if !self.media.is_served_locally()
+            && !self.media.base_url.starts_with("http://")
+            && !self.media.base_url.starts_with("https://")
+        {
+            errors.push(format!(
+                "media.base_url must be a path or an http(s) url, got {:?}",
+                self.media.base_url
+            ));
+        }
+        if self.cache.capacity > 0 && self.cache.ttl_secs == 0 {
+            errors.push("cache.ttl_secs must be at least 1 while the cache is on".to_string());
+        }
+        for (name, value) in [
+            ("jobs.price_scheduler_interval_secs", self.jobs.price_scheduler_interval_secs),
+            ("jobs.event_relay_interval_secs", self.jobs.event_relay_interval_secs),
+            ("jobs.webhook_dispatch_interval_secs", self.jobs.webhook_dispatch_interval_secs),
+        ] {
+            if value == 0 {
+                errors.push(format!("{} must be at least 1", name));
+            }
+        }
+        if !(0.0..=1.0).contains(&self.tracing.sample_ratio) {
+            errors.push(format!(
+                "tracing.sample_ratio must be from 0 to 1, got {}",
+                self.tracing.sample_ratio
+            ));
+        }
+        if self.tracing.service_name.trim().is_empty() {
+            errors.push("tracing.service_name must not be empty".to_string());
+        }
+        if self.tracing.exporter == TraceExporter::Otlp
+            && !self.tracing.otlp_endpoint.starts_with("http://")
+            && !self.tracing.otlp_endpoint.starts_with("https://")
+        {
+            errors.push(format!(
+                "tracing.otlp_endpoint must be an http(s) url, got {:?}",
+                self.tracing.otlp_endpoint
+            ));
+        }
+        match normalize_currency(&self.base_currency) {
+            Ok(currency) => self.base_currency = currency,
+            Err(_) => errors.push(format!(
+                "base_currency must be an ISO 4217 code, got {:?}",
+                self.base_currency
+            )),
+        }
+        if let Some(url) = &self.low_stock_webhook_url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                errors.push(format!("low_stock_webhook_url must be an http(s) url, got {:?}", url));
+            }
+        }
+
+        errors
+    }
+}
+
+fn assign(target: &mut String, value: &str) -> Result<(), String> {
+    *target = value.to_string();
+    Ok(())
+}
+
+fn parse<T: FromStr>(target: &mut T, value: &str, expected: &str) -> Result<(), String> {
+    *target = value.trim().parse().map_err(|_| expected.to_string())?;
+    Ok(())
+}
+
+/// Accepts what env_logger does, except bare module names: a typo in a level
+/// would otherwise silently turn into a module that never logs.
+fn is_log_filter(filter: &str) -> bool {
+    !filter.trim().is_empty()
+        && filter.split(',').map(str::trim).all(|directive| {
+            let level = match directive.split_once('=') {
+                Some((module, level)) if !module.is_empty() => level,
+                Some(_) => return false,
+                None => directive,
+            };
+            LevelFilter::from_str(level).is_ok()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn cli(args: &[&str]) -> Cli {
+        Cli::parse_from([&["gomarket-items"], args].concat())
+    }
+
+    #[test]
+    fn test_config_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+base_currency = "eur"
+
+[server]
+port = 9000
+workers = 4
+
+[database]
+url = "postgresql://localhost/items"
+run_migrations = true
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.server.port, 9000);
+        assert_eq!(config.server.workers, 4);
+        assert_eq!(config.server.host, SERVER_HOST_DEFAULT);
+        assert_eq!(config.database.url, "postgresql://localhost/items");
+        assert!(config.database.run_migrations);
+        assert_eq!(config.database.pool_size, POSTGRESQL_POOL_SIZE_DEFAULT);
+        assert_eq!(config.cache, CacheConfig::default());
+
+        let error = toml::from_str::<Config>("[server]\nport = \"x\"\nthreads = 2\n").unwrap_err();
+        assert!(error.to_string().contains("port"));
+        assert!(toml::from_str::<Config>("[server]\nthreads = 2\n").is_err());
+    }
+
+    #[test]
+    fn test_config_overrides() {
+        let mut config = Config::default();
+        config.server.port = 9000;
+        config.server.workers = 4;
+
+        let env = HashMap::from([
+            (SERVER_PORT, "9100"),
+            (POSTGRESQL_DB_URI, "postgresql://env/items"),
+            (POSTGRESQL_POOL_SIZE, "20"),
+            (RUN_MIGRATIONS, "true"),
+            (TRACING_EXPORTER, "otlp"),
+            (TRACING_OTLP_ENDPOINT, "http://collector:4318"),
+        ]);
+        let errors = config.apply_env(|name| env.get(name).map(|v| v.to_string()));
+        assert!(errors.is_empty());
+
+        config.apply_cli(&cli(&[
+            "--port",
+            "9200",
+            "--log-level",
+            "warn,actix_web=debug",
+            "--log-format",
+            "json",
+        ]));
+
+        assert_eq!(config.server.port, 9200);
+        assert_eq!(config.server.workers, 4);
+        assert_eq!(config.server.log_level, "warn,actix_web=debug");
+        assert_eq!(config.server.log_format, LogFormat::Json);
+        assert_eq!(config.database.url, "postgresql://env/items");
+        assert_eq!(config.database.pool_size, 20);
+        assert!(config.database.run_migrations);
+        assert_eq!(config.tracing.exporter, TraceExporter::Otlp);
+        assert_eq!(config.tracing.otlp_endpoint, "http://collector:4318");
+        assert!(config.validate().is_empty());
+
+        config.apply_cli(&cli(&["--trace-exporter", "stdout"]));
+        assert_eq!(config.tracing.exporter, TraceExporter::Stdout);
+    }
+
+    #[test]
+    fn test_config_errors() {
+        let mut config = Config::default();
+        config.media.base_url = "media".to_string();
+        let env = HashMap::from([
+            (SERVER_PORT, "80000"),
+            (RUN_MIGRATIONS, "yes"),
+            (BASE_CURRENCY, "euro"),
+            (EVENT_RELAY_INTERVAL_SECS, "0"),
+            (LOG_LEVEL, "inf"),
+            (TRACING_SAMPLE_RATIO, "1.5"),
+            (ADMIN_TOKEN, "secret"),
+        ]);
+
+        let errors = config.apply_env(|name| env.get(name).map(|v| v.to_string()));
+        assert_eq!(
+            errors,
+            vec![
+                "SERVER_PORT must be a port number, got \"80000\"".to_string(),
+                "RUN_MIGRATIONS must be true or false, got \"yes\"".to_string(),
+            ]
+        );
+        assert_eq!(config.server.port, SERVER_PORT_DEFAULT);
+
+        let errors = config.validate();
+        assert_eq!(errors.len(), 7);
+        assert!(errors[0].starts_with("server.log_level"));
+        assert_eq!(errors[1], "server.admin_token must have at least 16 characters");
+        assert!(errors[2].starts_with("database.url must be set"));
+        assert_eq!(errors[3], "media.base_url must be a path or an http(s) url, got \"media\"");
+        assert_eq!(errors[4], "jobs.event_relay_interval_secs must be at least 1");
+        assert_eq!(errors[5], "tracing.sample_ratio must be from 0 to 1, got 1.5");
+        assert!(errors[6].starts_with("base_currency"));
+    }
+
+    #[test]
+    fn test_is_log_filter() {
+        assert!(is_log_filter("debug"));
+        assert!(is_log_filter("info, actix_web=debug,gomarket_items::api=trace"));
+        assert!(!is_log_filter(""));
+        assert!(!is_log_filter("inf"));
+        assert!(!is_log_filter("actix_web"));
+        assert!(!is_log_filter("=debug"));
+    }
+}
@@ -1,41 +1,143 @@
-use crate::domain::constants::{POSTGRESQL_DB_URI, POSTGRESQL_POOL_SIZE, POSTGRESQL_POOL_SIZE_DEFAULT};
+use crate::config::Config;
+use crate::domain::caches::items::ItemCache;
+use crate::domain::notifiers::stock::StockNotifier;
 use crate::domain::repositories::items::Repository;
+use crate::domain::services::currencies::CurrencyService;
+use crate::domain::services::events::EventService;
+use crate::domain::services::health::HealthService;
+use crate::domain::services::media::MediaService;
 use crate::domain::services::order::CoreService;
-use crate::infrastructure::databases::postgresql::db_pool;
+use crate::domain::services::prices::PriceService;
+use crate::domain::services::promotions::PromotionService;
+use crate::domain::services::service_context::ServiceContextService;
+use crate::domain::services::taxes::TaxService;
+use crate::domain::services::warehouses::WarehouseService;
+use crate::domain::services::webhooks::WebhookService;
+use crate::domain::storages::media::MediaStorage;
+use crate::infrastructure::databases::postgresql::{db_pool_with_metrics, DBConn};
+use crate::infrastructure::metrics::Metrics;
+use crate::infrastructure::notifiers::log::LogStockNotifier;
+use crate::infrastructure::notifiers::webhook::{HttpWebhookSender, WebhookStockNotifier};
+use crate::infrastructure::repositories::cached::CachedRepository;
 use crate::infrastructure::repositories::items::DieselRepository;
+use crate::infrastructure::services::service_context::ServiceContextServiceImpl;
+use crate::infrastructure::storages::local::LocalMediaStorage;
+use crate::services::currencies::CurrencyServiceImpl;
+use crate::services::events::EventServiceImpl;
+use crate::services::health::HealthServiceImpl;
 use crate::services::items::CoreServiceImpl;
-use dotenv::dotenv;
-use std::env;
+use crate::services::media::MediaServiceImpl;
+use crate::services::prices::PriceServiceImpl;
+use crate::services::promotions::PromotionServiceImpl;
+use crate::services::taxes::TaxServiceImpl;
+use crate::services::warehouses::WarehouseServiceImpl;
+use crate::services::webhooks::WebhookServiceImpl;
 use std::sync::Arc;
-use log::warn;
+use std::time::Duration;
 
 pub struct Container {
+    pub database_url: String,
+    pub db_pool: Arc<DBConn>,
+    pub metrics: Arc<Metrics>,
+    /// The repository every service uses, read cache included.
+    pub repository: Arc<dyn Repository>,
+    /// Local caches the item change listener keeps in sync with other replicas.
+    pub item_caches: Vec<Arc<dyn ItemCache>>,
     pub core_service: Arc<dyn CoreService>,
+    pub media_service: Arc<dyn MediaService>,
+    pub price_service: Arc<dyn PriceService>,
+    pub promotion_service: Arc<dyn PromotionService>,
+    pub currency_service: Arc<dyn CurrencyService>,
+    pub tax_service: Arc<dyn TaxService>,
+    pub warehouse_service: Arc<dyn WarehouseService>,
+    pub event_service: Arc<dyn EventService>,
+    pub webhook_service: Arc<dyn WebhookService>,
+    pub health_service: Arc<dyn HealthService>,
+    pub service_context_service: Arc<dyn ServiceContextService>,
+    pub price_scheduler_interval: Duration,
+    pub event_relay_interval: Duration,
+    pub webhook_dispatch_interval: Duration,
 }
 
 impl Container {
-    pub fn new() -> Self {
-        dotenv().ok();
-        let database_url = env::var(POSTGRESQL_DB_URI)
-            .expect(&*format!("{value} must be set", value = POSTGRESQL_DB_URI));
-        let pool_size =  env::var(POSTGRESQL_POOL_SIZE)
-            .unwrap_or_else(|_ | -> String {
-                warn!("{value} doesn't exist! using default = {default}",
-                    value = POSTGRESQL_POOL_SIZE, default = POSTGRESQL_POOL_SIZE_DEFAULT);
-                "10".to_string()
-            })
-            .parse::<u32>()
-            .expect("POSTGRESQL_POOL_SIZE must be a uint32 number");
+    pub fn new(config: &Config) -> Self {
+        let database_url = config.database.url.clone();
+        let base_currency = config.base_currency.clone();
 
-        let repository: Arc<dyn Repository> =
-            Arc::new(DieselRepository::new(Arc::new(db_pool(database_url, pool_size))));
-        let core_service = Arc::new(CoreServiceImpl::new(repository));
-        Container { core_service }
-    }
-}
+        let stock_notifier: Arc<dyn StockNotifier> = match &config.low_stock_webhook_url {
+            Some(url) => Arc::new(WebhookStockNotifier::new(url.clone())),
+            None => Arc::new(LogStockNotifier),
+        };
+
+        let metrics = Arc::new(Metrics::new());
+        let db_pool = Arc::new(db_pool_with_metrics(
+            database_url.clone(),
+            config.database.pool_size,
+            Duration::from_secs(config.database.connection_timeout_secs),
+            metrics.clone(),
+        ));
+        let mut repository: Arc<dyn Repository> = Arc::new(
+            DieselRepository::new(db_pool.clone())
+                .with_notifier(stock_notifier)
+                .with_metrics(metrics.clone()),
+        );
+        let mut item_caches: Vec<Arc<dyn ItemCache>> = vec![];
+        if config.cache.capacity > 0 {
+            let cache = Arc::new(CachedRepository::new(
+                repository,
+                config.cache.capacity,
+                Duration::from_secs(config.cache.ttl_secs),
+            ));
+            item_caches.push(cache.clone());
+            repository = cache;
+        }
+        let storage: Arc<dyn MediaStorage> =
+            Arc::new(LocalMediaStorage::new(
+                config.media.storage_path.clone(),
+                config.media.base_url.clone(),
+            ));
 
-impl Default for Container {
-    fn default() -> Self {
-        Self::new()
+        let core_service = Arc::new(
+            CoreServiceImpl::new(repository.clone()).with_base_currency(base_currency.clone()),
+        );
+        let media_service = Arc::new(MediaServiceImpl::new(repository.clone(), storage));
+        let price_service = Arc::new(PriceServiceImpl::new(repository.clone()));
+        let promotion_service = Arc::new(PromotionServiceImpl::new(repository.clone()));
+        let currency_service = Arc::new(
+            CurrencyServiceImpl::new(repository.clone()).with_base_currency(base_currency),
+        );
+        let tax_service = Arc::new(TaxServiceImpl::new(repository.clone()));
+        let warehouse_service = Arc::new(WarehouseServiceImpl::new(repository.clone()));
+        let service_context_service = Arc::new(ServiceContextServiceImpl::new(db_pool.clone()));
+        let health_service = Arc::new(
+            HealthServiceImpl::new(repository.clone())
+                .with_service_context(service_context_service.clone()),
+        );
+        let webhook_service = Arc::new(WebhookServiceImpl::new(
+            repository.clone(),
+            Arc::new(HttpWebhookSender::new()),
+        ));
+        let event_service = Arc::new(EventServiceImpl::new(repository.clone(), webhook_service.clone()));
+        Container {
+            database_url,
+            db_pool,
+            metrics,
+            repository,
+            item_caches,
+            core_service,
+            media_service,
+            price_service,
+            promotion_service,
+            currency_service,
+            tax_service,
+            warehouse_service,
+            event_service,
+            webhook_service,
+            health_service,
+            service_context_service,
+            price_scheduler_interval: Duration::from_secs(config.jobs.price_scheduler_interval_secs),
+            event_relay_interval: Duration::from_secs(config.jobs.event_relay_interval_secs),
+            webhook_dispatch_interval: Duration::from_secs(config.jobs.webhook_dispatch_interval_secs),
+        }
     }
 }
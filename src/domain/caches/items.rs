@@ -0,0 +1,24 @@
+#[allow(unused_imports)]
+use mockall::automock;
+
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped to stay within the size bound.
+    pub evictions: u64,
+    pub entries: usize,
+}
+
+/// Item state kept in process memory. Other replicas change the same items,
+/// so implementations are told about every committed change.
+#[cfg_attr(test, automock)]
+pub trait ItemCache: Sync + Send {
+    /// Drops whatever is held for the item.
+    fn invalidate_item(&self, item_id: i64);
+
+    /// Drops everything; used when changes may have been missed.
+    fn invalidate_all(&self);
+
+    fn stats(&self) -> CacheStats;
+}
@@ -1,3 +1,139 @@
+/// TOML file read before env vars and command-line flags are applied.
+pub const CONFIG_FILE: &str = "CONFIG_FILE";
+
+pub const SERVER_HOST: &str = "SERVER_HOST";
+pub const SERVER_HOST_DEFAULT: &str = "0.0.0.0";
+pub const SERVER_PORT: &str = "SERVER_PORT";
+pub const SERVER_PORT_DEFAULT: u16 = 8000;
+/// HTTP worker threads; 0 starts one per physical core.
+pub const SERVER_WORKERS: &str = "SERVER_WORKERS";
+pub const SERVER_WORKERS_DEFAULT: usize = 0;
+pub const LOG_LEVEL: &str = "RUST_LOG";
+pub const LOG_LEVEL_DEFAULT: &str = "info";
+/// `text` or `json`.
+pub const LOG_FORMAT: &str = "LOG_FORMAT";
+/// Request header carrying the id logs and error responses are tagged with;
+/// generated when the client sends none.
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+pub const REQUEST_ID_MAX_LENGTH: usize = 128;
+pub const SERVER_KEEP_ALIVE_SECS: &str = "SERVER_KEEP_ALIVE_SECS";
+pub const SERVER_KEEP_ALIVE_SECS_DEFAULT: u64 = 5;
+/// Time a client gets to send the request head; 0 disables the limit.
+pub const SERVER_REQUEST_TIMEOUT_MS: &str = "SERVER_REQUEST_TIMEOUT_MS";
+pub const SERVER_REQUEST_TIMEOUT_MS_DEFAULT: u64 = 5000;
+/// Time in-flight requests get to finish on shutdown.
+pub const SERVER_SHUTDOWN_TIMEOUT_SECS: &str = "SERVER_SHUTDOWN_TIMEOUT_SECS";
+pub const SERVER_SHUTDOWN_TIMEOUT_SECS_DEFAULT: u64 = 30;
+/// Bearer token the admin routes require; they are refused while it is unset.
+pub const ADMIN_TOKEN: &str = "ADMIN_TOKEN";
+pub const ADMIN_TOKEN_MIN_LEN: usize = 16;
+
 pub const POSTGRESQL_DB_URI: &str = "DATABASE_URL";
 pub const POSTGRESQL_POOL_SIZE: &str = "POSTGRESQL_POOL_SIZE";
-pub const POSTGRESQL_POOL_SIZE_DEFAULT: u32 = 10;
\ No newline at end of file
+pub const POSTGRESQL_POOL_SIZE_DEFAULT: u32 = 10;
+/// Time to wait for a free pooled connection before a query fails.
+pub const POSTGRESQL_CONNECTION_TIMEOUT_SECS: &str = "POSTGRESQL_CONNECTION_TIMEOUT_SECS";
+pub const POSTGRESQL_CONNECTION_TIMEOUT_SECS_DEFAULT: u64 = 30;
+/// Applies pending embedded migrations before the server starts when "true".
+pub const RUN_MIGRATIONS: &str = "RUN_MIGRATIONS";
+pub const MEDIA_STORAGE_PATH: &str = "MEDIA_STORAGE_PATH";
+pub const MEDIA_STORAGE_PATH_DEFAULT: &str = "media";
+pub const MEDIA_BASE_URL: &str = "MEDIA_BASE_URL";
+pub const MEDIA_BASE_URL_DEFAULT: &str = "/media";
+pub const MEDIA_MAX_UPLOAD_SIZE: usize = 10 * 1024 * 1024;
+
+pub const PRICE_SCHEDULER_INTERVAL_SECS: &str = "PRICE_SCHEDULER_INTERVAL_SECS";
+pub const PRICE_SCHEDULER_INTERVAL_SECS_DEFAULT: u64 = 30;
+
+pub const EVENT_RELAY_INTERVAL_SECS: &str = "EVENT_RELAY_INTERVAL_SECS";
+pub const EVENT_RELAY_INTERVAL_SECS_DEFAULT: u64 = 5;
+/// Outbox events published per relay run.
+pub const EVENT_RELAY_BATCH_SIZE: i64 = 100;
+/// How long the events a relay claimed are kept from the other replicas.
+pub const EVENT_RELAY_CLAIM_SECS: i64 = 60;
+
+/// How often an open change feed looks for new item events.
+pub const ITEM_EVENTS_POLL_INTERVAL_MS: u64 = 1000;
+/// Idle time after which the change feed sends a comment to keep proxies
+/// from closing the connection.
+pub const ITEM_EVENTS_KEEP_ALIVE_SECS: u64 = 15;
+pub const ITEM_EVENTS_BATCH_SIZE: i64 = 100;
+/// Request header an SSE client sends with the last event id it received.
+pub const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+/// Postgres channel notified with the item id whenever a change to an item
+/// commits, whether or not it records an event.
+pub const ITEM_CHANGES_CHANNEL: &str = "item_changes";
+/// Payload on the change channel for changes that may touch every item.
+pub const ITEM_CHANGES_ALL: &str = "*";
+/// How often the change listener reads notifications off its connection.
+pub const ITEM_CHANGES_POLL_MS: u64 = 200;
+/// Idle time after which the change listener checks its connection is alive.
+pub const ITEM_CHANGES_HEALTH_CHECK_SECS: u64 = 30;
+pub const ITEM_CHANGES_RECONNECT_SECS: u64 = 5;
+
+/// Entries of the in-process item read cache; 0 turns the cache off.
+pub const ITEM_CACHE_CAPACITY: &str = "ITEM_CACHE_CAPACITY";
+pub const ITEM_CACHE_CAPACITY_DEFAULT: usize = 1000;
+/// Bounds staleness from changes no item event reports, like promotions
+/// starting or exchange rates set on another replica.
+pub const ITEM_CACHE_TTL_SECS: &str = "ITEM_CACHE_TTL_SECS";
+pub const ITEM_CACHE_TTL_SECS_DEFAULT: u64 = 30;
+pub const ITEM_CACHE_STATS_INTERVAL_SECS: u64 = 60;
+
+/// Limit for each readiness check, below the usual probe timeout; a pool
+/// checkout would otherwise wait for the full connection timeout.
+pub const HEALTH_CHECK_TIMEOUT_MS: u64 = 2000;
+
+/// How long a replica reuses the service context before reading it again,
+/// so bounds how late it notices maintenance toggled on another one.
+pub const SERVICE_CONTEXT_CACHE_TTL_SECS: u64 = 5;
+
+/// How often pool and catalog gauges are sampled for `/metrics`.
+pub const METRICS_COLLECT_INTERVAL_SECS: u64 = 15;
+
+/// `none`, `stdout` or `otlp`; the names are the standard OpenTelemetry ones.
+pub const TRACING_EXPORTER: &str = "OTEL_TRACES_EXPORTER";
+/// OTLP/HTTP collector; spans are posted to `{endpoint}/v1/traces`.
+pub const TRACING_OTLP_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+pub const TRACING_OTLP_ENDPOINT_DEFAULT: &str = "http://localhost:4318";
+pub const TRACING_SERVICE_NAME: &str = "OTEL_SERVICE_NAME";
+pub const TRACING_SERVICE_NAME_DEFAULT: &str = "gomarket-items";
+/// Share of new traces recorded; a caller's sampling decision in
+/// `traceparent` is always followed.
+pub const TRACING_SAMPLE_RATIO: &str = "OTEL_TRACES_SAMPLER_ARG";
+pub const TRACING_SAMPLE_RATIO_DEFAULT: f64 = 1.0;
+
+pub const BASE_CURRENCY: &str = "BASE_CURRENCY";
+pub const BASE_CURRENCY_DEFAULT: &str = "USD";
+
+/// Url receiving low-stock events; they are only logged when unset.
+pub const LOW_STOCK_WEBHOOK_URL: &str = "LOW_STOCK_WEBHOOK_URL";
+
+/// Actor recorded for stock movements not caused by a request.
+pub const SYSTEM_ACTOR: &str = "system";
+/// Request header naming who performs a stock change. Nothing vouches for
+/// it, so the name is recorded behind `ACTOR_UNVERIFIED_PREFIX`.
+pub const ACTOR_HEADER: &str = "X-Actor";
+pub const ACTOR_DEFAULT: &str = "anonymous";
+pub const ACTOR_UNVERIFIED_PREFIX: &str = "unverified:";
+/// Actor recorded for requests authenticated with the admin token.
+pub const ACTOR_ADMIN: &str = "admin";
+
+pub const WEBHOOK_DISPATCH_INTERVAL_SECS: &str = "WEBHOOK_DISPATCH_INTERVAL_SECS";
+pub const WEBHOOK_DISPATCH_INTERVAL_SECS_DEFAULT: u64 = 5;
+pub const WEBHOOK_DISPATCH_BATCH_SIZE: i64 = 50;
+/// How long claimed deliveries are kept from the other replicas; outlasts a
+/// full batch sent at the sender's timeout.
+pub const WEBHOOK_DISPATCH_LEASE_SECS: i64 = 300;
+/// Failed attempts after which a delivery becomes a dead letter.
+pub const WEBHOOK_MAX_ATTEMPTS: i32 = 8;
+/// Delay before the first retry, doubled on every further failure.
+pub const WEBHOOK_RETRY_BASE_SECS: i64 = 10;
+pub const WEBHOOK_RETRY_MAX_SECS: i64 = 3600;
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Gomarket-Event";
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Gomarket-Delivery";
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Gomarket-Timestamp";
+/// `sha256=` and the hex HMAC-SHA256 of `{timestamp}.{body}` keyed with the
+/// subscription secret.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Gomarket-Signature";
@@ -1,3 +1,4 @@
+use crate::logging::current_request_id;
 use actix_threadpool::BlockingError;
 use diesel::r2d2;
 use serde::{Deserialize, Serialize};
@@ -12,6 +13,12 @@ pub struct CommonError {
 pub enum CommonErrorKind {
     AlreadyExists,
     NotFound,
+    InvalidInput,
+    Conflict,
+    /// The service refuses the request for now, like during maintenance.
+    Unavailable,
+    /// The request lacks valid credentials for the route.
+    Unauthorized,
     Unknown,
 }
 
@@ -36,16 +43,37 @@ impl std::fmt::Display for ApiError {
     }
 }
 
+impl ApiError {
+    pub fn code(&self) -> &CommonErrorKind {
+        &self.0.code
+    }
+}
+
 impl actix_web::ResponseError for ApiError {
-    fn error_response(&self) -> actix_web::HttpResponse {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        use actix_web::http::StatusCode;
+
         match &self.0.code {
-            CommonErrorKind::AlreadyExists => actix_web::HttpResponse::Conflict().json(&self.0),
-            CommonErrorKind::NotFound => actix_web::HttpResponse::Gone().json(&self.0),
-            CommonErrorKind::Unknown => {
-                actix_web::HttpResponse::InternalServerError().json(&self.0)
-            }
+            CommonErrorKind::AlreadyExists => StatusCode::CONFLICT,
+            CommonErrorKind::NotFound => StatusCode::GONE,
+            CommonErrorKind::InvalidInput => StatusCode::BAD_REQUEST,
+            CommonErrorKind::Conflict => StatusCode::CONFLICT,
+            CommonErrorKind::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+            CommonErrorKind::Unauthorized => StatusCode::UNAUTHORIZED,
+            CommonErrorKind::Unknown => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
+
+    /// The error as JSON, tagged with the request id when there is one so
+    /// clients can quote it.
+    fn error_response(&self) -> actix_web::HttpResponse {
+        let mut body = serde_json::to_value(&self.0).unwrap_or_default();
+        if let Some(request_id) = current_request_id() {
+            body["request_id"] = request_id.into();
+        }
+
+        actix_web::HttpResponse::build(self.status_code()).json(body)
+    }
 }
 
 #[derive(Debug)]
@@ -58,6 +86,7 @@ pub struct RepositoryError {
 pub enum RepositoryErrorKind {
     UniqueViolation,
     NotFound,
+    Conflict,
     Unknown,
 }
 
@@ -68,12 +97,69 @@ impl From<RepositoryError> for CommonError {
             code: match error.code {
                 RepositoryErrorKind::NotFound => CommonErrorKind::NotFound,
                 RepositoryErrorKind::UniqueViolation => CommonErrorKind::AlreadyExists,
+                RepositoryErrorKind::Conflict => CommonErrorKind::Conflict,
                 RepositoryErrorKind::Unknown => CommonErrorKind::Unknown,
             },
         }
     }
 }
 
+#[derive(Debug)]
+pub struct StorageError {
+    pub message: String,
+    pub code: StorageErrorKind,
+}
+
+#[derive(Debug)]
+pub enum StorageErrorKind {
+    InvalidKey,
+    NotFound,
+    Unknown,
+}
+
+impl From<StorageError> for CommonError {
+    fn from(error: StorageError) -> CommonError {
+        CommonError {
+            message: error.message,
+            code: match error.code {
+                StorageErrorKind::InvalidKey => CommonErrorKind::InvalidInput,
+                StorageErrorKind::NotFound => CommonErrorKind::NotFound,
+                StorageErrorKind::Unknown => CommonErrorKind::Unknown,
+            },
+        }
+    }
+}
+
+impl From<std::io::Error> for StorageError {
+    fn from(error: std::io::Error) -> StorageError {
+        StorageError {
+            message: error.to_string(),
+            code: match error.kind() {
+                std::io::ErrorKind::NotFound => StorageErrorKind::NotFound,
+                _ => StorageErrorKind::Unknown,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierError {
+    pub message: String,
+    pub code: NotifierErrorKind,
+}
+
+#[derive(Debug)]
+pub enum NotifierErrorKind {
+    Rejected,
+    Unknown,
+}
+
+impl std::fmt::Display for NotifierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Error: {}, Code: {:?}", self.message, self.code)
+    }
+}
+
 pub type AsyncPoolError<T> = BlockingError<T>;
 impl From<r2d2::PoolError> for RepositoryError {
     fn from(value: r2d2::PoolError) -> Self {
@@ -112,6 +198,13 @@ impl From<diesel::result::Error> for RepositoryError {
                 message: info.message().to_string(),
                 code: RepositoryErrorKind::UniqueViolation,
             },
+            diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::CheckViolation,
+                info,
+            ) => RepositoryError {
+                message: info.message().to_string(),
+                code: RepositoryErrorKind::Conflict,
+            },
             diesel::result::Error::NotFound => RepositoryError {
                 message: error.to_string(),
                 code: RepositoryErrorKind::NotFound,
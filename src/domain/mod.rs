@@ -1,5 +1,8 @@
+pub mod caches;
 pub mod constants;
 pub mod error;
 pub mod models;
+pub mod notifiers;
 pub mod repositories;
 pub mod services;
+pub mod storages;
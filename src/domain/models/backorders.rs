@@ -0,0 +1,61 @@
+use chrono::NaiveDate;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BackorderPolicy {
+    Deny,
+    /// Sell beyond stock and ship once it arrives.
+    Backorder,
+    /// Sell before the first stock arrives on `expected_date`.
+    Preorder,
+}
+
+/// How an item size may be sold beyond its stock.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BackorderRule {
+    pub item_id: i64,
+    pub size: String,
+    pub policy: BackorderPolicy,
+    /// Most units owed at once; unlimited when `None`.
+    pub limit: Option<i32>,
+    pub expected_date: Option<NaiveDate>,
+    /// Units sold beyond stock and not yet delivered.
+    pub backordered: i32,
+}
+
+impl BackorderRule {
+    /// Units that may still be sold without stock.
+    pub fn allowance(&self) -> i32 {
+        match self.policy {
+            BackorderPolicy::Deny => 0,
+            _ => self
+                .limit
+                .map_or(i32::MAX, |limit| (limit - self.backordered).max(0)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Availability {
+    InStock,
+    Backorder,
+    Preorder,
+    OutOfStock,
+}
+
+impl Availability {
+    /// Availability of a size with `stock` units under its rule, if any.
+    pub fn of(stock: i32, rule: Option<&BackorderRule>) -> Availability {
+        if stock > 0 {
+            return Availability::InStock;
+        }
+
+        match rule {
+            Some(rule) if rule.allowance() > 0 => match rule.policy {
+                BackorderPolicy::Backorder => Availability::Backorder,
+                BackorderPolicy::Preorder => Availability::Preorder,
+                BackorderPolicy::Deny => Availability::OutOfStock,
+            },
+            _ => Availability::OutOfStock,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::domain::models::items::Size;
+
+/// Item size that goes into each unit of a bundle size.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BundleComponent {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+    /// Stock of the component size over all warehouses, ignored on writes.
+    pub available: i32,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Bundle {
+    pub item_id: i64,
+    pub size: Size,
+    pub components: Vec<BundleComponent>,
+}
+
+impl Bundle {
+    /// Complete units the current component stock is enough for.
+    pub fn stock(&self) -> i32 {
+        self.components
+            .iter()
+            .map(|c| (c.available / c.quantity).max(0))
+            .min()
+            .unwrap_or(0)
+    }
+}
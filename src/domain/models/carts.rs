@@ -0,0 +1,34 @@
+/// Line of a cart as sent by the orders service before checkout.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CartLine {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+    /// Unit price the customer was shown, in the base currency.
+    pub expected_price: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum CartLineStatus {
+    Ok,
+    InsufficientStock { available: i32 },
+    PriceChanged { current_price: f64 },
+    /// The item was removed or is not offered in the requested size.
+    ItemMissing,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct CartLineResult {
+    pub line: CartLine,
+    pub status: CartLineStatus,
+}
+
+/// Current sale price and total stock per size of an item.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemAvailability {
+    pub item_id: i64,
+    pub sale_price: f64,
+    pub sizes: Vec<(String, i32)>,
+    /// Sale prices of the sizes a size promotion is active for.
+    pub size_sale_prices: Vec<(String, f64)>,
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+
+/// Units of `currency` for one unit of the base currency.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExchangeRate {
+    pub currency: String,
+    pub rate: f64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Explicit price of an item in a currency, taking precedence over conversion.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PriceListEntry {
+    pub item_id: i64,
+    pub currency: String,
+    /// `None` for every region; a regional price takes precedence over it.
+    pub region: Option<String>,
+    pub price: f64,
+}
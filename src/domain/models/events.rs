@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Change to an item that other services are told about. The serialized form
+/// is what publishers send.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ItemEvent {
+    ItemCreated {
+        item_id: i64,
+        name: String,
+        price: f64,
+    },
+    ItemUpdated {
+        item_id: i64,
+    },
+    PriceChanged {
+        item_id: i64,
+        price: f64,
+    },
+    StockChanged {
+        item_id: i64,
+        size: String,
+        warehouse_id: i32,
+        quantity: i32,
+        delta: i32,
+    },
+    ItemDeleted {
+        item_id: i64,
+    },
+}
+
+impl ItemEvent {
+    pub fn item_id(&self) -> i64 {
+        match self {
+            ItemEvent::ItemCreated { item_id, .. }
+            | ItemEvent::ItemUpdated { item_id }
+            | ItemEvent::PriceChanged { item_id, .. }
+            | ItemEvent::StockChanged { item_id, .. }
+            | ItemEvent::ItemDeleted { item_id } => *item_id,
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            ItemEvent::ItemCreated { .. } => "item_created",
+            ItemEvent::ItemUpdated { .. } => "item_updated",
+            ItemEvent::PriceChanged { .. } => "price_changed",
+            ItemEvent::StockChanged { .. } => "stock_changed",
+            ItemEvent::ItemDeleted { .. } => "item_deleted",
+        }
+    }
+}
+
+/// Every value `ItemEvent::event_type` returns.
+pub const ITEM_EVENT_TYPES: [&str; 5] = [
+    "item_created",
+    "item_updated",
+    "price_changed",
+    "stock_changed",
+    "item_deleted",
+];
+
+/// Event waiting in the outbox, in commit order of its change.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OutboxEvent {
+    pub id: i64,
+    pub event: ItemEvent,
+    pub created_at: DateTime<Utc>,
+    pub attempts: i32,
+}
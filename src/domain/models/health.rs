@@ -0,0 +1,33 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// Outcome of probing one dependency.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HealthCheck {
+    pub name: String,
+    pub status: HealthStatus,
+    pub details: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// Down as soon as any of its checks is down.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: Vec<HealthCheck>,
+}
+
+impl HealthReport {
+    pub fn new(checks: Vec<HealthCheck>) -> Self {
+        let status = if checks.iter().all(|c| c.status == HealthStatus::Up) {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+
+        HealthReport { status, checks }
+    }
+}
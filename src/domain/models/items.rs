@@ -1,10 +1,40 @@
+use chrono::NaiveDate;
+use crate::domain::models::backorders::Availability;
+use crate::domain::models::media::ItemMedia;
+use crate::domain::models::promotions::SizeEffectivePrice;
+use crate::domain::models::taxes::ItemTax;
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct Item {
     pub id: i64,
     pub name: String,
     pub description: String,
     pub price: f64,
+    pub category: Option<String>,
+    pub tax_class_id: Option<i32>,
     pub sizes: Vec<(Size, i32)>,
+    pub media: Vec<ItemMedia>,
+    /// Effective price after promotions, filled on reads.
+    pub sale_price: Option<f64>,
+    pub promotion_id: Option<i64>,
+    /// Sizes a size promotion is active for, priced like `sale_price`.
+    pub size_sale_prices: Vec<SizeEffectivePrice>,
+    /// Currency of `price` and `sale_price`, filled on reads.
+    pub currency: Option<String>,
+    /// Taxes of `sale_price` in the requested region, filled on reads.
+    pub tax: Option<ItemTax>,
+    /// Best availability over the sizes, filled on reads.
+    pub availability: Option<Availability>,
+    /// Earliest date back- or pre-ordered sizes are expected to ship.
+    pub expected_ship_date: Option<NaiveDate>,
+}
+
+/// Catalog totals exported as metrics.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct InventoryStats {
+    pub items: i64,
+    /// Item sizes whose total quantity is zero or less.
+    pub out_of_stock_sizes: i64,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -17,6 +47,8 @@ pub struct Size {
 pub struct PriceGetItemsQuery {
     pub from: Option<f64>,
     pub to: Option<f64>,
+    /// Compare against the price after promotions instead of the base price.
+    pub effective: bool,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -30,6 +62,12 @@ pub struct GetItemsQuery {
     pub ids: Option<Vec<i64>>,
     pub price: Option<PriceGetItemsQuery>,
     pub names: Option<NamesGetItemsQuery>,
+    /// Only items stocked in the warehouse, with its quantities instead of totals.
+    pub warehouse_id: Option<i32>,
+    /// Currency the price filter, price sorting and returned prices are in.
+    pub currency: Option<String>,
+    /// Region taxes of the returned items are calculated for.
+    pub region: Option<String>,
 }
 
 #[derive(Clone, Default, PartialEq, Debug)]
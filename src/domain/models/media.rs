@@ -0,0 +1,32 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemMedia {
+    pub id: i64,
+    pub item_id: i64,
+    pub url: String,
+    pub path: Option<String>,
+    pub alt_text: String,
+    pub position: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct NewItemMedia {
+    pub item_id: i64,
+    pub url: String,
+    pub path: Option<String>,
+    pub alt_text: String,
+    /// `None` appends the media after the last one of the item.
+    pub position: Option<i32>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MediaUpload {
+    pub item_id: i64,
+    pub content_type: String,
+    pub content: Vec<u8>,
+    pub alt_text: String,
+    pub position: Option<i32>,
+}
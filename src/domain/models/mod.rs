@@ -1,2 +1,14 @@
+pub mod backorders;
+pub mod bundles;
+pub mod carts;
+pub mod currencies;
+pub mod events;
+pub mod health;
 pub mod items;
+pub mod media;
+pub mod prices;
+pub mod promotions;
 pub mod service_context;
+pub mod taxes;
+pub mod warehouses;
+pub mod webhooks;
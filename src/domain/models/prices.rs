@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+
+/// A price that was in effect for an item during `[valid_from, valid_to)`.
+/// The current price has no `valid_to`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PriceRecord {
+    pub id: i64,
+    pub item_id: i64,
+    pub price: f64,
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScheduledPrice {
+    pub id: i64,
+    pub item_id: i64,
+    pub price: f64,
+    pub starts_at: DateTime<Utc>,
+    pub applied_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemPrices {
+    pub item_id: i64,
+    pub history: Vec<PriceRecord>,
+    pub scheduled: Vec<ScheduledPrice>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum PriceChange {
+    Applied(PriceRecord),
+    Scheduled(ScheduledPrice),
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DiscountKind {
+    Percentage,
+    Fixed,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum PromotionTarget {
+    Item(i64),
+    Category(String),
+    /// Applies to every item, but only in the size.
+    Size(String),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Promotion {
+    pub id: i64,
+    pub name: String,
+    pub kind: DiscountKind,
+    pub value: f64,
+    pub target: PromotionTarget,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub priority: i32,
+    pub active: bool,
+}
+
+/// Price of an item after the winning promotion, if any, was applied.
+#[derive(Clone, PartialEq, Debug)]
+pub struct EffectivePrice {
+    pub item_id: i64,
+    pub price: f64,
+    pub sale_price: f64,
+    pub promotion_id: Option<i64>,
+    /// Sizes a size promotion is active for; other sizes sell at `sale_price`.
+    pub sizes: Vec<SizeEffectivePrice>,
+}
+
+/// Price of an item in one size, after the winning promotion for the size.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SizeEffectivePrice {
+    pub size: String,
+    pub sale_price: f64,
+    pub promotion_id: Option<i64>,
+}
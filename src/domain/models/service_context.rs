@@ -1,7 +1,26 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MaintenanceScope {
+    /// Reads keep working; requests that change data are refused.
+    Writes,
+    All,
+}
+
+/// Service-wide switches shared by every replica.
+#[derive(Clone, PartialEq, Debug)]
 pub struct ServiceContext {
     pub id: i32,
     pub maintenance: bool,
+    pub maintenance_scope: MaintenanceScope,
+    /// Sent as `Retry-After` with refused requests.
+    pub retry_after_secs: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ServiceContext {
+    /// Whether maintenance refuses a request, given whether it only reads.
+    pub fn refuses(&self, read_only: bool) -> bool {
+        self.maintenance && (self.maintenance_scope == MaintenanceScope::All || !read_only)
+    }
 }
@@ -0,0 +1,30 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct TaxClass {
+    pub id: i32,
+    pub name: String,
+    pub rates: Vec<TaxRate>,
+}
+
+/// Percentage of the net price charged for items of the class in the region.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TaxRate {
+    pub tax_class_id: i32,
+    pub region: String,
+    pub rate: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemTaxRate {
+    pub item_id: i64,
+    pub rate: f64,
+}
+
+/// Tax breakdown of the price an item sells for in a region.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemTax {
+    pub region: String,
+    pub rate: f64,
+    pub net: f64,
+    pub tax: f64,
+    pub gross: f64,
+}
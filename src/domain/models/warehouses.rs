@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Warehouse {
+    pub id: i32,
+    pub name: String,
+    /// Receives the stock of items created without an explicit warehouse.
+    pub is_default: bool,
+}
+
+/// Quantity of an item size held in a warehouse.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StockLevel {
+    pub warehouse_id: i32,
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+}
+
+/// Change applied to a single stock level.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StockOperation {
+    /// Replaces the quantity, e.g. after a recount.
+    Set(i32),
+    /// Adds to the quantity; negative values remove stock.
+    Delta(i32),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MovementReason {
+    Receipt,
+    Sale,
+    Reservation,
+    Adjustment,
+    Return,
+}
+
+/// Why and by whom stock is being changed, recorded with every movement.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MovementMeta {
+    pub reason: MovementReason,
+    pub actor: String,
+    pub note: Option<String>,
+}
+
+/// Append-only ledger entry of a stock change.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StockMovement {
+    pub id: i64,
+    pub warehouse_id: i32,
+    pub item_id: i64,
+    pub size: String,
+    pub delta: i32,
+    pub quantity_after: i32,
+    pub reason: MovementReason,
+    pub actor: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Total stock of an item size at or below which it should be reordered.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StockThreshold {
+    pub item_id: i64,
+    pub size: String,
+    pub threshold: i32,
+}
+
+/// Item size whose total stock is at or below its threshold.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LowStock {
+    pub item_id: i64,
+    pub size: String,
+    pub quantity: i32,
+    pub threshold: i32,
+}
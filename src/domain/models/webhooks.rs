@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use std::net::IpAddr;
+
+/// Partner callback for item events. An empty `event_types` list matches
+/// every event.
+#[derive(Clone, PartialEq, Debug)]
+pub struct WebhookSubscription {
+    pub id: i64,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.event_types.is_empty() || self.event_types.iter().any(|t| t == event_type)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    /// Ran out of attempts; only retried by hand.
+    Dead,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub event_id: i64,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// Pending delivery together with where and how to send it.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DueDelivery {
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
+
+/// Outcome of one delivery attempt.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DeliveryAttempt {
+    pub status: DeliveryStatus,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// Whether webhooks may be sent to `ip`: anything but loopback, private,
+/// link-local and other addresses that never leave the network.
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                || a == 0
+                // carrier-grade NAT, 100.64.0.0/10
+                || (a == 100 && b & 0xc0 == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_address(IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // unique local, fc00::/7, and link-local, fe80::/10
+                    || first & 0xfe00 == 0xfc00
+                    || first & 0xffc0 == 0xfe80)
+            }
+        },
+    }
+}
@@ -0,0 +1,14 @@
+use crate::domain::models::events::OutboxEvent;
+use crate::domain::notifiers::stock::NotifierResult;
+use async_trait::async_trait;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+/// Delivers committed item events to other services. Delivery is at least
+/// once, so receivers should deduplicate by event id.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, event: OutboxEvent) -> NotifierResult<()>;
+}
@@ -0,0 +1,3 @@
+pub mod events;
+pub mod stock;
+pub mod webhooks;
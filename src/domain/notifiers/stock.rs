@@ -0,0 +1,16 @@
+use crate::domain::error::NotifierError;
+use crate::domain::models::warehouses::LowStock;
+use async_trait::async_trait;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+pub type NotifierResult<T> = Result<T, NotifierError>;
+
+/// Receives stock events once the change causing them is committed.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait StockNotifier: Send + Sync {
+    /// The total stock of an item size dropped to or below its threshold.
+    async fn low_stock(&self, event: LowStock) -> NotifierResult<()>;
+}
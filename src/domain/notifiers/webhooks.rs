@@ -0,0 +1,14 @@
+use crate::domain::models::webhooks::WebhookRequest;
+use crate::domain::notifiers::stock::NotifierResult;
+use async_trait::async_trait;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait WebhookSender: Send + Sync {
+    /// Posts the request and returns the status code of the answer. Errors
+    /// mean no answer was received.
+    async fn send(&self, request: WebhookRequest) -> NotifierResult<u16>;
+}
@@ -1,9 +1,23 @@
-use crate::domain::models::items::{GetItemsQuery, GetItemsSortBy, Item};
+use crate::domain::models::backorders::BackorderRule;
+use crate::domain::models::bundles::{Bundle, BundleComponent};
+use crate::domain::models::carts::ItemAvailability;
+use crate::domain::models::currencies::{ExchangeRate, PriceListEntry};
+use crate::domain::models::events::OutboxEvent;
+use crate::domain::models::items::{GetItemsQuery, GetItemsSortBy, InventoryStats, Item};
+use crate::domain::models::media::{ItemMedia, NewItemMedia};
+use crate::domain::models::prices::{PriceRecord, ScheduledPrice};
+use crate::domain::models::promotions::{EffectivePrice, Promotion};
+use crate::domain::models::taxes::{ItemTaxRate, TaxClass, TaxRate};
+use crate::domain::models::webhooks::{DeliveryAttempt, DueDelivery, WebhookDelivery, WebhookSubscription};
+use crate::domain::models::warehouses::{
+    LowStock, MovementMeta, StockLevel, StockMovement, StockOperation, StockThreshold, Warehouse,
+};
 use crate::domain::repositories::repository::{
     QueryParams, RepositoryResult, DEFAULT_LIMIT, DEFAULT_OFFSET,
 };
 use crate::infrastructure::models::items::{ItemDiesel, ItemsSizesDiesel, SizeDiesel};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -37,4 +51,253 @@ pub trait Repository: Send + Sync {
     ) -> RepositoryResult<Vec<(ItemDiesel, Vec<SizeDiesel>, Vec<ItemsSizesDiesel>)>>;
 
     async fn create_item(&self, item: Item) -> RepositoryResult<i64>;
+
+    async fn get_items_media(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<ItemMedia>>;
+
+    async fn create_item_media(&self, media: NewItemMedia) -> RepositoryResult<ItemMedia>;
+
+    async fn get_price_history(&self, item_id: i64) -> RepositoryResult<Vec<PriceRecord>>;
+
+    async fn get_scheduled_prices(&self, item_id: i64) -> RepositoryResult<Vec<ScheduledPrice>>;
+
+    async fn change_item_price(
+        &self,
+        item_id: i64,
+        price: f64,
+        valid_from: DateTime<Utc>,
+    ) -> RepositoryResult<PriceRecord>;
+
+    async fn schedule_item_price(
+        &self,
+        item_id: i64,
+        price: f64,
+        starts_at: DateTime<Utc>,
+    ) -> RepositoryResult<ScheduledPrice>;
+
+    async fn cancel_scheduled_price(&self, item_id: i64, schedule_id: i64) -> RepositoryResult<()>;
+
+    /// Applies every pending scheduled price with `starts_at <= now`.
+    async fn apply_due_prices(&self, now: DateTime<Utc>) -> RepositoryResult<Vec<PriceRecord>>;
+
+    /// Sale price and per-size sellable quantity (stock, or bundle stock, plus
+    /// backorder allowance) of the given items, for cart checks. Missing items are left out.
+    async fn get_item_availability(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<ItemAvailability>>;
+
+    async fn get_effective_prices(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<EffectivePrice>>;
+
+    async fn get_promotions(&self) -> RepositoryResult<Vec<Promotion>>;
+
+    async fn create_promotion(&self, promotion: Promotion) -> RepositoryResult<Promotion>;
+
+    async fn delete_promotion(&self, promotion_id: i64) -> RepositoryResult<()>;
+
+    /// Whether items can be priced in `currency` by rate or by price list.
+    async fn currency_exists(&self, currency: String) -> RepositoryResult<bool>;
+
+    /// Prices of the items in `currency`, regional ones first when `region` is
+    /// given; items that can't be priced in it are omitted.
+    async fn get_currency_prices(
+        &self,
+        item_ids: Vec<i64>,
+        currency: String,
+        region: Option<String>,
+    ) -> RepositoryResult<Vec<PriceListEntry>>;
+
+    async fn get_exchange_rates(&self) -> RepositoryResult<Vec<ExchangeRate>>;
+
+    async fn set_exchange_rate(&self, currency: String, rate: f64) -> RepositoryResult<ExchangeRate>;
+
+    /// Entries of every region unless `region` is given.
+    async fn get_price_list(
+        &self,
+        currency: String,
+        region: Option<String>,
+    ) -> RepositoryResult<Vec<PriceListEntry>>;
+
+    async fn set_price_list_price(&self, entry: PriceListEntry) -> RepositoryResult<PriceListEntry>;
+
+    async fn delete_price_list_price(
+        &self,
+        currency: String,
+        region: Option<String>,
+        item_id: i64,
+    ) -> RepositoryResult<()>;
+
+    /// Whether any tax class has a rate or any price list a price in `region`.
+    async fn region_exists(&self, region: String) -> RepositoryResult<bool>;
+
+    /// Rates of the items in `region`; untaxed items are omitted.
+    async fn get_item_tax_rates(
+        &self,
+        item_ids: Vec<i64>,
+        region: String,
+    ) -> RepositoryResult<Vec<ItemTaxRate>>;
+
+    async fn get_tax_classes(&self) -> RepositoryResult<Vec<TaxClass>>;
+
+    async fn create_tax_class(&self, name: String) -> RepositoryResult<TaxClass>;
+
+    async fn set_tax_rate(&self, rate: TaxRate) -> RepositoryResult<TaxRate>;
+
+    async fn delete_tax_rate(&self, tax_class_id: i32, region: String) -> RepositoryResult<()>;
+
+    async fn set_item_tax_class(&self, item_id: i64, tax_class_id: Option<i32>) -> RepositoryResult<()>;
+
+    async fn get_warehouses(&self) -> RepositoryResult<Vec<Warehouse>>;
+
+    async fn create_warehouse(&self, name: String) -> RepositoryResult<Warehouse>;
+
+    async fn get_item_stock(&self, item_id: i64) -> RepositoryResult<Vec<StockLevel>>;
+
+    /// Overwrites the quantities of the given item sizes in the warehouse,
+    /// recording a movement for every changed level.
+    async fn set_stock_levels(
+        &self,
+        warehouse_id: i32,
+        levels: Vec<StockLevel>,
+        meta: MovementMeta,
+    ) -> RepositoryResult<Vec<StockLevel>>;
+
+    /// Applies `operation` to one item size in the warehouse (the default one
+    /// when `None`) under a row lock. Sales and reservations beyond stock are
+    /// backordered as far as the size's rule allows, and receipts fill
+    /// backorders first. Deltas on a bundle size are applied to each component
+    /// instead. Fails with a conflict if the result would still drop below zero.
+    async fn adjust_stock(
+        &self,
+        item_id: i64,
+        size: String,
+        warehouse_id: Option<i32>,
+        operation: StockOperation,
+        meta: MovementMeta,
+    ) -> RepositoryResult<StockLevel>;
+
+    async fn set_stock_threshold(&self, threshold: StockThreshold) -> RepositoryResult<StockThreshold>;
+
+    async fn delete_stock_threshold(&self, item_id: i64, size: String) -> RepositoryResult<()>;
+
+    /// Item sizes whose total stock is at or below their threshold.
+    async fn get_low_stock(&self) -> RepositoryResult<Vec<LowStock>>;
+
+    /// Creates or replaces the rule of an item size, keeping its backordered count.
+    async fn set_backorder_rule(&self, rule: BackorderRule) -> RepositoryResult<BackorderRule>;
+
+    async fn delete_backorder_rule(&self, item_id: i64, size: String) -> RepositoryResult<()>;
+
+    async fn get_backorder_rules(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<BackorderRule>>;
+
+    /// Replaces the components of a bundle size; an empty list turns it back
+    /// into a plain size. Nested bundles are rejected with a conflict.
+    async fn set_bundle(
+        &self,
+        item_id: i64,
+        size: String,
+        components: Vec<BundleComponent>,
+    ) -> RepositoryResult<Bundle>;
+
+    /// Bundle sizes of the given items with current component stock.
+    async fn get_bundles(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<Bundle>>;
+
+    /// Claims the oldest undelivered outbox events, up to `limit`, until
+    /// `claimed_until`. Events another relay claimed before `now` are skipped.
+    async fn claim_pending_events(
+        &self,
+        now: DateTime<Utc>,
+        claimed_until: DateTime<Utc>,
+        limit: i64,
+    ) -> RepositoryResult<Vec<OutboxEvent>>;
+
+    async fn mark_event_delivered(&self, event_id: i64) -> RepositoryResult<()>;
+
+    /// Counts a failed delivery attempt; the event stays pending.
+    async fn mark_event_failed(&self, event_id: i64, error: String) -> RepositoryResult<()>;
+
+    /// Hands claimed events that were not published back to every relay.
+    async fn release_events(&self, event_ids: Vec<i64>) -> RepositoryResult<()>;
+
+    /// Settled outbox events following `after_id` in transaction order. An
+    /// event settles once every transaction started before its own has ended,
+    /// so no earlier event can show up after it. An empty `item_ids` matches
+    /// every item.
+    async fn get_events_after(
+        &self,
+        after_id: i64,
+        item_ids: Vec<i64>,
+        limit: i64,
+    ) -> RepositoryResult<Vec<OutboxEvent>>;
+
+    /// Id of the last settled outbox event, 0 when there is none.
+    async fn get_last_event_id(&self) -> RepositoryResult<i64>;
+
+    async fn get_webhook_subscriptions(&self) -> RepositoryResult<Vec<WebhookSubscription>>;
+
+    async fn get_webhook_subscription(&self, subscription_id: i64) -> RepositoryResult<WebhookSubscription>;
+
+    async fn create_webhook_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> RepositoryResult<WebhookSubscription>;
+
+    /// Replaces url, secret, filters and state of an existing subscription.
+    async fn update_webhook_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> RepositoryResult<WebhookSubscription>;
+
+    async fn delete_webhook_subscription(&self, subscription_id: i64) -> RepositoryResult<()>;
+
+    /// Queues `payload` for every active subscription matching the event type
+    /// and returns how many were queued. Queuing an event again is a no-op.
+    async fn enqueue_webhook_deliveries(
+        &self,
+        event_id: i64,
+        event_type: String,
+        payload: serde_json::Value,
+    ) -> RepositoryResult<usize>;
+
+    /// Claims the pending deliveries whose next attempt is due at `now`,
+    /// oldest first, by moving their next attempt to `leased_until`.
+    /// Deliveries claimed by another dispatcher are skipped.
+    async fn claim_due_webhook_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        leased_until: DateTime<Utc>,
+        limit: i64,
+    ) -> RepositoryResult<Vec<DueDelivery>>;
+
+    async fn record_webhook_attempt(&self, delivery_id: i64, attempt: DeliveryAttempt) -> RepositoryResult<()>;
+
+    /// Delivery log of a subscription, newest first.
+    async fn get_webhook_deliveries(
+        &self,
+        subscription_id: i64,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<Vec<WebhookDelivery>>;
+
+    /// Queues a dead delivery again with a fresh set of attempts, due at `now`.
+    /// Other deliveries are a conflict.
+    async fn retry_webhook_delivery(
+        &self,
+        subscription_id: i64,
+        delivery_id: i64,
+        now: DateTime<Utc>,
+    ) -> RepositoryResult<WebhookDelivery>;
+
+    /// Ledger of an item size across warehouses, newest first.
+    async fn get_stock_movements(
+        &self,
+        item_id: i64,
+        size: String,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<Vec<StockMovement>>;
+
+    /// Checks out a connection and runs a trivial query on it.
+    async fn ping(&self) -> RepositoryResult<()>;
+
+    /// Embedded migrations not applied to the database yet, oldest first.
+    async fn get_pending_migrations(&self) -> RepositoryResult<Vec<String>>;
+
+    async fn get_inventory_stats(&self) -> RepositoryResult<InventoryStats>;
 }
@@ -35,6 +35,7 @@ impl QueryParams for QueryParamsImpl {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<ResultPaging<ItemDTO>> for ResultPaging<Item> {
     fn into(self) -> ResultPaging<ItemDTO> {
         ResultPaging {
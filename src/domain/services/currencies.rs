@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::currencies::{ExchangeRate, PriceListEntry};
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait CurrencyService: Sync + Send {
+    async fn get_exchange_rates(&self) -> Result<Vec<ExchangeRate>, CommonError>;
+
+    async fn set_exchange_rate(&self, currency: String, rate: f64) -> Result<ExchangeRate, CommonError>;
+
+    async fn get_price_list(
+        &self,
+        currency: String,
+        region: Option<String>,
+    ) -> Result<Vec<PriceListEntry>, CommonError>;
+
+    async fn set_price_list_price(&self, entry: PriceListEntry) -> Result<PriceListEntry, CommonError>;
+
+    async fn delete_price_list_price(
+        &self,
+        currency: String,
+        region: Option<String>,
+        item_id: i64,
+    ) -> Result<(), CommonError>;
+}
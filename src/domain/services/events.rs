@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::events::OutboxEvent;
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait EventService: Sync + Send {
+    /// Publishes pending outbox events in order and returns how many were
+    /// delivered. Stops at the first failure so later events never overtake it.
+    async fn relay_events(&self) -> Result<usize, CommonError>;
+
+    /// Next page of the change feed after `after_id`, oldest first. An empty
+    /// `item_ids` matches every item.
+    async fn get_events_after(
+        &self,
+        after_id: i64,
+        item_ids: Vec<i64>,
+    ) -> Result<Vec<OutboxEvent>, CommonError>;
+
+    /// Where a client without a `Last-Event-ID` starts following the feed.
+    async fn get_last_event_id(&self) -> Result<i64, CommonError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::models::health::HealthReport;
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait HealthService: Sync + Send {
+    /// Probes everything the service needs to take traffic: the database,
+    /// the schema version and the maintenance switch.
+    async fn check_readiness(&self) -> HealthReport;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::media::{ItemMedia, MediaUpload};
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait MediaService: Sync + Send {
+    async fn upload_item_media(&self, upload: MediaUpload) -> Result<ItemMedia, CommonError>;
+}
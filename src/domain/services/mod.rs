@@ -1,2 +1,11 @@
+pub mod currencies;
+pub mod events;
+pub mod health;
+pub mod media;
 pub mod order;
+pub mod prices;
+pub mod promotions;
 pub mod service_context;
+pub mod taxes;
+pub mod warehouses;
+pub mod webhooks;
@@ -3,6 +3,8 @@ use async_trait::async_trait;
 use mockall::automock;
 
 use crate::domain::error::CommonError;
+use crate::domain::models::bundles::{Bundle, BundleComponent};
+use crate::domain::models::carts::{CartLine, CartLineResult};
 use crate::domain::models::items::{GetItemsQuery, GetItemsSortBy, Item};
 use crate::domain::repositories::repository::ResultPaging;
 
@@ -18,4 +20,16 @@ pub trait CoreService: Sync + Send {
     ) -> Result<ResultPaging<Item>, CommonError>;
 
     async fn create_item(&self, item: Item) -> Result<i64, CommonError>;
+
+    /// Checks stock and price of every line, in request order.
+    async fn validate_cart(&self, lines: Vec<CartLine>) -> Result<Vec<CartLineResult>, CommonError>;
+
+    async fn set_bundle(
+        &self,
+        item_id: i64,
+        size: String,
+        components: Vec<BundleComponent>,
+    ) -> Result<Bundle, CommonError>;
+
+    async fn get_bundles(&self, item_id: i64) -> Result<Vec<Bundle>, CommonError>;
 }
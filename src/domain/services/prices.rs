@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::prices::{ItemPrices, PriceChange, PriceRecord};
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait PriceService: Sync + Send {
+    async fn get_item_prices(&self, item_id: i64) -> Result<ItemPrices, CommonError>;
+
+    /// Changes the price right away when `starts_at` is absent or already
+    /// passed, otherwise schedules it.
+    async fn set_item_price(
+        &self,
+        item_id: i64,
+        price: f64,
+        starts_at: Option<DateTime<Utc>>,
+    ) -> Result<PriceChange, CommonError>;
+
+    async fn cancel_scheduled_price(&self, item_id: i64, schedule_id: i64)
+        -> Result<(), CommonError>;
+
+    async fn apply_due_prices(&self) -> Result<Vec<PriceRecord>, CommonError>;
+}
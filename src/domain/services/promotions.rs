@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::promotions::Promotion;
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait PromotionService: Sync + Send {
+    async fn get_promotions(&self) -> Result<Vec<Promotion>, CommonError>;
+
+    async fn create_promotion(&self, promotion: Promotion) -> Result<Promotion, CommonError>;
+
+    async fn delete_promotion(&self, promotion_id: i64) -> Result<(), CommonError>;
+}
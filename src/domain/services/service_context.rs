@@ -1,7 +1,14 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
 use crate::domain::models::service_context::ServiceContext;
 
-pub trait ServiceContextService {
-    fn get_service_context(&self) -> ServiceContext;
-    fn update(&self, service_context: ServiceContext) -> ServiceContext;
-    fn is_maintenance_active(&self) -> bool;
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ServiceContextService: Sync + Send {
+    async fn get_service_context(&self) -> Result<ServiceContext, CommonError>;
+    async fn update(&self, service_context: ServiceContext) -> Result<ServiceContext, CommonError>;
+    async fn is_maintenance_active(&self) -> Result<bool, CommonError>;
 }
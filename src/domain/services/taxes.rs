@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::taxes::{TaxClass, TaxRate};
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait TaxService: Sync + Send {
+    async fn get_tax_classes(&self) -> Result<Vec<TaxClass>, CommonError>;
+
+    async fn create_tax_class(&self, name: String) -> Result<TaxClass, CommonError>;
+
+    async fn set_tax_rate(&self, rate: TaxRate) -> Result<TaxRate, CommonError>;
+
+    async fn delete_tax_rate(&self, tax_class_id: i32, region: String) -> Result<(), CommonError>;
+
+    async fn set_item_tax_class(&self, item_id: i64, tax_class_id: Option<i32>) -> Result<(), CommonError>;
+}
@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::backorders::BackorderRule;
+use crate::domain::models::warehouses::{
+    LowStock, MovementMeta, StockLevel, StockMovement, StockOperation, StockThreshold, Warehouse,
+};
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait WarehouseService: Sync + Send {
+    async fn get_warehouses(&self) -> Result<Vec<Warehouse>, CommonError>;
+
+    async fn create_warehouse(&self, name: String) -> Result<Warehouse, CommonError>;
+
+    async fn get_item_stock(&self, item_id: i64) -> Result<Vec<StockLevel>, CommonError>;
+
+    async fn set_stock_levels(
+        &self,
+        warehouse_id: i32,
+        levels: Vec<StockLevel>,
+        meta: MovementMeta,
+    ) -> Result<Vec<StockLevel>, CommonError>;
+
+    async fn adjust_stock(
+        &self,
+        item_id: i64,
+        size: String,
+        warehouse_id: Option<i32>,
+        operation: StockOperation,
+        meta: MovementMeta,
+    ) -> Result<StockLevel, CommonError>;
+
+    async fn set_stock_threshold(
+        &self,
+        threshold: StockThreshold,
+    ) -> Result<StockThreshold, CommonError>;
+
+    async fn delete_stock_threshold(&self, item_id: i64, size: String) -> Result<(), CommonError>;
+
+    async fn get_low_stock(&self) -> Result<Vec<LowStock>, CommonError>;
+
+    async fn set_backorder_rule(&self, rule: BackorderRule) -> Result<BackorderRule, CommonError>;
+
+    async fn delete_backorder_rule(&self, item_id: i64, size: String) -> Result<(), CommonError>;
+
+    async fn get_stock_movements(
+        &self,
+        item_id: i64,
+        size: String,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<StockMovement>, CommonError>;
+}
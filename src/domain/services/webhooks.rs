@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+#[allow(unused_imports)]
+use mockall::automock;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::webhooks::{WebhookDelivery, WebhookSubscription};
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait WebhookService: Sync + Send {
+    async fn get_subscriptions(&self) -> Result<Vec<WebhookSubscription>, CommonError>;
+
+    async fn get_subscription(&self, subscription_id: i64) -> Result<WebhookSubscription, CommonError>;
+
+    /// Generates a secret when none is given.
+    async fn create_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, CommonError>;
+
+    /// Keeps the current secret when none is given.
+    async fn update_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, CommonError>;
+
+    async fn delete_subscription(&self, subscription_id: i64) -> Result<(), CommonError>;
+
+    async fn get_deliveries(
+        &self,
+        subscription_id: i64,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, CommonError>;
+
+    async fn retry_delivery(
+        &self,
+        subscription_id: i64,
+        delivery_id: i64,
+    ) -> Result<WebhookDelivery, CommonError>;
+
+    /// Attempts every due delivery once and returns how many succeeded.
+    async fn dispatch_deliveries(&self) -> Result<usize, CommonError>;
+}
@@ -0,0 +1,17 @@
+use crate::domain::error::StorageError;
+use async_trait::async_trait;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+pub type StorageResult<T> = Result<T, StorageError>;
+
+/// Binary storage for item media. `key` is a relative path such as
+/// `items/1/<uuid>.png`; implementations return the public url of the object.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait MediaStorage: Send + Sync {
+    async fn save(&self, key: String, content: Vec<u8>) -> StorageResult<String>;
+
+    async fn delete(&self, key: String) -> StorageResult<()>;
+}
@@ -0,0 +1,47 @@
+use diesel::connection::{Instrumentation, InstrumentationEvent};
+use tracing::{field, info_span, Span};
+
+/// Bind values are left out of spans, they may hold customer data.
+const BINDS_SEPARATOR: &str = " -- binds: ";
+
+/// Opens a span per SQL statement as a child of the current span, with the
+/// statement text and, when it fails, the error.
+#[derive(Default)]
+pub struct QueryTracing {
+    statements: Vec<Span>,
+}
+
+impl Instrumentation for QueryTracing {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        match event {
+            InstrumentationEvent::StartQuery { query, .. } => {
+                let span = info_span!(
+                    "db.statement",
+                    otel.kind = "client",
+                    db.system = "postgresql",
+                    db.statement = field::Empty,
+                    otel.status_code = field::Empty,
+                    error.message = field::Empty,
+                );
+                if !span.is_disabled() {
+                    let sql = query.to_string();
+                    let statement = sql.split(BINDS_SEPARATOR).next().unwrap_or_default();
+                    span.record("db.statement", statement);
+                }
+                self.statements.push(span);
+            }
+            InstrumentationEvent::FinishQuery { error, .. } => {
+                if let (Some(span), Some(error)) = (self.statements.pop(), error) {
+                    span.record("otel.status_code", "ERROR");
+                    span.record("error.message", error.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Records on the current repository span how many rows a call returned.
+pub fn record_rows(rows: usize) {
+    Span::current().record("db.rows", rows as i64);
+}
@@ -0,0 +1,58 @@
+use crate::domain::error::{RepositoryError, RepositoryErrorKind};
+use crate::domain::repositories::repository::RepositoryResult;
+use diesel::migration::{Migration, MigrationSource};
+use diesel::pg::{Pg, PgConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+/// The `migrations/` directory, compiled into the binary.
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+fn migration_error(error: Box<dyn std::error::Error + Send + Sync>) -> RepositoryError {
+    RepositoryError {
+        message: error.to_string(),
+        code: RepositoryErrorKind::Unknown,
+    }
+}
+
+fn migrations() -> RepositoryResult<Vec<Box<dyn Migration<Pg>>>> {
+    MigrationSource::<Pg>::migrations(&MIGRATIONS).map_err(migration_error)
+}
+
+/// Applies every pending migration and returns their names.
+pub fn run_pending(conn: &mut PgConnection) -> RepositoryResult<Vec<String>> {
+    let pending = conn.pending_migrations(MIGRATIONS).map_err(migration_error)?;
+    let names = pending.iter().map(|m| m.name().to_string()).collect();
+    conn.run_migrations(&pending).map_err(migration_error)?;
+
+    Ok(names)
+}
+
+/// Names of the migrations not applied yet, oldest first.
+pub fn pending(conn: &mut PgConnection) -> RepositoryResult<Vec<String>> {
+    Ok(conn
+        .pending_migrations(MIGRATIONS)
+        .map_err(migration_error)?
+        .iter()
+        .map(|m| m.name().to_string())
+        .collect())
+}
+
+/// Reverts the newest applied migration and returns its name.
+pub fn revert_last(conn: &mut PgConnection) -> RepositoryResult<String> {
+    let version = conn.revert_last_migration(MIGRATIONS).map_err(migration_error)?;
+
+    Ok(migrations()?
+        .iter()
+        .find(|m| m.name().version() == version)
+        .map_or_else(|| version.to_string(), |m| m.name().to_string()))
+}
+
+/// Every known migration, oldest first, with whether it is applied.
+pub fn status(conn: &mut PgConnection) -> RepositoryResult<Vec<(String, bool)>> {
+    let applied = conn.applied_migrations().map_err(migration_error)?;
+
+    Ok(migrations()?
+        .iter()
+        .map(|m| (m.name().to_string(), applied.contains(&m.name().version())))
+        .collect())
+}
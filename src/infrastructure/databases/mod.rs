@@ -1 +1,4 @@
+pub mod instrumentation;
+pub mod listener;
+pub mod migrations;
 pub mod postgresql;
@@ -2,6 +2,9 @@ use diesel;
 use diesel::pg::PgConnection;
 use diesel::r2d2;
 use diesel::r2d2::ConnectionManager;
+use crate::infrastructure::metrics::{Metrics, PoolEventMetrics};
+use std::sync::Arc;
+use std::time::Duration;
 
 pub type Pool<T> = r2d2::Pool<ConnectionManager<T>>;
 
@@ -15,3 +18,19 @@ pub fn db_pool(database_url: String, pool_size: u32) -> DBConn {
         .build(manager)
         .expect("Failed to register_order pool")
 }
+
+/// Pool whose checkout waits and timeouts are recorded in `metrics`.
+pub fn db_pool_with_metrics(
+    database_url: String,
+    pool_size: u32,
+    connection_timeout: Duration,
+    metrics: Arc<Metrics>,
+) -> DBConn {
+    let manager = ConnectionManager::<PgConnection>::new(database_url);
+    Pool::builder()
+        .max_size(pool_size)
+        .connection_timeout(connection_timeout)
+        .event_handler(Box::new(PoolEventMetrics(metrics)))
+        .build(manager)
+        .expect("Failed to register_order pool")
+}
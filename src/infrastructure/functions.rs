@@ -0,0 +1,31 @@
+// SQL functions created by migrations.
+
+use diesel::sql_types::{Float8, Int8, Nullable, Text, Varchar};
+
+diesel::define_sql_function! {
+    /// Price of the item in `currency` and, when given, `region`; `NULL` when
+    /// it can't be priced in it.
+    fn item_price_in(
+        item_id: Int8,
+        base_price: Float8,
+        currency: Varchar,
+        region: Nullable<Varchar>,
+    ) -> Nullable<Float8>;
+}
+
+diesel::define_sql_function! {
+    /// Price after promotions of the item in `currency`.
+    fn item_sale_price_in(item_id: Int8, currency: Varchar, region: Nullable<Varchar>) -> Nullable<Float8>;
+}
+
+diesel::define_sql_function! {
+    /// Oldest transaction still running; outbox events with a lower `txid`
+    /// can no longer be joined by earlier ones.
+    fn outbox_settled_txid() -> Int8;
+}
+
+diesel::define_sql_function! {
+    /// Built in; delivers `payload` to listeners of `channel` once the
+    /// transaction commits.
+    fn pg_notify(channel: Text, payload: Text);
+}
@@ -0,0 +1,209 @@
+use crate::domain::repositories::items::Repository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use diesel::r2d2::event::{CheckoutEvent, TimeoutEvent};
+use diesel::r2d2::HandleEvent;
+use log::error;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+    IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Every metric the service exports on `/metrics`, kept in its own registry
+/// so tests can create as many as they like.
+pub struct Metrics {
+    registry: Registry,
+    pub http_requests: IntCounterVec,
+    pub http_request_duration: HistogramVec,
+    pub db_pool_connections: IntGaugeVec,
+    pub db_pool_max_connections: IntGauge,
+    pub db_pool_wait: Histogram,
+    pub db_pool_timeouts: IntCounter,
+    pub repository_query_duration: HistogramVec,
+    pub catalog_items: IntGauge,
+    pub catalog_out_of_stock_sizes: IntGauge,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests by route pattern and status."),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time to produce the response head, by route pattern.",
+            ),
+            &["method", "route"],
+        )
+        .unwrap();
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Pooled database connections by state."),
+            &["state"],
+        )
+        .unwrap();
+        let db_pool_max_connections = IntGauge::new(
+            "db_pool_max_connections",
+            "Size limit of the database pool.",
+        )
+        .unwrap();
+        let db_pool_wait = Histogram::with_opts(HistogramOpts::new(
+            "db_pool_wait_seconds",
+            "Time spent waiting to check out a database connection.",
+        ))
+        .unwrap();
+        let db_pool_timeouts = IntCounter::new(
+            "db_pool_timeouts_total",
+            "Checkouts that gave up waiting for a database connection.",
+        )
+        .unwrap();
+        let repository_query_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "repository_query_duration_seconds",
+                "Time of repository calls, including the connection checkout.",
+            ),
+            &["query", "outcome"],
+        )
+        .unwrap();
+        let catalog_items = IntGauge::new("catalog_items", "Items in the catalog.").unwrap();
+        let catalog_out_of_stock_sizes = IntGauge::new(
+            "catalog_out_of_stock_sizes",
+            "Item sizes without stock left.",
+        )
+        .unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry.register(Box::new(http_request_duration.clone())).unwrap();
+        registry.register(Box::new(db_pool_connections.clone())).unwrap();
+        registry.register(Box::new(db_pool_max_connections.clone())).unwrap();
+        registry.register(Box::new(db_pool_wait.clone())).unwrap();
+        registry.register(Box::new(db_pool_timeouts.clone())).unwrap();
+        registry.register(Box::new(repository_query_duration.clone())).unwrap();
+        registry.register(Box::new(catalog_items.clone())).unwrap();
+        registry.register(Box::new(catalog_out_of_stock_sizes.clone())).unwrap();
+
+        Metrics {
+            registry,
+            http_requests,
+            http_request_duration,
+            db_pool_connections,
+            db_pool_max_connections,
+            db_pool_wait,
+            db_pool_timeouts,
+            repository_query_duration,
+            catalog_items,
+            catalog_out_of_stock_sizes,
+        }
+    }
+
+    /// The registry in the Prometheus text format.
+    pub fn render(&self) -> String {
+        let mut buffer = vec![];
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .unwrap();
+
+        String::from_utf8(buffer).unwrap()
+    }
+
+    pub fn observe_query(&self, query: &str, elapsed: Duration, ok: bool) {
+        self.repository_query_duration
+            .with_label_values(&[query, if ok { "ok" } else { "error" }])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_pool(&self, pool: &DBConn) {
+        let state = pool.state();
+
+        self.db_pool_connections
+            .with_label_values(&["idle"])
+            .set(state.idle_connections as i64);
+        self.db_pool_connections
+            .with_label_values(&["in_use"])
+            .set((state.connections - state.idle_connections) as i64);
+        self.db_pool_max_connections.set(pool.max_size() as i64);
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Feeds pool checkout waits and timeouts into the metrics.
+pub struct PoolEventMetrics(pub Arc<Metrics>);
+
+impl fmt::Debug for PoolEventMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PoolEventMetrics")
+    }
+}
+
+impl HandleEvent for PoolEventMetrics {
+    fn handle_checkout(&self, event: CheckoutEvent) {
+        self.0.db_pool_wait.observe(event.duration().as_secs_f64());
+    }
+
+    fn handle_timeout(&self, _event: TimeoutEvent) {
+        self.0.db_pool_timeouts.inc();
+    }
+}
+
+/// Periodically samples the pool and the catalog gauges, which would be too
+/// costly to compute on every scrape.
+pub fn spawn_metrics_collector(
+    metrics: Arc<Metrics>,
+    pool: Arc<DBConn>,
+    repository: Arc<dyn Repository>,
+    period: Duration,
+) -> actix_web::rt::task::JoinHandle<()> {
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(period);
+        loop {
+            interval.tick().await;
+
+            metrics.observe_pool(&pool);
+            match repository.get_inventory_stats().await {
+                Ok(stats) => {
+                    metrics.catalog_items.set(stats.items);
+                    metrics.catalog_out_of_stock_sizes.set(stats.out_of_stock_sizes);
+                }
+                Err(e) => error!("failed to collect inventory metrics: {}", e.message),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_metrics() {
+        let metrics = Metrics::new();
+        metrics
+            .http_requests
+            .with_label_values(&["GET", "/v1/items/{id}/stock", "200"])
+            .inc();
+        metrics.observe_query("get_items", Duration::from_millis(20), true);
+        metrics.catalog_items.set(42);
+
+        let rendered = metrics.render();
+
+        assert!(rendered.contains(
+            "http_requests_total{method=\"GET\",route=\"/v1/items/{id}/stock\",status=\"200\"} 1"
+        ));
+        assert!(rendered.contains(
+            "repository_query_duration_seconds_bucket{outcome=\"ok\",query=\"get_items\",le=\"0.025\"} 1"
+        ));
+        assert!(rendered.contains("catalog_items 42"));
+        assert!(rendered.contains("# TYPE db_pool_wait_seconds histogram"));
+    }
+}
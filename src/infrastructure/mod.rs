@@ -1,5 +1,10 @@
 pub mod databases;
+pub mod functions;
+pub mod metrics;
 pub mod models;
+pub mod notifiers;
 pub mod repositories;
 pub mod schema;
 pub mod services;
+pub mod storages;
+pub mod views;
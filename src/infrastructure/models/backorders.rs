@@ -0,0 +1,48 @@
+use crate::domain::models::backorders::{BackorderPolicy, BackorderRule};
+use crate::infrastructure::schema::backorder_rules;
+use chrono::NaiveDate;
+use diesel;
+use diesel::prelude::*;
+
+pub const POLICY_DENY: &str = "deny";
+pub const POLICY_BACKORDER: &str = "backorder";
+pub const POLICY_PREORDER: &str = "preorder";
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = backorder_rules)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct BackorderRuleDiesel {
+    pub item_id: i64,
+    pub size_id: i32,
+    pub policy: String,
+    pub backorder_limit: Option<i32>,
+    pub expected_date: Option<NaiveDate>,
+    pub backordered: i32,
+}
+
+pub fn policy_to_str(policy: BackorderPolicy) -> &'static str {
+    match policy {
+        BackorderPolicy::Deny => POLICY_DENY,
+        BackorderPolicy::Backorder => POLICY_BACKORDER,
+        BackorderPolicy::Preorder => POLICY_PREORDER,
+    }
+}
+
+impl From<(BackorderRuleDiesel, String)> for BackorderRule {
+    fn from(value: (BackorderRuleDiesel, String)) -> Self {
+        let (rule, size) = value;
+
+        BackorderRule {
+            item_id: rule.item_id,
+            size,
+            policy: match rule.policy.as_str() {
+                POLICY_BACKORDER => BackorderPolicy::Backorder,
+                POLICY_PREORDER => BackorderPolicy::Preorder,
+                _ => BackorderPolicy::Deny,
+            },
+            limit: rule.backorder_limit,
+            expected_date: rule.expected_date,
+            backordered: rule.backordered,
+        }
+    }
+}
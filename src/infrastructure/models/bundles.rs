@@ -0,0 +1,14 @@
+use crate::infrastructure::schema::bundle_components;
+use diesel;
+use diesel::prelude::*;
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = bundle_components)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct BundleComponentDiesel {
+    pub bundle_id: i64,
+    pub bundle_size_id: i32,
+    pub item_id: i64,
+    pub size_id: i32,
+    pub quantity: i32,
+}
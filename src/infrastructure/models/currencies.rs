@@ -0,0 +1,59 @@
+use crate::domain::models::currencies::{ExchangeRate, PriceListEntry};
+use crate::infrastructure::schema::{exchange_rates, item_currency_prices};
+use chrono::{DateTime, Utc};
+use diesel;
+use diesel::prelude::*;
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = exchange_rates)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExchangeRateDiesel {
+    pub currency: String,
+    pub rate: f64,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = item_currency_prices)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemCurrencyPriceDiesel {
+    pub item_id: i64,
+    pub currency: String,
+    pub price: f64,
+    pub region: String,
+}
+
+/// Stored region of the prices that apply in every region.
+pub const ALL_REGIONS: &str = "";
+
+impl From<ExchangeRateDiesel> for ExchangeRate {
+    fn from(value: ExchangeRateDiesel) -> Self {
+        ExchangeRate {
+            currency: value.currency,
+            rate: value.rate,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<ItemCurrencyPriceDiesel> for PriceListEntry {
+    fn from(value: ItemCurrencyPriceDiesel) -> Self {
+        PriceListEntry {
+            item_id: value.item_id,
+            currency: value.currency,
+            region: Some(value.region).filter(|r| r != ALL_REGIONS),
+            price: value.price,
+        }
+    }
+}
+
+impl From<PriceListEntry> for ItemCurrencyPriceDiesel {
+    fn from(value: PriceListEntry) -> Self {
+        ItemCurrencyPriceDiesel {
+            item_id: value.item_id,
+            currency: value.currency,
+            price: value.price,
+            region: value.region.unwrap_or_else(|| ALL_REGIONS.to_string()),
+        }
+    }
+}
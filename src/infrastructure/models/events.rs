@@ -0,0 +1,53 @@
+use crate::domain::models::events::{ItemEvent, OutboxEvent};
+use crate::infrastructure::schema::outbox_events;
+use chrono::{DateTime, Utc};
+use diesel;
+use diesel::prelude::*;
+
+#[derive(Queryable)]
+#[diesel(table_name = outbox_events)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct OutboxEventDiesel {
+    pub id: i64,
+    pub event_type: String,
+    pub item_id: i64,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub claimed_until: Option<DateTime<Utc>>,
+    pub txid: i64,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = outbox_events)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimpleOutboxEventDiesel {
+    pub event_type: String,
+    pub item_id: i64,
+    pub payload: serde_json::Value,
+}
+
+impl From<&ItemEvent> for SimpleOutboxEventDiesel {
+    fn from(event: &ItemEvent) -> Self {
+        SimpleOutboxEventDiesel {
+            event_type: event.event_type().to_string(),
+            item_id: event.item_id(),
+            payload: serde_json::to_value(event).expect("item events serialize to json"),
+        }
+    }
+}
+
+impl TryFrom<OutboxEventDiesel> for OutboxEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(value: OutboxEventDiesel) -> Result<Self, Self::Error> {
+        Ok(OutboxEvent {
+            id: value.id,
+            event: serde_json::from_value(value.payload)?,
+            created_at: value.created_at,
+            attempts: value.attempts,
+        })
+    }
+}
@@ -11,6 +11,8 @@ pub struct ItemDiesel {
     pub name: String,
     pub description: String,
     pub price: f64,
+    pub category: Option<String>,
+    pub tax_class_id: Option<i32>,
 }
 
 #[derive(Insertable)]
@@ -20,6 +22,8 @@ pub struct SimpleItemDiesel {
     pub name: String,
     pub description: String,
     pub price: f64,
+    pub category: Option<String>,
+    pub tax_class_id: Option<i32>,
 }
 
 
@@ -85,7 +89,17 @@ impl From<(ItemDiesel, Vec<SizeDiesel>, Vec<ItemsSizesDiesel>)> for Item {
             name: value.0.name.clone(),
             description: value.0.description.clone(),
             price: value.0.price,
+            category: value.0.category.clone(),
+            tax_class_id: value.0.tax_class_id,
             sizes,
+            media: vec![],
+            sale_price: None,
+            promotion_id: None,
+            size_sale_prices: vec![],
+            currency: None,
+            tax: None,
+            availability: None,
+            expected_ship_date: None,
         }
     }
 }
\ No newline at end of file
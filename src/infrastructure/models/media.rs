@@ -0,0 +1,46 @@
+use crate::domain::models::media::ItemMedia;
+use crate::infrastructure::schema::items_media;
+use diesel;
+use diesel::prelude::*;
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = items_media)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemMediaDiesel {
+    pub id: i64,
+    pub item_id: i64,
+    pub url: String,
+    pub path: Option<String>,
+    pub alt_text: String,
+    pub position: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = items_media)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimpleItemMediaDiesel {
+    pub item_id: i64,
+    pub url: String,
+    pub path: Option<String>,
+    pub alt_text: String,
+    pub position: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl From<ItemMediaDiesel> for ItemMedia {
+    fn from(value: ItemMediaDiesel) -> Self {
+        ItemMedia {
+            id: value.id,
+            item_id: value.item_id,
+            url: value.url,
+            path: value.path,
+            alt_text: value.alt_text,
+            position: value.position,
+            width: value.width,
+            height: value.height,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::domain::models::prices::{PriceRecord, ScheduledPrice};
+use crate::infrastructure::schema::{item_price_history, item_price_schedules};
+use chrono::{DateTime, Utc};
+use diesel;
+use diesel::prelude::*;
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = item_price_history)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct PriceHistoryDiesel {
+    pub id: i64,
+    pub item_id: i64,
+    pub price: f64,
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = item_price_history)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimplePriceHistoryDiesel {
+    pub item_id: i64,
+    pub price: f64,
+    pub valid_from: DateTime<Utc>,
+}
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = item_price_schedules)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct PriceScheduleDiesel {
+    pub id: i64,
+    pub item_id: i64,
+    pub price: f64,
+    pub starts_at: DateTime<Utc>,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub superseded_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = item_price_schedules)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimplePriceScheduleDiesel {
+    pub item_id: i64,
+    pub price: f64,
+    pub starts_at: DateTime<Utc>,
+}
+
+impl From<PriceHistoryDiesel> for PriceRecord {
+    fn from(value: PriceHistoryDiesel) -> Self {
+        PriceRecord {
+            id: value.id,
+            item_id: value.item_id,
+            price: value.price,
+            valid_from: value.valid_from,
+            valid_to: value.valid_to,
+        }
+    }
+}
+
+impl From<PriceScheduleDiesel> for ScheduledPrice {
+    fn from(value: PriceScheduleDiesel) -> Self {
+        ScheduledPrice {
+            id: value.id,
+            item_id: value.item_id,
+            price: value.price,
+            starts_at: value.starts_at,
+            applied_at: value.applied_at,
+        }
+    }
+}
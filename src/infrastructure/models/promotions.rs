@@ -0,0 +1,144 @@
+use crate::domain::models::promotions::{DiscountKind, EffectivePrice, Promotion, PromotionTarget, SizeEffectivePrice};
+use crate::infrastructure::schema::promotions;
+use chrono::{DateTime, Utc};
+use diesel;
+use diesel::prelude::*;
+
+pub const KIND_PERCENTAGE: &str = "percentage";
+pub const KIND_FIXED: &str = "fixed";
+pub const TARGET_ITEM: &str = "item";
+pub const TARGET_CATEGORY: &str = "category";
+pub const TARGET_SIZE: &str = "size";
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = promotions)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct PromotionDiesel {
+    pub id: i64,
+    pub name: String,
+    pub kind: String,
+    pub value: f64,
+    pub target: String,
+    pub item_id: Option<i64>,
+    pub category: Option<String>,
+    pub size_id: Option<i32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub priority: i32,
+    pub active: bool,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = promotions)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimplePromotionDiesel {
+    pub name: String,
+    pub kind: String,
+    pub value: f64,
+    pub target: String,
+    pub item_id: Option<i64>,
+    pub category: Option<String>,
+    pub size_id: Option<i32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub priority: i32,
+    pub active: bool,
+}
+
+#[derive(Queryable)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct EffectivePriceDiesel {
+    pub item_id: i64,
+    pub price: f64,
+    pub sale_price: f64,
+    pub promotion_id: Option<i64>,
+}
+
+#[derive(Queryable)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SizeEffectivePriceDiesel {
+    pub item_id: i64,
+    pub size_id: i32,
+    pub size: String,
+    pub price: f64,
+    pub sale_price: f64,
+    pub promotion_id: Option<i64>,
+}
+
+impl SimplePromotionDiesel {
+    /// `size_id` has to be resolved by the caller for size targets.
+    pub fn new(promotion: Promotion, size_id: Option<i32>) -> Self {
+        let (target, item_id, category) = match promotion.target {
+            PromotionTarget::Item(id) => (TARGET_ITEM, Some(id), None),
+            PromotionTarget::Category(category) => (TARGET_CATEGORY, None, Some(category)),
+            PromotionTarget::Size(_) => (TARGET_SIZE, None, None),
+        };
+
+        SimplePromotionDiesel {
+            name: promotion.name,
+            kind: match promotion.kind {
+                DiscountKind::Percentage => KIND_PERCENTAGE,
+                DiscountKind::Fixed => KIND_FIXED,
+            }
+            .to_string(),
+            value: promotion.value,
+            target: target.to_string(),
+            item_id,
+            category,
+            size_id,
+            starts_at: promotion.starts_at,
+            ends_at: promotion.ends_at,
+            priority: promotion.priority,
+            active: promotion.active,
+        }
+    }
+}
+
+impl From<(PromotionDiesel, Option<String>)> for Promotion {
+    fn from(value: (PromotionDiesel, Option<String>)) -> Self {
+        let (p, size_name) = value;
+
+        let target = match p.target.as_str() {
+            TARGET_ITEM => PromotionTarget::Item(p.item_id.unwrap_or_default()),
+            TARGET_CATEGORY => PromotionTarget::Category(p.category.unwrap_or_default()),
+            _ => PromotionTarget::Size(size_name.unwrap_or_default()),
+        };
+
+        Promotion {
+            id: p.id,
+            name: p.name,
+            kind: match p.kind.as_str() {
+                KIND_FIXED => DiscountKind::Fixed,
+                _ => DiscountKind::Percentage,
+            },
+            value: p.value,
+            target,
+            starts_at: p.starts_at,
+            ends_at: p.ends_at,
+            priority: p.priority,
+            active: p.active,
+        }
+    }
+}
+
+impl From<EffectivePriceDiesel> for EffectivePrice {
+    fn from(value: EffectivePriceDiesel) -> Self {
+        EffectivePrice {
+            item_id: value.item_id,
+            price: value.price,
+            sale_price: value.sale_price,
+            promotion_id: value.promotion_id,
+            sizes: vec![],
+        }
+    }
+}
+
+impl From<SizeEffectivePriceDiesel> for SizeEffectivePrice {
+    fn from(value: SizeEffectivePriceDiesel) -> Self {
+        SizeEffectivePrice {
+            size: value.size,
+            sale_price: value.sale_price,
+            promotion_id: value.promotion_id,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::domain::models::service_context::{MaintenanceScope, ServiceContext};
+use crate::infrastructure::schema::service_contexts;
+use chrono::{DateTime, Utc};
+use diesel;
+use diesel::prelude::*;
+
+pub const SCOPE_WRITES: &str = "writes";
+pub const SCOPE_ALL: &str = "all";
+
+#[derive(Queryable, Insertable, AsChangeset)]
+#[diesel(table_name = service_contexts)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct ServiceContextDiesel {
+    pub id: i32,
+    pub maintenance: bool,
+    pub maintenance_scope: String,
+    pub retry_after_secs: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub fn scope_to_str(scope: MaintenanceScope) -> &'static str {
+    match scope {
+        MaintenanceScope::Writes => SCOPE_WRITES,
+        MaintenanceScope::All => SCOPE_ALL,
+    }
+}
+
+impl From<ServiceContextDiesel> for ServiceContext {
+    fn from(value: ServiceContextDiesel) -> Self {
+        ServiceContext {
+            id: value.id,
+            maintenance: value.maintenance,
+            maintenance_scope: match value.maintenance_scope.as_str() {
+                SCOPE_ALL => MaintenanceScope::All,
+                _ => MaintenanceScope::Writes,
+            },
+            retry_after_secs: value.retry_after_secs,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<ServiceContext> for ServiceContextDiesel {
+    fn from(value: ServiceContext) -> Self {
+        ServiceContextDiesel {
+            id: value.id,
+            maintenance: value.maintenance,
+            maintenance_scope: scope_to_str(value.maintenance_scope).to_string(),
+            retry_after_secs: value.retry_after_secs,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::domain::models::taxes::{TaxClass, TaxRate};
+use crate::infrastructure::schema::{tax_classes, tax_rates};
+use diesel;
+use diesel::prelude::*;
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = tax_classes)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct TaxClassDiesel {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = tax_classes)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimpleTaxClassDiesel {
+    pub name: String,
+}
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = tax_rates)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct TaxRateDiesel {
+    pub tax_class_id: i32,
+    pub region: String,
+    pub rate: f64,
+}
+
+impl From<TaxRateDiesel> for TaxRate {
+    fn from(value: TaxRateDiesel) -> Self {
+        TaxRate {
+            tax_class_id: value.tax_class_id,
+            region: value.region,
+            rate: value.rate,
+        }
+    }
+}
+
+impl From<TaxRate> for TaxRateDiesel {
+    fn from(value: TaxRate) -> Self {
+        TaxRateDiesel {
+            tax_class_id: value.tax_class_id,
+            region: value.region,
+            rate: value.rate,
+        }
+    }
+}
+
+impl From<(TaxClassDiesel, Vec<TaxRateDiesel>)> for TaxClass {
+    fn from(value: (TaxClassDiesel, Vec<TaxRateDiesel>)) -> Self {
+        TaxClass {
+            id: value.0.id,
+            name: value.0.name,
+            rates: value.1.into_iter().map(TaxRate::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::domain::models::warehouses::{MovementMeta, MovementReason, StockMovement, Warehouse};
+use crate::infrastructure::schema::{stock_movements, stock_thresholds, warehouse_stock, warehouses};
+use chrono::{DateTime, Utc};
+use diesel;
+use diesel::prelude::*;
+
+pub const REASON_RECEIPT: &str = "receipt";
+pub const REASON_SALE: &str = "sale";
+pub const REASON_RESERVATION: &str = "reservation";
+pub const REASON_ADJUSTMENT: &str = "adjustment";
+pub const REASON_RETURN: &str = "return";
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = warehouses)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct WarehouseDiesel {
+    pub id: i32,
+    pub name: String,
+    pub is_default: bool,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = warehouses)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimpleWarehouseDiesel {
+    pub name: String,
+}
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = warehouse_stock)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct WarehouseStockDiesel {
+    pub warehouse_id: i32,
+    pub item_id: i64,
+    pub size_id: i32,
+    pub quantity: i32,
+}
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = stock_thresholds)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct StockThresholdDiesel {
+    pub item_id: i64,
+    pub size_id: i32,
+    pub threshold: i32,
+}
+
+impl From<WarehouseDiesel> for Warehouse {
+    fn from(value: WarehouseDiesel) -> Self {
+        Warehouse {
+            id: value.id,
+            name: value.name,
+            is_default: value.is_default,
+        }
+    }
+}
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = stock_movements)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct StockMovementDiesel {
+    pub id: i64,
+    pub warehouse_id: i32,
+    pub item_id: i64,
+    pub size_id: i32,
+    pub delta: i32,
+    pub quantity_after: i32,
+    pub reason: String,
+    pub actor: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = stock_movements)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimpleStockMovementDiesel {
+    pub warehouse_id: i32,
+    pub item_id: i64,
+    pub size_id: i32,
+    pub delta: i32,
+    pub quantity_after: i32,
+    pub reason: String,
+    pub actor: String,
+    pub note: Option<String>,
+}
+
+pub fn reason_to_str(reason: MovementReason) -> &'static str {
+    match reason {
+        MovementReason::Receipt => REASON_RECEIPT,
+        MovementReason::Sale => REASON_SALE,
+        MovementReason::Reservation => REASON_RESERVATION,
+        MovementReason::Adjustment => REASON_ADJUSTMENT,
+        MovementReason::Return => REASON_RETURN,
+    }
+}
+
+impl SimpleStockMovementDiesel {
+    pub fn new(stock: &WarehouseStockDiesel, delta: i32, meta: &MovementMeta) -> Self {
+        SimpleStockMovementDiesel {
+            warehouse_id: stock.warehouse_id,
+            item_id: stock.item_id,
+            size_id: stock.size_id,
+            delta,
+            quantity_after: stock.quantity,
+            reason: reason_to_str(meta.reason).to_string(),
+            actor: meta.actor.clone(),
+            note: meta.note.clone(),
+        }
+    }
+}
+
+impl From<(StockMovementDiesel, String)> for StockMovement {
+    fn from(value: (StockMovementDiesel, String)) -> Self {
+        let (m, size) = value;
+
+        StockMovement {
+            id: m.id,
+            warehouse_id: m.warehouse_id,
+            item_id: m.item_id,
+            size,
+            delta: m.delta,
+            quantity_after: m.quantity_after,
+            reason: match m.reason.as_str() {
+                REASON_RECEIPT => MovementReason::Receipt,
+                REASON_SALE => MovementReason::Sale,
+                REASON_RESERVATION => MovementReason::Reservation,
+                REASON_RETURN => MovementReason::Return,
+                _ => MovementReason::Adjustment,
+            },
+            actor: m.actor,
+            note: m.note,
+            created_at: m.created_at,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::domain::models::webhooks::{DeliveryStatus, WebhookDelivery, WebhookSubscription};
+use crate::infrastructure::schema::{webhook_deliveries, webhook_subscriptions};
+use chrono::{DateTime, Utc};
+use diesel;
+use diesel::prelude::*;
+
+pub const STATUS_PENDING: &str = "pending";
+pub const STATUS_DELIVERED: &str = "delivered";
+pub const STATUS_DEAD: &str = "dead";
+
+#[derive(Queryable)]
+#[diesel(table_name = webhook_subscriptions)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct WebhookSubscriptionDiesel {
+    pub id: i64,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, AsChangeset)]
+#[diesel(table_name = webhook_subscriptions)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimpleWebhookSubscriptionDiesel {
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+}
+
+#[derive(Queryable)]
+#[diesel(table_name = webhook_deliveries)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct WebhookDeliveryDiesel {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub event_id: i64,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = webhook_deliveries)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimpleWebhookDeliveryDiesel {
+    pub subscription_id: i64,
+    pub event_id: i64,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+}
+
+pub fn status_to_str(status: DeliveryStatus) -> &'static str {
+    match status {
+        DeliveryStatus::Pending => STATUS_PENDING,
+        DeliveryStatus::Delivered => STATUS_DELIVERED,
+        DeliveryStatus::Dead => STATUS_DEAD,
+    }
+}
+
+impl From<&WebhookSubscription> for SimpleWebhookSubscriptionDiesel {
+    fn from(value: &WebhookSubscription) -> Self {
+        SimpleWebhookSubscriptionDiesel {
+            url: value.url.clone(),
+            secret: value.secret.clone(),
+            event_types: value.event_types.clone(),
+            active: value.active,
+        }
+    }
+}
+
+impl From<WebhookSubscriptionDiesel> for WebhookSubscription {
+    fn from(value: WebhookSubscriptionDiesel) -> Self {
+        WebhookSubscription {
+            id: value.id,
+            url: value.url,
+            secret: value.secret,
+            event_types: value.event_types,
+            active: value.active,
+            created_at: value.created_at,
+        }
+    }
+}
+
+impl From<WebhookDeliveryDiesel> for WebhookDelivery {
+    fn from(value: WebhookDeliveryDiesel) -> Self {
+        WebhookDelivery {
+            id: value.id,
+            subscription_id: value.subscription_id,
+            event_id: value.event_id,
+            event_type: value.event_type,
+            payload: value.payload,
+            status: match value.status.as_str() {
+                STATUS_DELIVERED => DeliveryStatus::Delivered,
+                STATUS_DEAD => DeliveryStatus::Dead,
+                _ => DeliveryStatus::Pending,
+            },
+            attempts: value.attempts,
+            next_attempt_at: value.next_attempt_at,
+            last_status_code: value.last_status_code,
+            last_error: value.last_error,
+            created_at: value.created_at,
+            delivered_at: value.delivered_at,
+        }
+    }
+}
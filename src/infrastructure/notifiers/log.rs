@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use log::{info, warn};
+
+use crate::domain::models::events::OutboxEvent;
+use crate::domain::models::warehouses::LowStock;
+use crate::domain::notifiers::events::EventPublisher;
+use crate::domain::notifiers::stock::{NotifierResult, StockNotifier};
+
+/// Writes stock events to the application log.
+#[derive(Clone, Default)]
+pub struct LogStockNotifier;
+
+#[async_trait]
+impl StockNotifier for LogStockNotifier {
+    async fn low_stock(&self, event: LowStock) -> NotifierResult<()> {
+        warn!(
+            "low stock: item {} size {} has {} left (threshold {})",
+            event.item_id, event.size, event.quantity, event.threshold
+        );
+
+        Ok(())
+    }
+}
+
+/// Writes item events to the application log.
+#[derive(Clone, Default)]
+pub struct LogEventPublisher;
+
+#[async_trait]
+impl EventPublisher for LogEventPublisher {
+    async fn publish(&self, event: OutboxEvent) -> NotifierResult<()> {
+        info!(
+            "item event {}: {} for item {}",
+            event.id,
+            event.event.event_type(),
+            event.event.item_id()
+        );
+
+        Ok(())
+    }
+}
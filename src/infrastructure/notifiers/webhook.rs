@@ -0,0 +1,279 @@
+use async_trait::async_trait;
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+use serde::Serialize;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use url::{Host, Url};
+
+use crate::domain::error::{NotifierError, NotifierErrorKind};
+use crate::domain::models::warehouses::LowStock;
+use crate::domain::models::webhooks::{is_public_address, WebhookRequest};
+use crate::domain::notifiers::stock::{NotifierResult, StockNotifier};
+use crate::domain::notifiers::webhooks::WebhookSender;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Posts stock events as JSON to a fixed url.
+pub struct WebhookStockNotifier {
+    pub url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookStockNotifier {
+    pub fn new(url: String) -> Self {
+        WebhookStockNotifier {
+            url,
+            client: reqwest::Client::builder()
+                .timeout(WEBHOOK_TIMEOUT)
+                .build()
+                .expect("failed to build webhook client"),
+        }
+    }
+}
+
+/// Resolves partner hosts to their public addresses only, so a name pointing
+/// into the network the service runs in cannot be used to reach it.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_address(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} has no public address", name.as_str()).into());
+            }
+
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Posts signed webhook deliveries to partner urls. Redirects are not
+/// followed, since they could lead anywhere.
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+    /// Refuse IP literal hosts inside the network, which are never resolved.
+    /// Off only in tests, which post to a local server.
+    public_only: bool,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Self {
+        HttpWebhookSender {
+            client: reqwest::Client::builder()
+                .timeout(WEBHOOK_TIMEOUT)
+                .redirect(reqwest::redirect::Policy::none())
+                .dns_resolver(Arc::new(PublicResolver))
+                .build()
+                .expect("failed to build webhook client"),
+            public_only: true,
+        }
+    }
+
+    #[cfg(test)]
+    fn allowing_internal_addresses() -> Self {
+        HttpWebhookSender {
+            public_only: false,
+            ..Self::new()
+        }
+    }
+}
+
+/// IP address a url names directly; `None` for domain names.
+fn literal_address(url: &Url) -> Option<IpAddr> {
+    match url.host()? {
+        Host::Ipv4(ip) => Some(IpAddr::V4(ip)),
+        Host::Ipv6(ip) => Some(IpAddr::V6(ip)),
+        Host::Domain(_) => None,
+    }
+}
+
+impl Default for HttpWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize)]
+struct LowStockPayload<'a> {
+    event: &'a str,
+    item_id: i64,
+    size: &'a str,
+    quantity: i32,
+    threshold: i32,
+}
+
+impl From<reqwest::Error> for NotifierError {
+    fn from(error: reqwest::Error) -> NotifierError {
+        NotifierError {
+            message: error.to_string(),
+            code: NotifierErrorKind::Unknown,
+        }
+    }
+}
+
+#[async_trait]
+impl StockNotifier for WebhookStockNotifier {
+    async fn low_stock(&self, event: LowStock) -> NotifierResult<()> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&LowStockPayload {
+                event: "low_stock",
+                item_id: event.item_id,
+                size: &event.size,
+                quantity: event.quantity,
+                threshold: event.threshold,
+            })
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(NotifierError {
+                message: format!("webhook {} answered {}", self.url, response.status()),
+                code: NotifierErrorKind::Rejected,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, request: WebhookRequest) -> NotifierResult<u16> {
+        let url = Url::parse(&request.url).map_err(|e| NotifierError {
+            message: format!("invalid webhook url {}: {}", request.url, e),
+            code: NotifierErrorKind::Rejected,
+        })?;
+        if let Some(ip) = literal_address(&url) {
+            if self.public_only && !is_public_address(ip) {
+                return Err(NotifierError {
+                    message: format!("{} is not a public address", ip),
+                    code: NotifierErrorKind::Rejected,
+                });
+            }
+        }
+
+        let mut builder = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.body(request.body).send().await?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+    use std::sync::{Arc, Mutex};
+
+    type Received = Mutex<Vec<(Option<String>, String)>>;
+
+    #[actix_web::test]
+    async fn test_http_webhook_sender_posts_headers_and_body() {
+        let received: Arc<Received> = Arc::new(Mutex::new(vec![]));
+        let log = web::Data::from(received.clone());
+
+        let server = HttpServer::new(move || {
+            App::new().app_data(log.clone()).route(
+                "/hooks",
+                web::post().to(
+                    |req: HttpRequest, body: String, log: web::Data<Received>| async move {
+                        let event = req
+                            .headers()
+                            .get("X-Gomarket-Event")
+                            .and_then(|v| v.to_str().ok())
+                            .map(|v| v.to_string());
+                        log.lock().unwrap().push((event, body));
+
+                        HttpResponse::Accepted().finish()
+                    },
+                ),
+            )
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let addr = server.addrs()[0];
+        let handle = server.run();
+        let stop = handle.handle();
+        actix_web::rt::spawn(handle);
+
+        let sender = HttpWebhookSender::allowing_internal_addresses();
+        let code = sender
+            .send(WebhookRequest {
+                url: format!("http://{}/hooks", addr),
+                headers: vec![("X-Gomarket-Event".to_string(), "item_created".to_string())],
+                body: r#"{"id":1}"#.to_string(),
+            })
+            .await
+            .unwrap();
+        let missing = sender
+            .send(WebhookRequest {
+                url: format!("http://{}/missing", addr),
+                headers: vec![],
+                body: "{}".to_string(),
+            })
+            .await
+            .unwrap();
+
+        // Hosts resolving inside the network are refused.
+        let internal = sender
+            .send(WebhookRequest {
+                url: format!("http://localhost:{}/hooks", addr.port()),
+                headers: vec![],
+                body: "{}".to_string(),
+            })
+            .await;
+
+        stop.stop(true).await;
+
+        assert!(internal.is_err());
+        assert_eq!(code, 202);
+        assert_eq!(missing, 404);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![(Some("item_created".to_string()), r#"{"id":1}"#.to_string())]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_http_webhook_sender_refuses_internal_ip_literals() {
+        let sender = HttpWebhookSender::new();
+
+        // A request that got through would fail to connect instead, with
+        // another kind of error.
+        for url in [
+            "http://127.0.0.1:9/hooks",
+            "http://10.0.0.1:9/hooks",
+            "http://10.255.255.254:9/hooks",
+            "http://[::1]:9/hooks",
+            "http://[::ffff:127.0.0.1]:9/hooks",
+        ] {
+            let err = sender
+                .send(WebhookRequest {
+                    url: url.to_string(),
+                    headers: vec![],
+                    body: "{}".to_string(),
+                })
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err.code, NotifierErrorKind::Rejected), "{}", url);
+        }
+    }
+}
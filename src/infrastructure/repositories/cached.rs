@@ -0,0 +1,855 @@
+use crate::domain::caches::items::{CacheStats, ItemCache};
+use crate::domain::models::backorders::BackorderRule;
+use crate::domain::models::bundles::{Bundle, BundleComponent};
+use crate::domain::models::carts::ItemAvailability;
+use crate::domain::models::currencies::{ExchangeRate, PriceListEntry};
+use crate::domain::models::events::OutboxEvent;
+use crate::domain::models::items::{GetItemsQuery, GetItemsSortBy, InventoryStats, Item};
+use crate::domain::models::media::{ItemMedia, NewItemMedia};
+use crate::domain::models::prices::{PriceRecord, ScheduledPrice};
+use crate::domain::models::promotions::{EffectivePrice, Promotion};
+use crate::domain::models::taxes::{ItemTaxRate, TaxClass, TaxRate};
+use crate::domain::models::warehouses::{
+    LowStock, MovementMeta, StockLevel, StockMovement, StockOperation, StockThreshold, Warehouse,
+};
+use crate::domain::models::webhooks::{DeliveryAttempt, DueDelivery, WebhookDelivery, WebhookSubscription};
+use crate::domain::repositories::items::Repository;
+use crate::domain::repositories::repository::RepositoryResult;
+use crate::infrastructure::models::items::{ItemDiesel, ItemsSizesDiesel, SizeDiesel};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::info;
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+type ItemRows = Vec<(ItemDiesel, Vec<SizeDiesel>, Vec<ItemsSizesDiesel>)>;
+
+struct Entry {
+    value: Arc<dyn Any + Send + Sync>,
+    /// Items whose change makes the entry stale.
+    item_ids: Vec<i64>,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<String, Entry>,
+    /// Keys by last use, oldest first.
+    lru: BTreeMap<u64, String>,
+    clock: u64,
+    /// Bumped on every invalidation, so a read that raced with one is not
+    /// stored.
+    generation: u64,
+}
+
+impl CacheState {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.lru.remove(&entry.last_used);
+        }
+    }
+}
+
+/// Read-through cache in front of another repository for the per-item reads
+/// of item pages: lookups by id and the media, backorder rules and bundles of
+/// the found items. Entries live at most `ttl` and the least
+/// recently used ones are evicted beyond `capacity`. Writes made through the
+/// cache invalidate the items they touch; writes of other replicas arrive
+/// through [`ItemCache`].
+pub struct CachedRepository {
+    inner: Arc<dyn Repository>,
+    capacity: usize,
+    ttl: Duration,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl CachedRepository {
+    pub fn new(inner: Arc<dyn Repository>, capacity: usize, ttl: Duration) -> Self {
+        CachedRepository {
+            inner,
+            capacity,
+            ttl,
+            state: Mutex::new(CacheState::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    fn lookup<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+
+        match state.entries.get(key) {
+            Some(entry) if entry.expires_at > now => {}
+            Some(_) => {
+                state.remove(key);
+                return None;
+            }
+            None => return None,
+        }
+
+        state.clock += 1;
+        let clock = state.clock;
+        let entry = state.entries.get_mut(key)?;
+        let previous = std::mem::replace(&mut entry.last_used, clock);
+        let value = entry.value.downcast_ref::<T>().cloned();
+        state.lru.remove(&previous);
+        state.lru.insert(clock, key.to_string());
+
+        value
+    }
+
+    fn store<T: Send + Sync + 'static>(&self, key: String, value: T, item_ids: Vec<i64>, generation: u64) {
+        let mut state = self.state.lock().unwrap();
+        if state.generation != generation {
+            return;
+        }
+
+        state.remove(&key);
+        while state.entries.len() >= self.capacity {
+            let Some((_, oldest)) = state.lru.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        state.clock += 1;
+        let clock = state.clock;
+        state.lru.insert(clock, key.clone());
+        state.entries.insert(
+            key,
+            Entry {
+                value: Arc::new(value),
+                item_ids,
+                expires_at: Instant::now() + self.ttl,
+                last_used: clock,
+            },
+        );
+    }
+
+    async fn read_through<T, F>(
+        &self,
+        key: String,
+        item_ids: impl FnOnce(&T) -> Vec<i64>,
+        load: impl FnOnce() -> F,
+    ) -> RepositoryResult<T>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: Future<Output = RepositoryResult<T>>,
+    {
+        if let Some(value) = self.lookup::<T>(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(value);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let generation = self.state.lock().unwrap().generation;
+        let value = load().await?;
+        self.store(key, value.clone(), item_ids(&value), generation);
+
+        Ok(value)
+    }
+
+    fn invalidate_items(&self, item_ids: &[i64]) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+
+        let stale: Vec<String> = state
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.item_ids.iter().any(|id| item_ids.contains(id)))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in stale {
+            state.remove(&key);
+        }
+    }
+}
+
+impl ItemCache for CachedRepository {
+    fn invalidate_item(&self, item_id: i64) {
+        self.invalidate_items(&[item_id]);
+    }
+
+    fn invalidate_all(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        state.entries.clear();
+        state.lru.clear();
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            entries: self.state.lock().unwrap().entries.len(),
+        }
+    }
+}
+
+/// Periodically logs the hit rate of the cache.
+pub fn spawn_item_cache_reporter(
+    cache: Arc<dyn ItemCache>,
+    period: Duration,
+) -> actix_web::rt::task::JoinHandle<()> {
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(period);
+        loop {
+            interval.tick().await;
+
+            let stats = cache.stats();
+            info!(
+                "item cache: {} hits, {} misses, {} evictions, {} entries",
+                stats.hits, stats.misses, stats.evictions, stats.entries
+            );
+        }
+    })
+}
+
+#[async_trait]
+impl Repository for CachedRepository {
+    /// Only lookups by id are cached; filtered listings depend on every item.
+    async fn get_items(
+        &self,
+        query: Option<GetItemsQuery>,
+        sort_by: Option<GetItemsSortBy>,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<ItemRows> {
+        let ids = match &query {
+            Some(q) if q.price.is_none() && q.names.is_none() && q.warehouse_id.is_none() => {
+                q.ids.clone()
+            }
+            _ => None,
+        };
+        let Some(ids) = ids else {
+            return self.inner.get_items(query, sort_by, offset, limit).await;
+        };
+
+        let key = format!("items:{:?}:{:?}:{}:{}", query, sort_by, offset, limit);
+        self.read_through(key, |_| ids, || self.inner.get_items(query, sort_by, offset, limit))
+            .await
+    }
+
+    async fn create_item(&self, item: Item) -> RepositoryResult<i64> {
+        let item_id = self.inner.create_item(item).await?;
+        self.invalidate_item(item_id);
+
+        Ok(item_id)
+    }
+
+    async fn get_items_media(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<ItemMedia>> {
+        let key = format!("media:{:?}", item_ids);
+        let ids = item_ids.clone();
+        self.read_through(key, |_| ids, || self.inner.get_items_media(item_ids))
+            .await
+    }
+
+    async fn create_item_media(&self, media: NewItemMedia) -> RepositoryResult<ItemMedia> {
+        let item_id = media.item_id;
+        let res = self.inner.create_item_media(media).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn get_price_history(&self, item_id: i64) -> RepositoryResult<Vec<PriceRecord>> {
+        self.inner.get_price_history(item_id).await
+    }
+
+    async fn get_scheduled_prices(&self, item_id: i64) -> RepositoryResult<Vec<ScheduledPrice>> {
+        self.inner.get_scheduled_prices(item_id).await
+    }
+
+    async fn change_item_price(
+        &self,
+        item_id: i64,
+        price: f64,
+        valid_from: DateTime<Utc>,
+    ) -> RepositoryResult<PriceRecord> {
+        let res = self.inner.change_item_price(item_id, price, valid_from).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn schedule_item_price(
+        &self,
+        item_id: i64,
+        price: f64,
+        starts_at: DateTime<Utc>,
+    ) -> RepositoryResult<ScheduledPrice> {
+        self.inner.schedule_item_price(item_id, price, starts_at).await
+    }
+
+    async fn cancel_scheduled_price(&self, item_id: i64, schedule_id: i64) -> RepositoryResult<()> {
+        self.inner.cancel_scheduled_price(item_id, schedule_id).await
+    }
+
+    async fn apply_due_prices(&self, now: DateTime<Utc>) -> RepositoryResult<Vec<PriceRecord>> {
+        let applied = self.inner.apply_due_prices(now).await?;
+        self.invalidate_items(&applied.iter().map(|r| r.item_id).collect::<Vec<_>>());
+
+        Ok(applied)
+    }
+
+    async fn get_item_availability(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<ItemAvailability>> {
+        self.inner.get_item_availability(item_ids).await
+    }
+
+    /// Not cached: promotions start and end by the clock, with no write to
+    /// invalidate on.
+    async fn get_effective_prices(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<EffectivePrice>> {
+        self.inner.get_effective_prices(item_ids).await
+    }
+
+    async fn get_promotions(&self) -> RepositoryResult<Vec<Promotion>> {
+        self.inner.get_promotions().await
+    }
+
+    /// Promotions can target whole categories, so every entry goes.
+    async fn create_promotion(&self, promotion: Promotion) -> RepositoryResult<Promotion> {
+        let res = self.inner.create_promotion(promotion).await;
+        self.invalidate_all();
+
+        res
+    }
+
+    async fn delete_promotion(&self, promotion_id: i64) -> RepositoryResult<()> {
+        let res = self.inner.delete_promotion(promotion_id).await;
+        self.invalidate_all();
+
+        res
+    }
+
+    async fn currency_exists(&self, currency: String) -> RepositoryResult<bool> {
+        self.inner.currency_exists(currency).await
+    }
+
+    async fn get_currency_prices(
+        &self,
+        item_ids: Vec<i64>,
+        currency: String,
+        region: Option<String>,
+    ) -> RepositoryResult<Vec<PriceListEntry>> {
+        self.inner.get_currency_prices(item_ids, currency, region).await
+    }
+
+    async fn get_exchange_rates(&self) -> RepositoryResult<Vec<ExchangeRate>> {
+        self.inner.get_exchange_rates().await
+    }
+
+    async fn set_exchange_rate(&self, currency: String, rate: f64) -> RepositoryResult<ExchangeRate> {
+        let res = self.inner.set_exchange_rate(currency, rate).await;
+        self.invalidate_all();
+
+        res
+    }
+
+    async fn get_price_list(
+        &self,
+        currency: String,
+        region: Option<String>,
+    ) -> RepositoryResult<Vec<PriceListEntry>> {
+        self.inner.get_price_list(currency, region).await
+    }
+
+    async fn set_price_list_price(&self, entry: PriceListEntry) -> RepositoryResult<PriceListEntry> {
+        let item_id = entry.item_id;
+        let res = self.inner.set_price_list_price(entry).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn delete_price_list_price(
+        &self,
+        currency: String,
+        region: Option<String>,
+        item_id: i64,
+    ) -> RepositoryResult<()> {
+        let res = self.inner.delete_price_list_price(currency, region, item_id).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn region_exists(&self, region: String) -> RepositoryResult<bool> {
+        self.inner.region_exists(region).await
+    }
+
+    async fn get_item_tax_rates(
+        &self,
+        item_ids: Vec<i64>,
+        region: String,
+    ) -> RepositoryResult<Vec<ItemTaxRate>> {
+        self.inner.get_item_tax_rates(item_ids, region).await
+    }
+
+    async fn get_tax_classes(&self) -> RepositoryResult<Vec<TaxClass>> {
+        self.inner.get_tax_classes().await
+    }
+
+    async fn create_tax_class(&self, name: String) -> RepositoryResult<TaxClass> {
+        self.inner.create_tax_class(name).await
+    }
+
+    async fn set_tax_rate(&self, rate: TaxRate) -> RepositoryResult<TaxRate> {
+        self.inner.set_tax_rate(rate).await
+    }
+
+    async fn delete_tax_rate(&self, tax_class_id: i32, region: String) -> RepositoryResult<()> {
+        self.inner.delete_tax_rate(tax_class_id, region).await
+    }
+
+    async fn set_item_tax_class(&self, item_id: i64, tax_class_id: Option<i32>) -> RepositoryResult<()> {
+        let res = self.inner.set_item_tax_class(item_id, tax_class_id).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn get_warehouses(&self) -> RepositoryResult<Vec<Warehouse>> {
+        self.inner.get_warehouses().await
+    }
+
+    async fn create_warehouse(&self, name: String) -> RepositoryResult<Warehouse> {
+        self.inner.create_warehouse(name).await
+    }
+
+    async fn get_item_stock(&self, item_id: i64) -> RepositoryResult<Vec<StockLevel>> {
+        self.inner.get_item_stock(item_id).await
+    }
+
+    async fn set_stock_levels(
+        &self,
+        warehouse_id: i32,
+        levels: Vec<StockLevel>,
+        meta: MovementMeta,
+    ) -> RepositoryResult<Vec<StockLevel>> {
+        let item_ids: Vec<i64> = levels.iter().map(|l| l.item_id).collect();
+        let res = self.inner.set_stock_levels(warehouse_id, levels, meta).await;
+        self.invalidate_items(&item_ids);
+
+        res
+    }
+
+    /// Components of a bundle size are not known here; their entries are
+    /// dropped by the change listener.
+    async fn adjust_stock(
+        &self,
+        item_id: i64,
+        size: String,
+        warehouse_id: Option<i32>,
+        operation: StockOperation,
+        meta: MovementMeta,
+    ) -> RepositoryResult<StockLevel> {
+        let res = self
+            .inner
+            .adjust_stock(item_id, size, warehouse_id, operation, meta)
+            .await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn set_stock_threshold(&self, threshold: StockThreshold) -> RepositoryResult<StockThreshold> {
+        self.inner.set_stock_threshold(threshold).await
+    }
+
+    async fn delete_stock_threshold(&self, item_id: i64, size: String) -> RepositoryResult<()> {
+        self.inner.delete_stock_threshold(item_id, size).await
+    }
+
+    async fn get_low_stock(&self) -> RepositoryResult<Vec<LowStock>> {
+        self.inner.get_low_stock().await
+    }
+
+    async fn set_backorder_rule(&self, rule: BackorderRule) -> RepositoryResult<BackorderRule> {
+        let item_id = rule.item_id;
+        let res = self.inner.set_backorder_rule(rule).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn delete_backorder_rule(&self, item_id: i64, size: String) -> RepositoryResult<()> {
+        let res = self.inner.delete_backorder_rule(item_id, size).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    async fn get_backorder_rules(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<BackorderRule>> {
+        let key = format!("backorders:{:?}", item_ids);
+        let ids = item_ids.clone();
+        self.read_through(key, |_| ids, || self.inner.get_backorder_rules(item_ids))
+            .await
+    }
+
+    async fn set_bundle(
+        &self,
+        item_id: i64,
+        size: String,
+        components: Vec<BundleComponent>,
+    ) -> RepositoryResult<Bundle> {
+        let res = self.inner.set_bundle(item_id, size, components).await;
+        self.invalidate_item(item_id);
+
+        res
+    }
+
+    /// Bundle stock follows the components, so their changes drop it too.
+    async fn get_bundles(&self, item_ids: Vec<i64>) -> RepositoryResult<Vec<Bundle>> {
+        let key = format!("bundles:{:?}", item_ids);
+        let ids = item_ids.clone();
+        self.read_through(
+            key,
+            |bundles: &Vec<Bundle>| {
+                ids.into_iter()
+                    .chain(bundles.iter().flat_map(|b| b.components.iter().map(|c| c.item_id)))
+                    .collect()
+            },
+            || self.inner.get_bundles(item_ids),
+        )
+        .await
+    }
+
+    async fn claim_pending_events(
+        &self,
+        now: DateTime<Utc>,
+        claimed_until: DateTime<Utc>,
+        limit: i64,
+    ) -> RepositoryResult<Vec<OutboxEvent>> {
+        self.inner.claim_pending_events(now, claimed_until, limit).await
+    }
+
+    async fn mark_event_delivered(&self, event_id: i64) -> RepositoryResult<()> {
+        self.inner.mark_event_delivered(event_id).await
+    }
+
+    async fn mark_event_failed(&self, event_id: i64, error: String) -> RepositoryResult<()> {
+        self.inner.mark_event_failed(event_id, error).await
+    }
+
+    async fn release_events(&self, event_ids: Vec<i64>) -> RepositoryResult<()> {
+        self.inner.release_events(event_ids).await
+    }
+
+    async fn get_events_after(
+        &self,
+        after_id: i64,
+        item_ids: Vec<i64>,
+        limit: i64,
+    ) -> RepositoryResult<Vec<OutboxEvent>> {
+        self.inner.get_events_after(after_id, item_ids, limit).await
+    }
+
+    async fn get_last_event_id(&self) -> RepositoryResult<i64> {
+        self.inner.get_last_event_id().await
+    }
+
+    async fn get_webhook_subscriptions(&self) -> RepositoryResult<Vec<WebhookSubscription>> {
+        self.inner.get_webhook_subscriptions().await
+    }
+
+    async fn get_webhook_subscription(&self, subscription_id: i64) -> RepositoryResult<WebhookSubscription> {
+        self.inner.get_webhook_subscription(subscription_id).await
+    }
+
+    async fn create_webhook_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> RepositoryResult<WebhookSubscription> {
+        self.inner.create_webhook_subscription(subscription).await
+    }
+
+    async fn update_webhook_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> RepositoryResult<WebhookSubscription> {
+        self.inner.update_webhook_subscription(subscription).await
+    }
+
+    async fn delete_webhook_subscription(&self, subscription_id: i64) -> RepositoryResult<()> {
+        self.inner.delete_webhook_subscription(subscription_id).await
+    }
+
+    async fn enqueue_webhook_deliveries(
+        &self,
+        event_id: i64,
+        event_type: String,
+        payload: serde_json::Value,
+    ) -> RepositoryResult<usize> {
+        self.inner
+            .enqueue_webhook_deliveries(event_id, event_type, payload)
+            .await
+    }
+
+    async fn claim_due_webhook_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        leased_until: DateTime<Utc>,
+        limit: i64,
+    ) -> RepositoryResult<Vec<DueDelivery>> {
+        self.inner
+            .claim_due_webhook_deliveries(now, leased_until, limit)
+            .await
+    }
+
+    async fn record_webhook_attempt(&self, delivery_id: i64, attempt: DeliveryAttempt) -> RepositoryResult<()> {
+        self.inner.record_webhook_attempt(delivery_id, attempt).await
+    }
+
+    async fn get_webhook_deliveries(
+        &self,
+        subscription_id: i64,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<Vec<WebhookDelivery>> {
+        self.inner
+            .get_webhook_deliveries(subscription_id, offset, limit)
+            .await
+    }
+
+    async fn retry_webhook_delivery(
+        &self,
+        subscription_id: i64,
+        delivery_id: i64,
+        now: DateTime<Utc>,
+    ) -> RepositoryResult<WebhookDelivery> {
+        self.inner
+            .retry_webhook_delivery(subscription_id, delivery_id, now)
+            .await
+    }
+
+    async fn get_stock_movements(
+        &self,
+        item_id: i64,
+        size: String,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<Vec<StockMovement>> {
+        self.inner
+            .get_stock_movements(item_id, size, offset, limit)
+            .await
+    }
+
+    async fn ping(&self) -> RepositoryResult<()> {
+        self.inner.ping().await
+    }
+
+    async fn get_pending_migrations(&self) -> RepositoryResult<Vec<String>> {
+        self.inner.get_pending_migrations().await
+    }
+
+    async fn get_inventory_stats(&self) -> RepositoryResult<InventoryStats> {
+        self.inner.get_inventory_stats().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::items::{PriceGetItemsQuery, Size};
+    use crate::domain::repositories::items::MockRepository;
+
+    fn media(item_id: i64) -> ItemMedia {
+        ItemMedia {
+            id: item_id,
+            item_id,
+            url: format!("/media/{}.jpg", item_id),
+            path: None,
+            alt_text: "".to_string(),
+            position: 0,
+            width: None,
+            height: None,
+        }
+    }
+
+    fn item_media(mock_repository: &mut MockRepository, times: usize) {
+        mock_repository
+            .expect_get_items_media()
+            .times(times)
+            .returning(|ids| Box::pin(async move { Ok(ids.into_iter().map(media).collect()) }));
+    }
+
+    #[tokio::test]
+    async fn should_serve_repeated_reads_from_cache() {
+        let mut mock_repository = MockRepository::new();
+        item_media(&mut mock_repository, 1);
+
+        let cache = CachedRepository::new(Arc::new(mock_repository), 10, Duration::from_secs(60));
+
+        assert_eq!(cache.get_items_media(vec![1]).await.unwrap(), vec![media(1)]);
+        assert_eq!(cache.get_items_media(vec![1]).await.unwrap(), vec![media(1)]);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                evictions: 0,
+                entries: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_invalidate_on_writes_through_it() {
+        let mut mock_repository = MockRepository::new();
+        item_media(&mut mock_repository, 3);
+        mock_repository
+            .expect_change_item_price()
+            .times(1)
+            .returning(|item_id, price, valid_from| {
+                Box::pin(async move {
+                    Ok(PriceRecord {
+                        id: 1,
+                        item_id,
+                        price,
+                        valid_from,
+                        valid_to: None,
+                    })
+                })
+            });
+
+        let cache = CachedRepository::new(Arc::new(mock_repository), 10, Duration::from_secs(60));
+
+        cache.get_items_media(vec![1]).await.unwrap();
+        cache.get_items_media(vec![2]).await.unwrap();
+        cache.change_item_price(1, 5.0, Utc::now()).await.unwrap();
+        cache.get_items_media(vec![1]).await.unwrap();
+        cache.get_items_media(vec![2]).await.unwrap();
+
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().misses, 3);
+    }
+
+    #[tokio::test]
+    async fn should_evict_least_recently_used_and_expired_entries() {
+        let mut mock_repository = MockRepository::new();
+        item_media(&mut mock_repository, 4);
+
+        let cache = CachedRepository::new(Arc::new(mock_repository), 2, Duration::from_secs(60));
+
+        cache.get_items_media(vec![1]).await.unwrap();
+        cache.get_items_media(vec![2]).await.unwrap();
+        cache.get_items_media(vec![1]).await.unwrap();
+        // Evicts 2, the least recently used.
+        cache.get_items_media(vec![3]).await.unwrap();
+        cache.get_items_media(vec![1]).await.unwrap();
+        cache.get_items_media(vec![2]).await.unwrap();
+
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 4,
+                evictions: 2,
+                entries: 2,
+            }
+        );
+
+        let mut mock_repository = MockRepository::new();
+        item_media(&mut mock_repository, 2);
+        let cache = CachedRepository::new(Arc::new(mock_repository), 2, Duration::ZERO);
+
+        cache.get_items_media(vec![1]).await.unwrap();
+        cache.get_items_media(vec![1]).await.unwrap();
+
+        assert_eq!(cache.stats().hits, 0);
+    }
+
+    #[tokio::test]
+    async fn should_not_cache_effective_prices() {
+        let mut mock_repository = MockRepository::new();
+        mock_repository
+            .expect_get_effective_prices()
+            .times(2)
+            .returning(|_| Box::pin(async move { Ok(vec![]) }));
+
+        let cache = CachedRepository::new(Arc::new(mock_repository), 10, Duration::from_secs(60));
+
+        cache.get_effective_prices(vec![1]).await.unwrap();
+        cache.get_effective_prices(vec![1]).await.unwrap();
+
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn should_drop_bundles_when_a_component_changes() {
+        let mut mock_repository = MockRepository::new();
+        mock_repository
+            .expect_get_bundles()
+            .times(2)
+            .returning(|_| {
+                Box::pin(async move {
+                    Ok(vec![Bundle {
+                        item_id: 1,
+                        size: Size {
+                            id: 1,
+                            name: "S".to_string(),
+                        },
+                        components: vec![BundleComponent {
+                            item_id: 3,
+                            size: "L".to_string(),
+                            quantity: 2,
+                            available: 10,
+                        }],
+                    }])
+                })
+            });
+
+        let cache = CachedRepository::new(Arc::new(mock_repository), 10, Duration::from_secs(60));
+
+        cache.get_bundles(vec![1]).await.unwrap();
+        cache.invalidate_item(2);
+        cache.get_bundles(vec![1]).await.unwrap();
+        cache.invalidate_item(3);
+        cache.get_bundles(vec![1]).await.unwrap();
+
+        assert_eq!(cache.stats().hits, 1);
+    }
+
+    #[tokio::test]
+    async fn should_only_cache_item_lookups_by_id() {
+        let mut mock_repository = MockRepository::new();
+        mock_repository
+            .expect_get_items()
+            .times(3)
+            .returning(|_, _, _, _| Box::pin(async move { Ok(vec![]) }));
+
+        let cache = CachedRepository::new(Arc::new(mock_repository), 10, Duration::from_secs(60));
+        let by_id = GetItemsQuery {
+            ids: Some(vec![1]),
+            ..Default::default()
+        };
+        let filtered = GetItemsQuery {
+            price: Some(PriceGetItemsQuery {
+                from: Some(1.0),
+                to: None,
+                effective: false,
+            }),
+            ..by_id.clone()
+        };
+
+        cache.get_items(Some(by_id.clone()), None, 0, 25).await.unwrap();
+        cache.get_items(Some(by_id), None, 0, 25).await.unwrap();
+        cache.get_items(Some(filtered.clone()), None, 0, 25).await.unwrap();
+        cache.get_items(Some(filtered), None, 0, 25).await.unwrap();
+
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().entries, 1);
+    }
+}
@@ -1 +1,2 @@
+pub mod cached;
 pub mod items;
@@ -1,11 +1,88 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    backorder_rules (item_id, size_id) {
+        item_id -> Int8,
+        size_id -> Int4,
+        policy -> Varchar,
+        backorder_limit -> Nullable<Int4>,
+        expected_date -> Nullable<Date>,
+        backordered -> Int4,
+    }
+}
+
+diesel::table! {
+    bundle_components (bundle_id, bundle_size_id, item_id, size_id) {
+        bundle_id -> Int8,
+        bundle_size_id -> Int4,
+        item_id -> Int8,
+        size_id -> Int4,
+        quantity -> Int4,
+    }
+}
+
+diesel::table! {
+    exchange_rates (currency) {
+        #[max_length = 3]
+        currency -> Varchar,
+        rate -> Float8,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    item_currency_prices (item_id, currency, region) {
+        item_id -> Int8,
+        #[max_length = 3]
+        currency -> Varchar,
+        price -> Float8,
+        #[max_length = 8]
+        region -> Varchar,
+    }
+}
+
+diesel::table! {
+    item_price_history (id) {
+        id -> Int8,
+        item_id -> Int8,
+        price -> Float8,
+        valid_from -> Timestamptz,
+        valid_to -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    item_price_schedules (id) {
+        id -> Int8,
+        item_id -> Int8,
+        price -> Float8,
+        starts_at -> Timestamptz,
+        applied_at -> Nullable<Timestamptz>,
+        superseded_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     items (id) {
         id -> Int8,
         name -> Varchar,
         description -> Varchar,
         price -> Float8,
+        category -> Nullable<Varchar>,
+        tax_class_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    items_media (id) {
+        id -> Int8,
+        item_id -> Int8,
+        url -> Varchar,
+        path -> Nullable<Varchar>,
+        alt_text -> Varchar,
+        position -> Int4,
+        width -> Nullable<Int4>,
+        height -> Nullable<Int4>,
     }
 }
 
@@ -18,6 +95,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    outbox_events (id) {
+        id -> Int8,
+        event_type -> Varchar,
+        item_id -> Int8,
+        payload -> Jsonb,
+        created_at -> Timestamptz,
+        delivered_at -> Nullable<Timestamptz>,
+        attempts -> Int4,
+        last_error -> Nullable<Varchar>,
+        claimed_until -> Nullable<Timestamptz>,
+        txid -> Int8,
+    }
+}
+
+diesel::table! {
+    promotions (id) {
+        id -> Int8,
+        name -> Varchar,
+        kind -> Varchar,
+        value -> Float8,
+        target -> Varchar,
+        item_id -> Nullable<Int8>,
+        category -> Nullable<Varchar>,
+        size_id -> Nullable<Int4>,
+        starts_at -> Nullable<Timestamptz>,
+        ends_at -> Nullable<Timestamptz>,
+        priority -> Int4,
+        active -> Bool,
+    }
+}
+
+diesel::table! {
+    service_contexts (id) {
+        id -> Int4,
+        maintenance -> Bool,
+        maintenance_scope -> Varchar,
+        retry_after_secs -> Int4,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     sizes (id) {
         id -> Int4,
@@ -25,11 +144,132 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    stock_movements (id) {
+        id -> Int8,
+        warehouse_id -> Int4,
+        item_id -> Int8,
+        size_id -> Int4,
+        delta -> Int4,
+        quantity_after -> Int4,
+        reason -> Varchar,
+        actor -> Varchar,
+        note -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    stock_thresholds (item_id, size_id) {
+        item_id -> Int8,
+        size_id -> Int4,
+        threshold -> Int4,
+    }
+}
+
+diesel::table! {
+    tax_classes (id) {
+        id -> Int4,
+        name -> Varchar,
+    }
+}
+
+diesel::table! {
+    tax_rates (tax_class_id, region) {
+        tax_class_id -> Int4,
+        #[max_length = 8]
+        region -> Varchar,
+        rate -> Float8,
+    }
+}
+
+diesel::table! {
+    warehouse_stock (warehouse_id, item_id, size_id) {
+        warehouse_id -> Int4,
+        item_id -> Int8,
+        size_id -> Int4,
+        quantity -> Int4,
+    }
+}
+
+diesel::table! {
+    warehouses (id) {
+        id -> Int4,
+        name -> Varchar,
+        is_default -> Bool,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Int8,
+        subscription_id -> Int8,
+        event_id -> Int8,
+        event_type -> Varchar,
+        payload -> Jsonb,
+        status -> Varchar,
+        attempts -> Int4,
+        next_attempt_at -> Timestamptz,
+        last_status_code -> Nullable<Int4>,
+        last_error -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        delivered_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    webhook_subscriptions (id) {
+        id -> Int8,
+        url -> Varchar,
+        secret -> Varchar,
+        event_types -> Array<Varchar>,
+        active -> Bool,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::joinable!(backorder_rules -> items (item_id));
+diesel::joinable!(backorder_rules -> sizes (size_id));
+diesel::joinable!(item_currency_prices -> items (item_id));
+diesel::joinable!(item_price_history -> items (item_id));
+diesel::joinable!(item_price_schedules -> items (item_id));
+diesel::joinable!(items -> tax_classes (tax_class_id));
+diesel::joinable!(items_media -> items (item_id));
 diesel::joinable!(items_sizes -> items (item_id));
 diesel::joinable!(items_sizes -> sizes (size_id));
+diesel::joinable!(promotions -> items (item_id));
+diesel::joinable!(promotions -> sizes (size_id));
+diesel::joinable!(stock_movements -> items (item_id));
+diesel::joinable!(stock_movements -> sizes (size_id));
+diesel::joinable!(stock_movements -> warehouses (warehouse_id));
+diesel::joinable!(stock_thresholds -> items (item_id));
+diesel::joinable!(stock_thresholds -> sizes (size_id));
+diesel::joinable!(tax_rates -> tax_classes (tax_class_id));
+diesel::joinable!(warehouse_stock -> items (item_id));
+diesel::joinable!(warehouse_stock -> sizes (size_id));
+diesel::joinable!(warehouse_stock -> warehouses (warehouse_id));
+diesel::joinable!(webhook_deliveries -> webhook_subscriptions (subscription_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    backorder_rules,
+    bundle_components,
+    exchange_rates,
+    item_currency_prices,
+    item_price_history,
+    item_price_schedules,
     items,
+    items_media,
     items_sizes,
+    outbox_events,
+    promotions,
+    service_contexts,
     sizes,
+    stock_movements,
+    stock_thresholds,
+    tax_classes,
+    tax_rates,
+    warehouse_stock,
+    warehouses,
+    webhook_deliveries,
+    webhook_subscriptions,
 );
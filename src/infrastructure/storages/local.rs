@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+
+use crate::domain::error::{StorageError, StorageErrorKind};
+use crate::domain::storages::media::{MediaStorage, StorageResult};
+
+pub struct LocalMediaStorage {
+    pub root: PathBuf,
+    pub base_url: String,
+}
+
+impl LocalMediaStorage {
+    pub fn new(root: impl Into<PathBuf>, base_url: String) -> Self {
+        LocalMediaStorage {
+            root: root.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn resolve(&self, key: &str) -> StorageResult<PathBuf> {
+        let relative = Path::new(key);
+        let is_safe = relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)));
+
+        if key.is_empty() || !is_safe {
+            return Err(StorageError {
+                message: format!("invalid storage key: {}", key),
+                code: StorageErrorKind::InvalidKey,
+            });
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl MediaStorage for LocalMediaStorage {
+    async fn save(&self, key: String, content: Vec<u8>) -> StorageResult<String> {
+        let path = self.resolve(&key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, content).await?;
+
+        Ok(format!("{}/{}", self.base_url, key))
+    }
+
+    async fn delete(&self, key: String) -> StorageResult<()> {
+        let path = self.resolve(&key)?;
+        tokio::fs::remove_file(path).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("gomarket-items-{}-{}", name, std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn test_save_and_delete() {
+        let root = temp_root("save");
+        let storage = LocalMediaStorage::new(root.clone(), "/media/".to_string());
+
+        let url = storage
+            .save("items/1/a.png".to_string(), vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        assert_eq!(url, "/media/items/1/a.png");
+        assert_eq!(std::fs::read(root.join("items/1/a.png")).unwrap(), vec![1, 2, 3]);
+
+        storage.delete("items/1/a.png".to_string()).await.unwrap();
+        assert!(!root.join("items/1/a.png").exists());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_keys_outside_root() {
+        let storage = LocalMediaStorage::new(temp_root("traversal"), "/media".to_string());
+
+        for key in ["../x.png", "/etc/x.png", "items/../../x.png", ""] {
+            let err = storage.save(key.to_string(), vec![]).await.err().unwrap();
+            assert!(matches!(err.code, StorageErrorKind::InvalidKey));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// Views are not picked up by `diesel print-schema`, so they are declared by
+// hand here instead of in `schema.rs`.
+
+use crate::infrastructure::schema::items;
+
+diesel::table! {
+    item_effective_prices (item_id) {
+        item_id -> Int8,
+        price -> Float8,
+        sale_price -> Float8,
+        promotion_id -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    item_size_effective_prices (item_id, size_id) {
+        item_id -> Int8,
+        size_id -> Int4,
+        size -> Varchar,
+        price -> Float8,
+        sale_price -> Float8,
+        promotion_id -> Nullable<Int8>,
+    }
+}
+
+diesel::allow_tables_to_appear_in_same_query!(items, item_effective_prices);
@@ -1,7 +1,10 @@
 extern crate core;
 
 pub mod api;
+pub mod config;
 pub mod container;
 pub mod domain;
 pub mod infrastructure;
+pub mod logging;
 pub mod services;
+pub mod telemetry;
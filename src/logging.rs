@@ -0,0 +1,176 @@
+use crate::telemetry::current_trace_id;
+use chrono::{SecondsFormat, Utc};
+use log::kv::{Key, Value, VisitSource};
+use log::Record;
+use serde::Deserialize;
+use serde_json::{json, Map};
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+
+tokio::task_local! {
+    /// Id of the request the current task serves, set by the request id
+    /// middleware.
+    pub static REQUEST_ID: String;
+}
+
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Runs blocking work under the request id of the task that hands it off, so
+/// its log lines can still be correlated.
+pub fn with_request_id<R>(request_id: Option<String>, f: impl FnOnce() -> R) -> R {
+    match request_id {
+        Some(request_id) => REQUEST_ID.sync_scope(request_id, f),
+        None => f(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Text,
+    /// One JSON object per line, for log shippers.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("unknown log format {:?}", s)),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        })
+    }
+}
+
+pub fn init(filters: &str, format: LogFormat) {
+    env_logger::Builder::new()
+        .parse_filters(filters)
+        .format(move |buf, record| writeln!(buf, "{}", format_record(record, format)))
+        .init();
+}
+
+struct Fields(Map<String, serde_json::Value>);
+
+impl<'kvs> VisitSource<'kvs> for Fields {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), log::kv::Error> {
+        let value = if let Some(v) = value.to_u64() {
+            json!(v)
+        } else if let Some(v) = value.to_i64() {
+            json!(v)
+        } else if let Some(v) = value.to_f64() {
+            json!(v)
+        } else if let Some(v) = value.to_bool() {
+            json!(v)
+        } else {
+            json!(value.to_string())
+        };
+        self.0.insert(key.to_string(), value);
+
+        Ok(())
+    }
+}
+
+/// Renders a record with the current request id and its key-values, either
+/// as `[time LEVEL target] request_id message key=value` or as JSON, which
+/// also carries the trace id while a span is recorded.
+pub fn format_record(record: &Record, format: LogFormat) -> String {
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let request_id = current_request_id();
+    let mut fields = Fields(Map::new());
+    let _ = record.key_values().visit(&mut fields);
+
+    match format {
+        LogFormat::Text => {
+            let mut line = format!("[{} {} {}]", timestamp, record.level(), record.target());
+            if let Some(request_id) = request_id {
+                line.push(' ');
+                line.push_str(&request_id);
+            }
+            line.push(' ');
+            line.push_str(&record.args().to_string());
+            for (key, value) in fields.0 {
+                match value {
+                    serde_json::Value::String(value) => line.push_str(&format!(" {}={:?}", key, value)),
+                    value => line.push_str(&format!(" {}={}", key, value)),
+                }
+            }
+            line
+        }
+        LogFormat::Json => {
+            let mut entry = Map::new();
+            entry.insert("timestamp".to_string(), json!(timestamp));
+            entry.insert("level".to_string(), json!(record.level().as_str()));
+            entry.insert("target".to_string(), json!(record.target()));
+            entry.insert("message".to_string(), json!(record.args().to_string()));
+            if let Some(request_id) = request_id {
+                entry.insert("request_id".to_string(), json!(request_id));
+            }
+            if let Some(trace_id) = current_trace_id() {
+                entry.insert("trace_id".to_string(), json!(trace_id));
+            }
+            for (key, value) in fields.0 {
+                entry.entry(key).or_insert(value);
+            }
+            serde_json::Value::Object(entry).to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Level;
+
+    fn render(format: LogFormat) -> String {
+        let kvs: &[(&str, Value)] = &[
+            ("route", Value::from("/v1/items/{id}")),
+            ("status", Value::from(404u16)),
+            ("latency_ms", Value::from(1.5f64)),
+        ];
+        let record = Record::builder()
+            .level(Level::Info)
+            .target("access")
+            .args(format_args!("GET /v1/items/7"))
+            .key_values(&kvs)
+            .build();
+
+        REQUEST_ID.sync_scope("req-1".to_string(), || format_record(&record, format))
+    }
+
+    #[test]
+    fn test_format_record_as_json() {
+        let entry: serde_json::Value = serde_json::from_str(&render(LogFormat::Json)).unwrap();
+
+        assert_eq!(entry["level"], "INFO");
+        assert_eq!(entry["target"], "access");
+        assert_eq!(entry["message"], "GET /v1/items/7");
+        assert_eq!(entry["request_id"], "req-1");
+        assert_eq!(entry["route"], "/v1/items/{id}");
+        assert_eq!(entry["status"], 404);
+        assert_eq!(entry["latency_ms"], 1.5);
+    }
+
+    #[test]
+    fn test_format_record_as_text() {
+        let line = render(LogFormat::Text);
+
+        assert!(line.ends_with(
+            " INFO access] req-1 GET /v1/items/7 latency_ms=1.5 route=\"/v1/items/{id}\" status=404"
+        ));
+        assert_eq!(current_request_id(), None);
+    }
+}
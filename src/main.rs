@@ -1,29 +1,236 @@
 use std::sync::Arc;
-use actix_web::middleware::Logger;
+use std::time::Duration;
+use actix_files::Files;
+use actix_web::middleware::from_fn;
 use actix_web::{web, App, HttpServer};
-use gomarket_items::api::controllers::items_handler::{create_item, get_items};
+use gomarket_items::api::controllers::currencies_handler::{
+    delete_price_list_price, get_exchange_rates, get_price_list, set_exchange_rate, set_price_list_price,
+};
+use gomarket_items::api::controllers::events_handler::get_item_events;
+use gomarket_items::api::controllers::health_handler::{get_liveness, get_readiness};
+use gomarket_items::api::controllers::items_handler::{create_item, get_bundles, get_items, set_bundle, validate_cart};
+use gomarket_items::api::controllers::maintenance_handler::{get_maintenance, set_maintenance};
+use gomarket_items::api::controllers::media_handler::upload_item_media;
+use gomarket_items::api::controllers::metrics_handler::get_metrics;
+use gomarket_items::api::controllers::prices_handler::{cancel_scheduled_price, get_item_prices, set_item_price};
+use gomarket_items::api::controllers::promotions_handler::{create_promotion, delete_promotion, get_promotions};
+use gomarket_items::api::controllers::taxes_handler::{
+    create_tax_class, delete_tax_rate, get_tax_classes, set_item_tax_class, set_tax_rate,
+};
+use gomarket_items::api::controllers::warehouses_handler::{
+    adjust_stock, create_warehouse, delete_backorder_rule, delete_stock_threshold, get_item_stock,
+    get_low_stock, get_stock_movements, get_warehouses, set_backorder_rule, set_stock_levels,
+    set_stock_threshold,
+};
+use gomarket_items::api::controllers::webhooks_handler::{
+    create_webhook, delete_webhook, get_webhook, get_webhook_deliveries, get_webhooks,
+    retry_webhook_delivery, update_webhook,
+};
+use gomarket_items::api::middleware::{
+    access_log, admin_auth, maintenance, record_metrics, request_id, trace_request, AdminToken,
+    LastServiceContext,
+};
+use gomarket_items::config::{Cli, Command, Config, MigrateAction};
 use gomarket_items::container::Container;
+use gomarket_items::domain::constants::{
+    ITEM_CACHE_STATS_INTERVAL_SECS, MEDIA_MAX_UPLOAD_SIZE, METRICS_COLLECT_INTERVAL_SECS,
+};
+use gomarket_items::infrastructure::databases::listener::spawn_item_change_listener;
+use gomarket_items::infrastructure::databases::migrations;
+use gomarket_items::infrastructure::metrics::spawn_metrics_collector;
+use gomarket_items::infrastructure::repositories::cached::spawn_item_cache_reporter;
+use gomarket_items::logging;
+use gomarket_items::services::events::spawn_event_relay;
+use gomarket_items::services::prices::spawn_price_scheduler;
+use gomarket_items::services::webhooks::spawn_webhook_dispatcher;
+use gomarket_items::telemetry;
+use clap::Parser;
+use diesel::{Connection, PgConnection};
 use log::info;
 
 #[cfg(test)]
 mod tests;
 
+/// Runs a `migrate` subcommand without starting the server.
+fn migrate(config: &Config, action: MigrateAction) -> std::io::Result<()> {
+    let mut conn = PgConnection::establish(&config.database.url)
+        .map_err(|e| std::io::Error::other(format!("cannot connect to the database: {}", e)))?;
+
+    let result = match action {
+        MigrateAction::Up => migrations::run_pending(&mut conn).map(|applied| {
+            for name in applied {
+                println!("Applied {}", name);
+            }
+        }),
+        MigrateAction::Down => migrations::revert_last(&mut conn)
+            .map(|name| println!("Reverted {}", name)),
+        MigrateAction::Status => migrations::status(&mut conn).map(|all| {
+            for (name, applied) in all {
+                println!("[{}] {}", if applied { "X" } else { " " }, name);
+            }
+        }),
+    };
+
+    result.map_err(|e| std::io::Error::other(e.message))
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    std::env::set_var("RUST_LOG", "debug");
-    env_logger::init();
-    info!("Starting server on 0.0.0.0:8000");
+    // Before parsing, so `.env` can also name the config file.
+    dotenv::dotenv().ok();
+    let cli = Cli::parse();
+    let config = Config::load(&cli).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+
+    if let Some(Command::Migrate { action }) = cli.command {
+        return migrate(&config, action);
+    }
 
-    let container = Container::new();
+    logging::init(&config.server.log_level, config.server.log_format);
+    telemetry::init(&config.tracing)
+        .map_err(|e| std::io::Error::other(format!("cannot set up tracing: {}", e)))?;
+
+    if config.database.run_migrations {
+        let mut conn = PgConnection::establish(&config.database.url)
+            .map_err(|e| std::io::Error::other(format!("cannot connect to the database: {}", e)))?;
+        let applied = migrations::run_pending(&mut conn)
+            .map_err(|e| std::io::Error::other(format!("cannot run migrations: {}", e.message)))?;
+        for name in applied {
+            info!("applied migration {}", name);
+        }
+    }
+
+    let container = Container::new(&config);
+    spawn_price_scheduler(
+        Arc::clone(&container.price_service),
+        container.price_scheduler_interval,
+    );
+    spawn_event_relay(
+        Arc::clone(&container.event_service),
+        container.event_relay_interval,
+    );
+    spawn_item_change_listener(
+        container.database_url.clone(),
+        container.item_caches.clone(),
+    );
+    for cache in &container.item_caches {
+        spawn_item_cache_reporter(
+            Arc::clone(cache),
+            Duration::from_secs(ITEM_CACHE_STATS_INTERVAL_SECS),
+        );
+    }
+    spawn_webhook_dispatcher(
+        Arc::clone(&container.webhook_service),
+        container.webhook_dispatch_interval,
+    );
+    spawn_metrics_collector(
+        Arc::clone(&container.metrics),
+        Arc::clone(&container.db_pool),
+        Arc::clone(&container.repository),
+        Duration::from_secs(METRICS_COLLECT_INTERVAL_SECS),
+    );
+
+    let media = config.media.clone();
+    let admin_token = AdminToken(config.server.admin_token.clone());
+    let last_service_context = web::Data::new(LastServiceContext::default());
     let server = HttpServer::new(move || {
-        App::new()
+        let app = App::new()
             .app_data(web::Data::from(Arc::clone(&container.core_service)))
-            .wrap(Logger::default())
-            .service(web::scope("").
-                route("/v1/items", web::get().to(get_items)).
-                route("/v1/items", web::post().to(create_item))
-            )
+            .app_data(web::Data::from(Arc::clone(&container.media_service)))
+            .app_data(web::Data::from(Arc::clone(&container.price_service)))
+            .app_data(web::Data::from(Arc::clone(&container.promotion_service)))
+            .app_data(web::Data::from(Arc::clone(&container.currency_service)))
+            .app_data(web::Data::from(Arc::clone(&container.tax_service)))
+            .app_data(web::Data::from(Arc::clone(&container.warehouse_service)))
+            .app_data(web::Data::from(Arc::clone(&container.webhook_service)))
+            .app_data(web::Data::from(Arc::clone(&container.event_service)))
+            .app_data(web::Data::from(Arc::clone(&container.health_service)))
+            .app_data(web::Data::from(Arc::clone(&container.service_context_service)))
+            .app_data(web::Data::from(Arc::clone(&container.metrics)))
+            .app_data(web::Data::new(admin_token.clone()))
+            .app_data(last_service_context.clone())
+            .wrap(from_fn(maintenance))
+            .wrap(from_fn(record_metrics))
+            .wrap(from_fn(access_log))
+            .wrap(from_fn(trace_request))
+            .wrap(from_fn(request_id));
+        // Registered before the catch-all scope, which would answer 404.
+        let app = if media.is_served_locally() {
+            app.service(Files::new(&media.base_url, &media.storage_path))
+        } else {
+            app
+        };
+
+        // Routes only operators may call. Webhooks are among them, since a
+        // subscription decides where the service sends requests.
+        app.service(web::scope("/v1/admin").
+            wrap(from_fn(admin_auth)).
+            route("/maintenance", web::get().to(get_maintenance)).
+            route("/maintenance", web::put().to(set_maintenance))
+        ).service(web::scope("/v1/webhooks").
+            wrap(from_fn(admin_auth)).
+            route("", web::get().to(get_webhooks)).
+            route("", web::post().to(create_webhook)).
+            route("/{id}", web::get().to(get_webhook)).
+            route("/{id}", web::put().to(update_webhook)).
+            route("/{id}", web::delete().to(delete_webhook)).
+            route("/{id}/deliveries", web::get().to(get_webhook_deliveries)).
+            route("/{id}/deliveries/{delivery_id}/retry", web::post().to(retry_webhook_delivery))
+        ).service(web::scope("").
+            route("/healthz", web::get().to(get_liveness)).
+            route("/readyz", web::get().to(get_readiness)).
+            route("/metrics", web::get().to(get_metrics)).
+            route("/v1/items", web::get().to(get_items)).
+            route("/v1/items", web::post().to(create_item)).
+            route("/v1/items/validate-cart", web::post().to(validate_cart)).
+            route("/v1/items/events", web::get().to(get_item_events)).
+            service(web::resource("/v1/items/{id}/media")
+                .app_data(web::PayloadConfig::new(MEDIA_MAX_UPLOAD_SIZE))
+                .route(web::post().to(upload_item_media))).
+            route("/v1/items/{id}/prices", web::get().to(get_item_prices)).
+            route("/v1/items/{id}/prices", web::post().to(set_item_price)).
+            route("/v1/items/{id}/prices/scheduled/{schedule_id}", web::delete().to(cancel_scheduled_price)).
+            route("/v1/items/{id}/tax-class", web::put().to(set_item_tax_class)).
+            route("/v1/items/{id}/stock", web::get().to(get_item_stock)).
+            route("/v1/items/{id}/bundles", web::get().to(get_bundles)).
+            route("/v1/items/{id}/sizes/{size}", web::patch().to(adjust_stock)).
+            route("/v1/items/{id}/sizes/{size}/movements", web::get().to(get_stock_movements)).
+            route("/v1/items/{id}/sizes/{size}/threshold", web::put().to(set_stock_threshold)).
+            route("/v1/items/{id}/sizes/{size}/threshold", web::delete().to(delete_stock_threshold)).
+            route("/v1/items/{id}/sizes/{size}/backorder", web::put().to(set_backorder_rule)).
+            route("/v1/items/{id}/sizes/{size}/backorder", web::delete().to(delete_backorder_rule)).
+            route("/v1/items/{id}/sizes/{size}/components", web::put().to(set_bundle)).
+            route("/v1/stock/low", web::get().to(get_low_stock)).
+            route("/v1/promotions", web::get().to(get_promotions)).
+            route("/v1/promotions", web::post().to(create_promotion)).
+            route("/v1/promotions/{id}", web::delete().to(delete_promotion)).
+            route("/v1/exchange-rates", web::get().to(get_exchange_rates)).
+            route("/v1/exchange-rates/{currency}", web::put().to(set_exchange_rate)).
+            route("/v1/price-lists/{currency}", web::get().to(get_price_list)).
+            route("/v1/price-lists/{currency}/items/{id}", web::put().to(set_price_list_price)).
+            route("/v1/price-lists/{currency}/items/{id}", web::delete().to(delete_price_list_price)).
+            route("/v1/tax-classes", web::get().to(get_tax_classes)).
+            route("/v1/tax-classes", web::post().to(create_tax_class)).
+            route("/v1/tax-classes/{id}/rates/{region}", web::put().to(set_tax_rate)).
+            route("/v1/tax-classes/{id}/rates/{region}", web::delete().to(delete_tax_rate)).
+            route("/v1/warehouses", web::get().to(get_warehouses)).
+            route("/v1/warehouses", web::post().to(create_warehouse)).
+            route("/v1/warehouses/{id}/stock", web::put().to(set_stock_levels))
+        )
     })
-    .bind(("0.0.0.0", 8000))?;
-    server.run().await
+    .keep_alive(Duration::from_secs(config.server.keep_alive_secs))
+    .client_request_timeout(Duration::from_millis(config.server.request_timeout_ms))
+    .shutdown_timeout(config.server.shutdown_timeout_secs);
+    let server = if config.server.workers > 0 {
+        server.workers(config.server.workers)
+    } else {
+        server
+    };
+
+    info!("Starting server on {}:{}", config.server.host, config.server.port);
+    let result = server.bind((config.server.host.as_str(), config.server.port))?.run().await;
+    telemetry::shutdown();
+    result
 }
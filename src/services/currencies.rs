@@ -0,0 +1,234 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::constants::BASE_CURRENCY_DEFAULT;
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::currencies::{ExchangeRate, PriceListEntry};
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::currencies::CurrencyService;
+use crate::services::taxes::normalize_region;
+
+#[derive(Clone)]
+pub struct CurrencyServiceImpl {
+    pub repository: Arc<dyn Repository>,
+    pub base_currency: String,
+}
+
+impl CurrencyServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>) -> Self {
+        CurrencyServiceImpl {
+            repository,
+            base_currency: BASE_CURRENCY_DEFAULT.to_string(),
+        }
+    }
+
+    pub fn with_base_currency(mut self, base_currency: String) -> Self {
+        self.base_currency = base_currency;
+        self
+    }
+
+    /// Prices in the base currency live in `items.price`, not in a price list.
+    fn foreign_currency(&self, currency: &str) -> Result<String, CommonError> {
+        let currency = normalize_currency(currency)?;
+
+        if currency == self.base_currency {
+            return Err(CommonError {
+                message: format!("{} is the base currency", currency),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(currency)
+    }
+}
+
+/// Validates an ISO 4217 code and brings it to upper case.
+pub fn normalize_currency(currency: &str) -> Result<String, CommonError> {
+    let currency = currency.trim();
+
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(CommonError {
+            message: format!("invalid currency code: {:?}", currency),
+            code: CommonErrorKind::InvalidInput,
+        });
+    }
+
+    Ok(currency.to_ascii_uppercase())
+}
+
+fn validate_amount(name: &str, value: f64, allow_zero: bool) -> Result<(), CommonError> {
+    if !value.is_finite() || value < 0.0 || (!allow_zero && value == 0.0) {
+        return Err(CommonError {
+            message: format!("invalid {}: {}", name, value),
+            code: CommonErrorKind::InvalidInput,
+        });
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl CurrencyService for CurrencyServiceImpl {
+    async fn get_exchange_rates(&self) -> Result<Vec<ExchangeRate>, CommonError> {
+        Ok(self.repository.get_exchange_rates().await?)
+    }
+
+    async fn set_exchange_rate(&self, currency: String, rate: f64) -> Result<ExchangeRate, CommonError> {
+        let currency = self.foreign_currency(&currency)?;
+        validate_amount("exchange rate", rate, false)?;
+
+        Ok(self.repository.set_exchange_rate(currency, rate).await?)
+    }
+
+    async fn get_price_list(
+        &self,
+        currency: String,
+        region: Option<String>,
+    ) -> Result<Vec<PriceListEntry>, CommonError> {
+        let currency = self.foreign_currency(&currency)?;
+        let region = region.as_deref().map(normalize_region).transpose()?;
+
+        Ok(self.repository.get_price_list(currency, region).await?)
+    }
+
+    async fn set_price_list_price(&self, entry: PriceListEntry) -> Result<PriceListEntry, CommonError> {
+        let currency = self.foreign_currency(&entry.currency)?;
+        let region = entry.region.as_deref().map(normalize_region).transpose()?;
+        validate_amount("price", entry.price, true)?;
+
+        Ok(self
+            .repository
+            .set_price_list_price(PriceListEntry {
+                currency,
+                region,
+                ..entry
+            })
+            .await?)
+    }
+
+    async fn delete_price_list_price(
+        &self,
+        currency: String,
+        region: Option<String>,
+        item_id: i64,
+    ) -> Result<(), CommonError> {
+        let currency = self.foreign_currency(&currency)?;
+        let region = region.as_deref().map(normalize_region).transpose()?;
+        self.repository
+            .delete_price_list_price(currency, region, item_id)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod currency_service_tests {
+    use super::*;
+    use crate::domain::repositories::items::MockRepository;
+    use chrono::Utc;
+
+    #[test]
+    fn normalize_currency_should_validate_codes() {
+        assert_eq!(normalize_currency(" eur ").unwrap(), "EUR");
+        assert!(normalize_currency("EURO").is_err());
+        assert!(normalize_currency("E1R").is_err());
+        assert!(normalize_currency("").is_err());
+    }
+
+    #[tokio::test]
+    async fn set_exchange_rate_should_store_normalized_currency() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository.expect_set_exchange_rate().return_once(|currency, rate| {
+            assert_eq!(currency, "EUR");
+            assert_eq!(rate, 0.9);
+
+            Box::pin(async move {
+                Ok(ExchangeRate {
+                    currency,
+                    rate,
+                    updated_at: Utc::now(),
+                })
+            })
+        });
+
+        let currency_service = CurrencyServiceImpl::new(Arc::new(mock_repository));
+        let res = currency_service
+            .set_exchange_rate("eur".to_string(), 0.9)
+            .await
+            .unwrap();
+
+        assert_eq!(res.currency, "EUR");
+    }
+
+    #[tokio::test]
+    async fn set_exchange_rate_should_reject_invalid_input() {
+        let currency_service = CurrencyServiceImpl::new(Arc::new(MockRepository::new()))
+            .with_base_currency("EUR".to_string());
+
+        for (currency, rate) in [("USD", 0.0), ("USD", f64::NAN), ("EUR", 1.0), ("US", 1.0)] {
+            let res = currency_service
+                .set_exchange_rate(currency.to_string(), rate)
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(res.code, CommonErrorKind::InvalidInput);
+        }
+    }
+
+    #[tokio::test]
+    async fn set_price_list_price_should_reject_negative_price() {
+        let currency_service = CurrencyServiceImpl::new(Arc::new(MockRepository::new()));
+
+        let res = currency_service
+            .set_price_list_price(PriceListEntry {
+                item_id: 1,
+                currency: "EUR".to_string(),
+                region: None,
+                price: -1.0,
+            })
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(res.code, CommonErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn set_price_list_price_should_store_normalized_region() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository.expect_set_price_list_price().return_once(|entry| {
+            assert_eq!(entry.currency, "CHF");
+            assert_eq!(entry.region, Some("CH-ZH".to_string()));
+
+            Box::pin(async move { Ok(entry) })
+        });
+
+        let currency_service = CurrencyServiceImpl::new(Arc::new(mock_repository));
+        let res = currency_service
+            .set_price_list_price(PriceListEntry {
+                item_id: 1,
+                currency: "chf".to_string(),
+                region: Some("ch-zh".to_string()),
+                price: 12.0,
+            })
+            .await;
+        assert!(res.is_ok());
+
+        let err = currency_service
+            .set_price_list_price(PriceListEntry {
+                item_id: 1,
+                currency: "CHF".to_string(),
+                region: Some("C".to_string()),
+                price: 12.0,
+            })
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code, CommonErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use log::{error, warn};
+
+use crate::domain::constants::{
+    EVENT_RELAY_BATCH_SIZE, EVENT_RELAY_CLAIM_SECS, ITEM_EVENTS_BATCH_SIZE,
+};
+use crate::domain::error::CommonError;
+use crate::domain::models::events::OutboxEvent;
+use crate::domain::notifiers::events::EventPublisher;
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::events::EventService;
+
+#[derive(Clone)]
+pub struct EventServiceImpl {
+    pub repository: Arc<dyn Repository>,
+    pub publisher: Arc<dyn EventPublisher>,
+}
+
+impl EventServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>, publisher: Arc<dyn EventPublisher>) -> Self {
+        EventServiceImpl {
+            repository,
+            publisher,
+        }
+    }
+}
+
+#[async_trait]
+impl EventService for EventServiceImpl {
+    async fn relay_events(&self) -> Result<usize, CommonError> {
+        // Claimed, so replicas relaying at the same time publish each event once.
+        let now = Utc::now();
+        let mut events = self
+            .repository
+            .claim_pending_events(
+                now,
+                now + chrono::Duration::seconds(EVENT_RELAY_CLAIM_SECS),
+                EVENT_RELAY_BATCH_SIZE,
+            )
+            .await?
+            .into_iter();
+        let mut delivered = 0;
+
+        while let Some(event) = events.next() {
+            let event_id = event.id;
+
+            if let Err(e) = self.publisher.publish(event).await {
+                warn!("failed to publish item event {}: {}", event_id, e);
+                self.repository
+                    .mark_event_failed(event_id, e.message)
+                    .await?;
+
+                // Keeps the order: the rest waits for the failed event.
+                let rest: Vec<i64> = events.map(|event| event.id).collect();
+                if !rest.is_empty() {
+                    self.repository.release_events(rest).await?;
+                }
+                break;
+            }
+
+            self.repository.mark_event_delivered(event_id).await?;
+            delivered += 1;
+        }
+
+        Ok(delivered)
+    }
+
+    async fn get_events_after(
+        &self,
+        after_id: i64,
+        item_ids: Vec<i64>,
+    ) -> Result<Vec<OutboxEvent>, CommonError> {
+        Ok(self
+            .repository
+            .get_events_after(after_id, item_ids, ITEM_EVENTS_BATCH_SIZE)
+            .await?)
+    }
+
+    async fn get_last_event_id(&self) -> Result<i64, CommonError> {
+        Ok(self.repository.get_last_event_id().await?)
+    }
+}
+
+/// Periodically hands committed item events to the publisher.
+pub fn spawn_event_relay(
+    event_service: Arc<dyn EventService>,
+    period: Duration,
+) -> actix_web::rt::task::JoinHandle<()> {
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(period);
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = event_service.relay_events().await {
+                error!("failed to relay item events: {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod event_service_tests {
+    use super::*;
+    use crate::domain::error::{NotifierError, NotifierErrorKind};
+    use crate::domain::models::events::{ItemEvent, OutboxEvent};
+    use crate::domain::notifiers::events::MockEventPublisher;
+    use crate::domain::repositories::items::MockRepository;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    fn pending() -> Vec<OutboxEvent> {
+        (1..=3)
+            .map(|id| OutboxEvent {
+                id,
+                event: ItemEvent::PriceChanged {
+                    item_id: 1,
+                    price: id as f64,
+                },
+                created_at: Utc::now(),
+                attempts: 0,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn relay_events_should_mark_published_events_delivered() {
+        let mut mock_repository = MockRepository::new();
+        let mut mock_publisher = MockEventPublisher::new();
+
+        mock_repository
+            .expect_claim_pending_events()
+            .withf(|now, claimed_until, limit| {
+                *claimed_until == *now + chrono::Duration::seconds(EVENT_RELAY_CLAIM_SECS)
+                    && *limit == EVENT_RELAY_BATCH_SIZE
+            })
+            .return_once(|_, _, _| Box::pin(async move { Ok(pending()) }));
+        mock_publisher
+            .expect_publish()
+            .times(3)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repository
+            .expect_mark_event_delivered()
+            .times(3)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let event_service =
+            EventServiceImpl::new(Arc::new(mock_repository), Arc::new(mock_publisher));
+
+        assert_eq!(event_service.relay_events().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn relay_events_should_stop_at_first_failure() {
+        let mut mock_repository = MockRepository::new();
+        let mut mock_publisher = MockEventPublisher::new();
+
+        mock_repository
+            .expect_claim_pending_events()
+            .return_once(|_, _, _| Box::pin(async move { Ok(pending()) }));
+        mock_publisher
+            .expect_publish()
+            .withf(|event| event.id == 1)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_publisher
+            .expect_publish()
+            .withf(|event| event.id == 2)
+            .times(1)
+            .returning(|_| {
+                Box::pin(async {
+                    Err(NotifierError {
+                        message: "broker down".to_string(),
+                        code: NotifierErrorKind::Unknown,
+                    })
+                })
+            });
+        mock_repository
+            .expect_mark_event_delivered()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repository
+            .expect_mark_event_failed()
+            .with(eq(2), eq("broker down".to_string()))
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_repository
+            .expect_release_events()
+            .with(eq(vec![3]))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let event_service =
+            EventServiceImpl::new(Arc::new(mock_repository), Arc::new(mock_publisher));
+
+        assert_eq!(event_service.relay_events().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_events_after_should_read_one_batch() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_get_events_after()
+            .withf(|after_id, item_ids, limit| {
+                *after_id == 7 && item_ids == &vec![1, 2] && *limit == ITEM_EVENTS_BATCH_SIZE
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async move { Ok(pending()) }));
+
+        let event_service = EventServiceImpl::new(
+            Arc::new(mock_repository),
+            Arc::new(MockEventPublisher::new()),
+        );
+
+        assert_eq!(
+            event_service
+                .get_events_after(7, vec![1, 2])
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+}
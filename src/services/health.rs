@@ -0,0 +1,206 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::domain::constants::HEALTH_CHECK_TIMEOUT_MS;
+use crate::domain::models::health::{HealthCheck, HealthReport, HealthStatus};
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::health::HealthService;
+use crate::domain::services::service_context::ServiceContextService;
+
+#[derive(Clone)]
+pub struct HealthServiceImpl {
+    pub repository: Arc<dyn Repository>,
+    pub service_context: Option<Arc<dyn ServiceContextService>>,
+}
+
+impl HealthServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>) -> Self {
+        HealthServiceImpl {
+            repository,
+            service_context: None,
+        }
+    }
+
+    pub fn with_service_context(mut self, service_context: Arc<dyn ServiceContextService>) -> Self {
+        self.service_context = Some(service_context);
+        self
+    }
+}
+
+/// Runs one check under the timeout; `probe` returns the failure details.
+async fn check<F>(name: &str, probe: F) -> HealthCheck
+where
+    F: Future<Output = Result<(), String>>,
+{
+    let started = Instant::now();
+    let result = actix_web::rt::time::timeout(Duration::from_millis(HEALTH_CHECK_TIMEOUT_MS), probe)
+        .await
+        .unwrap_or_else(|_| Err(format!("timed out after {}ms", HEALTH_CHECK_TIMEOUT_MS)));
+
+    HealthCheck {
+        name: name.to_string(),
+        status: if result.is_ok() { HealthStatus::Up } else { HealthStatus::Down },
+        details: result.err(),
+        duration_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+#[async_trait]
+impl HealthService for HealthServiceImpl {
+    async fn check_readiness(&self) -> HealthReport {
+        let mut checks = vec![
+            check("database", async {
+                self.repository.ping().await.map_err(|e| e.message)
+            })
+            .await,
+            check("migrations", async {
+                let pending = self
+                    .repository
+                    .get_pending_migrations()
+                    .await
+                    .map_err(|e| e.message)?;
+
+                if pending.is_empty() {
+                    Ok(())
+                } else {
+                    Err(format!("pending: {}", pending.join(", ")))
+                }
+            })
+            .await,
+        ];
+
+        if let Some(service_context) = &self.service_context {
+            checks.push(
+                // Maintenance of writes only leaves reads up, so the replica
+                // stays in rotation.
+                check("maintenance", async {
+                    match service_context.get_service_context().await {
+                        Ok(service_context) if service_context.refuses(true) => {
+                            Err("maintenance mode is on for all requests".to_string())
+                        }
+                        Ok(_) => Ok(()),
+                        Err(e) => Err(e.message),
+                    }
+                })
+                .await,
+            );
+        }
+
+        HealthReport::new(checks)
+    }
+}
+
+#[cfg(test)]
+mod health_service_tests {
+    use super::*;
+    use crate::domain::error::{CommonError, CommonErrorKind, RepositoryError, RepositoryErrorKind};
+    use crate::domain::models::service_context::{MaintenanceScope, ServiceContext};
+    use crate::domain::repositories::items::MockRepository;
+    use crate::domain::services::service_context::MockServiceContextService;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn check_readiness_should_pass_when_every_check_is_up() {
+        let mut mock_repository = MockRepository::new();
+        mock_repository
+            .expect_ping()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(()) }));
+        mock_repository
+            .expect_get_pending_migrations()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        let mut mock_context = MockServiceContextService::new();
+        mock_context.expect_get_service_context().times(1).returning(|| {
+            Box::pin(async {
+                Ok(ServiceContext {
+                    id: 1,
+                    maintenance: true,
+                    maintenance_scope: MaintenanceScope::Writes,
+                    retry_after_secs: 300,
+                    updated_at: Utc::now(),
+                })
+            })
+        });
+
+        let service = HealthServiceImpl::new(Arc::new(mock_repository))
+            .with_service_context(Arc::new(mock_context));
+        let report = service.check_readiness().await;
+
+        assert_eq!(report.status, HealthStatus::Up);
+        assert_eq!(
+            report.checks.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+            vec!["database", "migrations", "maintenance"]
+        );
+        assert!(report.checks.iter().all(|c| c.details.is_none()));
+    }
+
+    #[tokio::test]
+    async fn check_readiness_should_report_every_failure() {
+        let mut mock_repository = MockRepository::new();
+        mock_repository.expect_ping().times(1).returning(|| {
+            Box::pin(async {
+                Err(RepositoryError {
+                    message: "connection refused".to_string(),
+                    code: RepositoryErrorKind::Unknown,
+                })
+            })
+        });
+        mock_repository
+            .expect_get_pending_migrations()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(vec!["2023-12-21-090000_webhooks".to_string()]) }));
+        let mut mock_context = MockServiceContextService::new();
+        mock_context.expect_get_service_context().times(1).returning(|| {
+            Box::pin(async {
+                Err(CommonError {
+                    message: "no service context".to_string(),
+                    code: CommonErrorKind::Unknown,
+                })
+            })
+        });
+
+        let service = HealthServiceImpl::new(Arc::new(mock_repository))
+            .with_service_context(Arc::new(mock_context));
+        let report = service.check_readiness().await;
+
+        assert_eq!(report.status, HealthStatus::Down);
+        assert_eq!(
+            report.checks.iter().map(|c| c.details.clone()).collect::<Vec<_>>(),
+            vec![
+                Some("connection refused".to_string()),
+                Some("pending: 2023-12-21-090000_webhooks".to_string()),
+                Some("no service context".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_readiness_should_time_out_slow_checks() {
+        let mut mock_repository = MockRepository::new();
+        mock_repository.expect_ping().times(1).returning(|| {
+            Box::pin(async {
+                actix_web::rt::time::sleep(Duration::from_secs(60)).await;
+                Ok(())
+            })
+        });
+        mock_repository
+            .expect_get_pending_migrations()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+
+        let service = HealthServiceImpl::new(Arc::new(mock_repository));
+        let report = service.check_readiness().await;
+
+        assert_eq!(report.status, HealthStatus::Down);
+        assert_eq!(report.checks.len(), 2);
+        assert_eq!(
+            report.checks[0].details,
+            Some(format!("timed out after {}ms", HEALTH_CHECK_TIMEOUT_MS))
+        );
+        assert_eq!(report.checks[1].status, HealthStatus::Up);
+    }
+}
@@ -1,26 +1,181 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use tracing::instrument;
 
-use crate::domain::error::CommonError;
-use crate::domain::models::items::{GetItemsQuery, GetItemsSortBy, Item};
+use crate::domain::constants::BASE_CURRENCY_DEFAULT;
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::backorders::{Availability, BackorderRule};
+use crate::domain::models::bundles::{Bundle, BundleComponent};
+use crate::domain::models::carts::{CartLine, CartLineResult, CartLineStatus, ItemAvailability};
+use crate::domain::models::items::{GetItemsQuery, GetItemsSortBy, Item, Size};
+use crate::domain::models::media::ItemMedia;
+use crate::domain::models::promotions::EffectivePrice;
 use crate::domain::repositories::items::Repository;
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::services::order::CoreService;
+use crate::services::currencies::normalize_currency;
+use crate::services::taxes::{calculate_tax, normalize_region};
 
 #[derive(Clone)]
 pub struct CoreServiceImpl {
     pub repository: Arc<dyn Repository>,
+    pub base_currency: String,
 }
 
 impl CoreServiceImpl {
     pub fn new(repository: Arc<dyn Repository>) -> Self {
-        CoreServiceImpl { repository }
+        CoreServiceImpl {
+            repository,
+            base_currency: BASE_CURRENCY_DEFAULT.to_string(),
+        }
     }
+
+    pub fn with_base_currency(mut self, base_currency: String) -> Self {
+        self.base_currency = base_currency;
+        self
+    }
+
+    /// Returns the requested currency unless prices are already stored in it.
+    async fn foreign_currency(&self, currency: Option<String>) -> Result<Option<String>, CommonError> {
+        let currency = match currency {
+            Some(currency) => normalize_currency(&currency)?,
+            None => return Ok(None),
+        };
+
+        if currency == self.base_currency {
+            return Ok(None);
+        }
+
+        if !self.repository.currency_exists(currency.clone()).await? {
+            return Err(CommonError {
+                message: format!("unsupported currency: {}", currency),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(Some(currency))
+    }
+
+    /// Returns the requested region if it has tax rates or regional prices.
+    async fn known_region(&self, region: Option<String>) -> Result<Option<String>, CommonError> {
+        let region = match region {
+            Some(region) => normalize_region(&region)?,
+            None => return Ok(None),
+        };
+
+        if !self.repository.region_exists(region.clone()).await? {
+            return Err(CommonError {
+                message: format!("no tax rates or prices for region: {}", region),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(Some(region))
+    }
+}
+
+/// Best availability over the sizes of an item, and the earliest date its
+/// back- or pre-ordered sizes ship when none is in stock.
+fn item_availability(
+    sizes: &[(Size, i32)],
+    rules: &[BackorderRule],
+) -> (Availability, Option<NaiveDate>) {
+    let rule = |name: &str| rules.iter().find(|r| r.size == name);
+    let unlisted = rules
+        .iter()
+        .filter(|r| !sizes.iter().any(|(size, _)| size.name == r.size))
+        .map(|r| (Availability::of(0, Some(r)), Some(r)));
+    let per_size: Vec<(Availability, Option<&BackorderRule>)> = sizes
+        .iter()
+        .map(|(size, quantity)| (Availability::of(*quantity, rule(&size.name)), rule(&size.name)))
+        .chain(unlisted)
+        .collect();
+
+    let best = [Availability::InStock, Availability::Backorder, Availability::Preorder]
+        .into_iter()
+        .find(|a| per_size.iter().any(|(availability, _)| availability == a))
+        .unwrap_or(Availability::OutOfStock);
+
+    let ship_date = match best {
+        Availability::Backorder | Availability::Preorder => per_size
+            .iter()
+            .filter(|(availability, _)| *availability == best)
+            .filter_map(|(_, rule)| rule.and_then(|r| r.expected_date))
+            .min(),
+        _ => None,
+    };
+
+    (best, ship_date)
+}
+
+fn validate_bundle(item_id: i64, size: &str, components: &[BundleComponent]) -> Result<(), String> {
+    if size.trim().is_empty() {
+        return Err(format!("empty size for bundle item {}", item_id));
+    }
+
+    let mut seen = HashSet::new();
+
+    for component in components {
+        if component.item_id == item_id {
+            return Err(format!("bundle item {} cannot contain itself", item_id));
+        }
+
+        if component.size.trim().is_empty() || component.quantity <= 0 {
+            return Err(format!(
+                "invalid component item {} size '{}' quantity {}",
+                component.item_id, component.size, component.quantity
+            ));
+        }
+
+        if !seen.insert((component.item_id, component.size.as_str())) {
+            return Err(format!(
+                "duplicate component item {} size {}",
+                component.item_id, component.size
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Status of a cart line against the current item. Missing items come first,
+/// then stock, then the price of the line's size, which is compared in cents.
+fn check_cart_line(line: &CartLine, item: Option<&ItemAvailability>) -> CartLineStatus {
+    let item = match item {
+        Some(item) => item,
+        None => return CartLineStatus::ItemMissing,
+    };
+
+    let available = match item.sizes.iter().find(|(size, _)| *size == line.size) {
+        Some((_, quantity)) => *quantity,
+        None => return CartLineStatus::ItemMissing,
+    };
+
+    if available < line.quantity {
+        return CartLineStatus::InsufficientStock { available };
+    }
+
+    let sale_price = item
+        .size_sale_prices
+        .iter()
+        .find(|(size, _)| *size == line.size)
+        .map_or(item.sale_price, |(_, price)| *price);
+
+    if (line.expected_price * 100.0).round() != (sale_price * 100.0).round() {
+        return CartLineStatus::PriceChanged {
+            current_price: sale_price,
+        };
+    }
+
+    CartLineStatus::Ok
 }
 
 #[async_trait]
 impl CoreService for CoreServiceImpl {
+    #[instrument(skip(self, query, sort_by))]
     async fn get_items(
         &self,
         query: Option<GetItemsQuery>,
@@ -28,7 +183,19 @@ impl CoreService for CoreServiceImpl {
         offset: i64,
         limit: i64,
     ) -> Result<ResultPaging<Item>, CommonError> {
-        let items: Vec<Item> = self
+        let mut query = query;
+        let currency = self
+            .foreign_currency(query.as_mut().and_then(|q| q.currency.take()))
+            .await?;
+        let region = self
+            .known_region(query.as_mut().and_then(|q| q.region.take()))
+            .await?;
+        if let Some(query) = query.as_mut() {
+            query.currency = currency.clone();
+            query.region = region.clone();
+        }
+
+        let mut items: Vec<Item> = self
             .repository
             .get_items(query, sort_by, offset, limit)
             .await?
@@ -36,6 +203,124 @@ impl CoreService for CoreServiceImpl {
             .map(Item::from)
             .collect();
 
+        if !items.is_empty() {
+            let mut media: HashMap<i64, Vec<ItemMedia>> = HashMap::new();
+            for m in self
+                .repository
+                .get_items_media(items.iter().map(|item| item.id).collect())
+                .await?
+            {
+                media.entry(m.item_id).or_default().push(m);
+            }
+
+            let mut prices: HashMap<i64, EffectivePrice> = self
+                .repository
+                .get_effective_prices(items.iter().map(|item| item.id).collect())
+                .await?
+                .into_iter()
+                .map(|p| (p.item_id, p))
+                .collect();
+
+            let mut rules: HashMap<i64, Vec<BackorderRule>> = HashMap::new();
+            for rule in self
+                .repository
+                .get_backorder_rules(items.iter().map(|item| item.id).collect())
+                .await?
+            {
+                rules.entry(rule.item_id).or_default().push(rule);
+            }
+
+            // Bundle stock follows the total component stock, whatever the
+            // warehouse filter.
+            let mut bundles: HashMap<i64, Vec<Bundle>> = HashMap::new();
+            for bundle in self
+                .repository
+                .get_bundles(items.iter().map(|item| item.id).collect())
+                .await?
+            {
+                bundles.entry(bundle.item_id).or_default().push(bundle);
+            }
+
+            for item in items.iter_mut() {
+                item.media = media.remove(&item.id).unwrap_or_default();
+
+                for bundle in bundles.remove(&item.id).unwrap_or_default() {
+                    match item.sizes.iter_mut().find(|(size, _)| size.id == bundle.size.id) {
+                        Some((_, quantity)) => *quantity = bundle.stock(),
+                        None => item.sizes.push((bundle.size.clone(), bundle.stock())),
+                    }
+                }
+
+                let (availability, ship_date) =
+                    item_availability(&item.sizes, rules.get(&item.id).map_or(&[], |r| r));
+                item.availability = Some(availability);
+                item.expected_ship_date = ship_date;
+
+                match prices.remove(&item.id) {
+                    Some(effective) => {
+                        item.sale_price = Some(effective.sale_price);
+                        item.promotion_id = effective.promotion_id;
+                        item.size_sale_prices = effective.sizes;
+                    }
+                    None => item.sale_price = Some(item.price),
+                }
+            }
+
+            if let Some(currency) = &currency {
+                let mut converted: HashMap<i64, f64> = self
+                    .repository
+                    .get_currency_prices(
+                        items.iter().map(|item| item.id).collect(),
+                        currency.clone(),
+                        region.clone(),
+                    )
+                    .await?
+                    .into_iter()
+                    .map(|p| (p.item_id, p.price))
+                    .collect();
+
+                // Promotions are defined in the base currency, so their discount
+                // carries over as a ratio.
+                for item in items.iter_mut() {
+                    if let Some(price) = converted.remove(&item.id) {
+                        let ratio = match item.sale_price {
+                            Some(sale_price) if item.price > 0.0 => sale_price / item.price,
+                            _ => 1.0,
+                        };
+
+                        if item.price > 0.0 {
+                            for size in item.size_sale_prices.iter_mut() {
+                                size.sale_price = price * size.sale_price / item.price;
+                            }
+                        }
+
+                        item.price = price;
+                        item.sale_price = Some(price * ratio);
+                    }
+                }
+            }
+
+            if let Some(region) = &region {
+                let rates: HashMap<i64, f64> = self
+                    .repository
+                    .get_item_tax_rates(items.iter().map(|item| item.id).collect(), region.clone())
+                    .await?
+                    .into_iter()
+                    .map(|r| (r.item_id, r.rate))
+                    .collect();
+
+                for item in items.iter_mut() {
+                    let rate = rates.get(&item.id).copied().unwrap_or(0.0);
+                    let net = item.sale_price.unwrap_or(item.price);
+                    item.tax = Some(calculate_tax(region.clone(), rate, net));
+                }
+            }
+        }
+
+        for item in items.iter_mut() {
+            item.currency = Some(currency.clone().unwrap_or_else(|| self.base_currency.clone()));
+        }
+
         Ok(ResultPaging {
             offset,
             total: items.len() as i64,
@@ -43,21 +328,111 @@ impl CoreService for CoreServiceImpl {
         })
     }
 
+    #[instrument(skip_all)]
     async fn create_item(&self, item: Item) -> Result<i64, CommonError> {
+        if let Some(currency) = &item.currency {
+            if normalize_currency(currency)? != self.base_currency {
+                return Err(CommonError {
+                    message: format!("items are priced in {}", self.base_currency),
+                    code: CommonErrorKind::InvalidInput,
+                });
+            }
+        }
+
         let item_id = self.repository.create_item(item).await?;
 
         Ok(item_id)
     }
+
+    #[instrument(skip_all, fields(lines = lines.len()))]
+    async fn validate_cart(&self, lines: Vec<CartLine>) -> Result<Vec<CartLineResult>, CommonError> {
+        if lines.is_empty() {
+            return Err(CommonError {
+                message: "cart has no lines".to_string(),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        if let Some(line) = lines
+            .iter()
+            .find(|l| l.quantity <= 0 || !l.expected_price.is_finite() || l.expected_price < 0.0)
+        {
+            return Err(CommonError {
+                message: format!(
+                    "invalid line for item {} size {}: quantity {}, price {}",
+                    line.item_id, line.size, line.quantity, line.expected_price
+                ),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        let mut item_ids: Vec<i64> = lines.iter().map(|l| l.item_id).collect();
+        item_ids.sort_unstable();
+        item_ids.dedup();
+
+        let items: HashMap<i64, ItemAvailability> = self
+            .repository
+            .get_item_availability(item_ids)
+            .await?
+            .into_iter()
+            .map(|a| (a.item_id, a))
+            .collect();
+
+        Ok(lines
+            .into_iter()
+            .map(|line| CartLineResult {
+                status: check_cart_line(&line, items.get(&line.item_id)),
+                line,
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self, components))]
+    async fn set_bundle(
+        &self,
+        item_id: i64,
+        size: String,
+        components: Vec<BundleComponent>,
+    ) -> Result<Bundle, CommonError> {
+        validate_bundle(item_id, &size, &components).map_err(|message| CommonError {
+            message,
+            code: CommonErrorKind::InvalidInput,
+        })?;
+
+        Ok(self.repository.set_bundle(item_id, size, components).await?)
+    }
+
+    #[instrument(skip(self))]
+    async fn get_bundles(&self, item_id: i64) -> Result<Vec<Bundle>, CommonError> {
+        Ok(self.repository.get_bundles(vec![item_id]).await?)
+    }
 }
 
 #[cfg(test)]
 mod core_service_tests {
     use super::*;
+    use crate::domain::models::backorders::BackorderPolicy;
+    use crate::domain::models::currencies::PriceListEntry;
     use crate::domain::models::items::{NamesGetItemsQuery, PriceGetItemsQuery, Size};
+    use crate::domain::models::promotions::SizeEffectivePrice;
+    use crate::domain::models::taxes::{ItemTax, ItemTaxRate};
     use crate::domain::repositories::items::MockRepository;
     use std::sync::Arc;
     use crate::infrastructure::models::items::*;
-    use crate::domain::error::{CommonErrorKind, RepositoryError, RepositoryErrorKind};
+    use crate::domain::error::{RepositoryError, RepositoryErrorKind};
+
+    fn get_test_media() -> ItemMedia {
+        ItemMedia {
+            id: 1,
+            item_id: 1,
+            url: "/media/items/1/a.png".to_string(),
+            path: Some("items/1/a.png".to_string()),
+            alt_text: "".to_string(),
+            position: 0,
+            width: Some(100),
+            height: Some(100),
+        }
+    }
 
     fn get_test_data() -> Vec<(ItemDiesel, Vec<SizeDiesel>, Vec<ItemsSizesDiesel>)> {
         vec![
@@ -67,6 +442,8 @@ mod core_service_tests {
                     name: "Item 1".to_string(),
                     description: "".to_string(),
                     price: 0.0,
+                    category: None,
+                    tax_class_id: None,
                 },
                 vec![SizeDiesel {
                     id: 1,
@@ -85,6 +462,8 @@ mod core_service_tests {
                     name: "Item 2".to_string(),
                     description: "".to_string(),
                     price: 0.0,
+                    category: None,
+                    tax_class_id: None,
                 },
                 vec![SizeDiesel {
                     id: 2,
@@ -111,6 +490,7 @@ mod core_service_tests {
                 name: "Item 1".to_string(),
                 description: "".to_string(),
                 price: 0.0,
+                category: None,
                 sizes: vec![(
                     Size {
                         id: 1,
@@ -118,12 +498,22 @@ mod core_service_tests {
                     },
                     1,
                 )],
+                media: vec![get_test_media()],
+                sale_price: Some(0.0),
+                promotion_id: Some(3),
+                size_sale_prices: vec![],
+                currency: Some("USD".to_string()),
+                tax: None,
+                tax_class_id: None,
+                availability: Some(Availability::InStock),
+                expected_ship_date: None,
             },
             Item {
                 id: 2,
                 name: "Item 2".to_string(),
                 description: "".to_string(),
                 price: 0.0,
+                category: None,
                 sizes: vec![(
                     Size {
                         id: 2,
@@ -131,6 +521,15 @@ mod core_service_tests {
                     },
                     0,
                 )],
+                media: vec![],
+                sale_price: Some(0.0),
+                promotion_id: None,
+                size_sale_prices: vec![],
+                currency: Some("USD".to_string()),
+                tax: None,
+                tax_class_id: None,
+                availability: Some(Availability::OutOfStock),
+                expected_ship_date: None,
             },
         ];
 
@@ -148,6 +547,30 @@ mod core_service_tests {
                 Box::pin(async move { Ok(mock_items) })
             },
         );
+        mock_repository
+            .expect_get_backorder_rules()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_bundles()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository.expect_get_items_media().return_once(|ids| {
+            assert_eq!(ids, vec![1, 2]);
+
+            Box::pin(async move { Ok(vec![get_test_media()]) })
+        });
+        mock_repository.expect_get_effective_prices().return_once(|ids| {
+            assert_eq!(ids, vec![1, 2]);
+
+            Box::pin(async move {
+                Ok(vec![EffectivePrice {
+                    item_id: 1,
+                    price: 0.0,
+                    sale_price: 0.0,
+                    promotion_id: Some(3),
+                    sizes: vec![],
+                }])
+            })
+        });
 
         let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
         let res = core_service.get_items(None, None, 0, 10).await.unwrap();
@@ -169,12 +592,20 @@ mod core_service_tests {
                 full: Some(vec!["Item 1".to_string()]),
                 partly: None,
             }),
+            currency: None,
+            region: None,
+            warehouse_id: None,
         });
         let sort = None;
         let limit = 0;
         let offset = 0;
 
-        let want_vec = vec![mock_items[0].clone().into()];
+        let want_vec = vec![Item {
+            sale_price: Some(0.0),
+            currency: Some("USD".to_string()),
+            availability: Some(Availability::InStock),
+            ..mock_items[0].clone().into()
+        }];
         let want_offset = 0;
         let want_total = 1;
 
@@ -194,6 +625,18 @@ mod core_service_tests {
                 Box::pin(async move { Ok(vec![mock_items[0].clone()]) })
             },
         );
+        mock_repository
+            .expect_get_backorder_rules()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_bundles()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_items_media()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_effective_prices()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
 
         let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
 
@@ -217,14 +660,23 @@ mod core_service_tests {
             price: Some(PriceGetItemsQuery {
                 from: Some(0.0),
                 to: Some(100.0),
+                effective: false,
             }),
             names: None,
+            currency: None,
+            region: None,
+            warehouse_id: None,
         });
         let sort = None;
         let limit = 0;
         let offset = 0;
 
-        let want_vec = vec![mock_items[1].clone().into()];
+        let want_vec = vec![Item {
+            sale_price: Some(0.0),
+            currency: Some("USD".to_string()),
+            availability: Some(Availability::OutOfStock),
+            ..mock_items[1].clone().into()
+        }];
         let want_offset = 0;
         let want_total = 1;
 
@@ -244,6 +696,18 @@ mod core_service_tests {
                 Box::pin(async move { Ok(vec![mock_items[1].clone()]) })
             },
         );
+        mock_repository
+            .expect_get_backorder_rules()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_bundles()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_items_media()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_effective_prices()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
 
         let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
 
@@ -257,6 +721,296 @@ mod core_service_tests {
         assert_eq!(res.items, want_vec);
     }
 
+    #[tokio::test]
+    async fn get_items_should_convert_prices_to_requested_currency() {
+        let mut mock_repository = MockRepository::new();
+        let mut mock_items = get_test_data();
+        mock_items[0].0.price = 100.0;
+
+        mock_repository.expect_currency_exists().return_once(|c| {
+            assert_eq!(c, "EUR");
+
+            Box::pin(async move { Ok(true) })
+        });
+        mock_repository.expect_get_items().return_once(move |q, _, _, _| {
+            assert_eq!(q.unwrap().currency, Some("EUR".to_string()));
+
+            Box::pin(async move { Ok(vec![mock_items[0].clone()]) })
+        });
+        mock_repository
+            .expect_get_backorder_rules()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_bundles()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_items_media()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository.expect_get_effective_prices().return_once(|_| {
+            Box::pin(async move {
+                Ok(vec![EffectivePrice {
+                    item_id: 1,
+                    price: 100.0,
+                    sale_price: 80.0,
+                    promotion_id: Some(3),
+                    sizes: vec![SizeEffectivePrice {
+                        size: "S".to_string(),
+                        sale_price: 50.0,
+                        promotion_id: Some(4),
+                    }],
+                }])
+            })
+        });
+        mock_repository
+            .expect_get_currency_prices()
+            .return_once(|ids, currency, region| {
+                assert_eq!(ids, vec![1]);
+                assert_eq!(region, None);
+
+                Box::pin(async move {
+                    Ok(vec![PriceListEntry {
+                        item_id: 1,
+                        currency,
+                        region,
+                        price: 90.0,
+                    }])
+                })
+            });
+
+        let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
+        let query = Some(GetItemsQuery {
+            currency: Some("eur".to_string()),
+            ..Default::default()
+        });
+
+        let res = core_service.get_items(query, None, 0, 10).await.unwrap();
+
+        assert_eq!(res.items[0].price, 90.0);
+        assert_eq!(res.items[0].sale_price, Some(72.0));
+        assert_eq!(res.items[0].size_sale_prices[0].sale_price, 45.0);
+        assert_eq!(res.items[0].currency, Some("EUR".to_string()));
+    }
+
+    #[tokio::test]
+    async fn get_items_should_reject_unsupported_currency() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_currency_exists()
+            .return_once(|_| Box::pin(async move { Ok(false) }));
+
+        let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
+        let query = Some(GetItemsQuery {
+            currency: Some("XYZ".to_string()),
+            ..Default::default()
+        });
+
+        let res = core_service.get_items(query, None, 0, 10).await.err().unwrap();
+
+        assert_eq!(res.code, CommonErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn get_items_should_calculate_taxes_for_region() {
+        let mut mock_repository = MockRepository::new();
+        let mut mock_items = get_test_data();
+        mock_items[0].0.price = 100.0;
+        mock_items[1].0.price = 50.0;
+
+        mock_repository.expect_region_exists().return_once(|r| {
+            assert_eq!(r, "DE");
+
+            Box::pin(async move { Ok(true) })
+        });
+        mock_repository
+            .expect_get_items()
+            .return_once(move |_, _, _, _| Box::pin(async move { Ok(mock_items) }));
+        mock_repository
+            .expect_get_backorder_rules()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_bundles()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_items_media()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository.expect_get_effective_prices().return_once(|_| {
+            Box::pin(async move {
+                Ok(vec![EffectivePrice {
+                    item_id: 1,
+                    price: 100.0,
+                    sale_price: 80.0,
+                    promotion_id: Some(3),
+                    sizes: vec![],
+                }])
+            })
+        });
+        mock_repository
+            .expect_get_item_tax_rates()
+            .return_once(|ids, region| {
+                assert_eq!(ids, vec![1, 2]);
+                assert_eq!(region, "DE");
+
+                Box::pin(async move { Ok(vec![ItemTaxRate { item_id: 1, rate: 19.0 }]) })
+            });
+
+        let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
+        let query = Some(GetItemsQuery {
+            region: Some("de".to_string()),
+            ..Default::default()
+        });
+
+        let res = core_service.get_items(query, None, 0, 10).await.unwrap();
+
+        assert_eq!(
+            res.items[0].tax,
+            Some(ItemTax {
+                region: "DE".to_string(),
+                rate: 19.0,
+                net: 80.0,
+                tax: 15.2,
+                gross: 95.2,
+            })
+        );
+        assert_eq!(res.items[1].tax.as_ref().map(|t| t.gross), Some(50.0));
+    }
+
+    #[tokio::test]
+    async fn get_items_should_derive_availability_from_backorder_rules() {
+        let mut mock_repository = MockRepository::new();
+        let mock_items = get_test_data();
+
+        mock_repository
+            .expect_get_items()
+            .return_once(move |_, _, _, _| Box::pin(async move { Ok(mock_items) }));
+        mock_repository
+            .expect_get_bundles()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository.expect_get_backorder_rules().return_once(|ids| {
+            assert_eq!(ids, vec![1, 2]);
+
+            Box::pin(async move {
+                Ok(vec![
+                    BackorderRule {
+                        item_id: 1,
+                        size: "S".to_string(),
+                        policy: BackorderPolicy::Backorder,
+                        limit: None,
+                        expected_date: NaiveDate::from_ymd_opt(2024, 1, 10),
+                        backordered: 0,
+                    },
+                    BackorderRule {
+                        item_id: 2,
+                        size: "M".to_string(),
+                        policy: BackorderPolicy::Backorder,
+                        limit: Some(5),
+                        expected_date: NaiveDate::from_ymd_opt(2024, 1, 10),
+                        backordered: 5,
+                    },
+                    BackorderRule {
+                        item_id: 2,
+                        size: "L".to_string(),
+                        policy: BackorderPolicy::Preorder,
+                        limit: None,
+                        expected_date: NaiveDate::from_ymd_opt(2024, 3, 1),
+                        backordered: 0,
+                    },
+                ])
+            })
+        });
+        mock_repository
+            .expect_get_items_media()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_effective_prices()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+
+        let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
+        let res = core_service.get_items(None, None, 0, 10).await.unwrap();
+
+        assert_eq!(res.items[0].availability, Some(Availability::InStock));
+        assert_eq!(res.items[0].expected_ship_date, None);
+        assert_eq!(res.items[1].availability, Some(Availability::Preorder));
+        assert_eq!(res.items[1].expected_ship_date, NaiveDate::from_ymd_opt(2024, 3, 1));
+    }
+
+    #[tokio::test]
+    async fn get_items_should_derive_bundle_stock_from_components() {
+        let mut mock_repository = MockRepository::new();
+        let mock_items = get_test_data();
+
+        mock_repository
+            .expect_get_items()
+            .return_once(move |_, _, _, _| Box::pin(async move { Ok(mock_items) }));
+        mock_repository
+            .expect_get_backorder_rules()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository.expect_get_bundles().return_once(|ids| {
+            assert_eq!(ids, vec![1, 2]);
+
+            Box::pin(async move {
+                Ok(vec![Bundle {
+                    item_id: 2,
+                    size: Size {
+                        id: 2,
+                        name: "M".to_string(),
+                    },
+                    components: vec![
+                        BundleComponent {
+                            item_id: 1,
+                            size: "S".to_string(),
+                            quantity: 3,
+                            available: 7,
+                        },
+                        BundleComponent {
+                            item_id: 3,
+                            size: "L".to_string(),
+                            quantity: 1,
+                            available: 100,
+                        },
+                    ],
+                }])
+            })
+        });
+        mock_repository
+            .expect_get_items_media()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repository
+            .expect_get_effective_prices()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+
+        let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
+        let res = core_service.get_items(None, None, 0, 10).await.unwrap();
+
+        assert_eq!(res.items[1].sizes[0].1, 2);
+        assert_eq!(res.items[1].availability, Some(Availability::InStock));
+    }
+
+    #[tokio::test]
+    async fn set_bundle_should_reject_invalid_components() {
+        let core_service = CoreServiceImpl::new(Arc::new(MockRepository::new()));
+        let component = |item_id, quantity| BundleComponent {
+            item_id,
+            size: "M".to_string(),
+            quantity,
+            available: 0,
+        };
+
+        for components in [
+            vec![component(1, 1)],
+            vec![component(2, 0)],
+            vec![component(2, 1), component(2, 3)],
+        ] {
+            let res = core_service
+                .set_bundle(1, "M".to_string(), components)
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(res.code, CommonErrorKind::InvalidInput);
+        }
+    }
+
     #[tokio::test]
     async fn create_item_should_create_item() {
         let mut mock_repository = MockRepository::new();
@@ -266,7 +1020,17 @@ mod core_service_tests {
             name: "Q".to_string(),
             description: "S".to_string(),
             price: 100.0,
+            category: None,
             sizes: vec![],
+            media: vec![],
+            sale_price: None,
+            promotion_id: None,
+            size_sale_prices: vec![],
+            currency: None,
+            tax: None,
+            tax_class_id: None,
+            availability: None,
+            expected_ship_date: None,
         };
 
         let item_backup = item.clone();
@@ -294,7 +1058,17 @@ mod core_service_tests {
             name: "Q".to_string(),
             description: "S".to_string(),
             price: 100.0,
+            category: None,
             sizes: vec![],
+            media: vec![],
+            sale_price: None,
+            promotion_id: None,
+            size_sale_prices: vec![],
+            currency: None,
+            tax: None,
+            tax_class_id: None,
+            availability: None,
+            expected_ship_date: None,
         };
 
         let item_backup = item.clone();
@@ -312,4 +1086,124 @@ mod core_service_tests {
 
         assert_eq!(res, CommonError{ message: "x".to_string(), code: CommonErrorKind::Unknown });
     }
+
+    fn cart_line(item_id: i64, size: &str, quantity: i32, expected_price: f64) -> CartLine {
+        CartLine {
+            item_id,
+            size: size.to_string(),
+            quantity,
+            expected_price,
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_cart_should_report_each_line() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_get_item_availability()
+            .times(1)
+            .return_once(|ids| {
+                assert_eq!(ids, vec![1, 2, 3]);
+
+                Box::pin(async move {
+                    Ok(vec![
+                        ItemAvailability {
+                            item_id: 1,
+                            sale_price: 9.99,
+                            sizes: vec![("S".to_string(), 5), ("M".to_string(), 1)],
+                            size_sale_prices: vec![],
+                        },
+                        ItemAvailability {
+                            item_id: 2,
+                            sale_price: 20.0,
+                            sizes: vec![("M".to_string(), 3)],
+                            size_sale_prices: vec![],
+                        },
+                    ])
+                })
+            });
+
+        let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
+
+        let res = core_service
+            .validate_cart(vec![
+                cart_line(1, "S", 5, 9.99),
+                cart_line(1, "M", 2, 9.99),
+                cart_line(2, "M", 1, 25.0),
+                cart_line(2, "XL", 1, 20.0),
+                cart_line(3, "S", 1, 1.0),
+                cart_line(1, "S", 1, 9.990000001),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.into_iter().map(|r| r.status).collect::<Vec<_>>(),
+            vec![
+                CartLineStatus::Ok,
+                CartLineStatus::InsufficientStock { available: 1 },
+                CartLineStatus::PriceChanged { current_price: 20.0 },
+                CartLineStatus::ItemMissing,
+                CartLineStatus::ItemMissing,
+                CartLineStatus::Ok,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_cart_should_price_each_size() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_get_item_availability()
+            .times(1)
+            .return_once(|_| {
+                Box::pin(async move {
+                    Ok(vec![ItemAvailability {
+                        item_id: 1,
+                        sale_price: 20.0,
+                        sizes: vec![("S".to_string(), 5), ("M".to_string(), 5)],
+                        size_sale_prices: vec![("S".to_string(), 15.0)],
+                    }])
+                })
+            });
+
+        let core_service = CoreServiceImpl::new(Arc::new(mock_repository));
+
+        let res = core_service
+            .validate_cart(vec![
+                cart_line(1, "S", 1, 15.0),
+                cart_line(1, "M", 1, 20.0),
+                cart_line(1, "M", 1, 15.0),
+                cart_line(1, "S", 1, 20.0),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.into_iter().map(|r| r.status).collect::<Vec<_>>(),
+            vec![
+                CartLineStatus::Ok,
+                CartLineStatus::Ok,
+                CartLineStatus::PriceChanged { current_price: 20.0 },
+                CartLineStatus::PriceChanged { current_price: 15.0 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_cart_should_reject_invalid_lines() {
+        let core_service = CoreServiceImpl::new(Arc::new(MockRepository::new()));
+
+        for lines in [
+            vec![],
+            vec![cart_line(1, "S", 0, 1.0)],
+            vec![cart_line(1, "S", 1, -1.0)],
+        ] {
+            let res = core_service.validate_cart(lines).await.err().unwrap();
+
+            assert_eq!(res.code, CommonErrorKind::InvalidInput);
+        }
+    }
 }
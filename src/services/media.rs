@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::warn;
+
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::media::{ItemMedia, MediaUpload, NewItemMedia};
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::media::MediaService;
+use crate::domain::storages::media::MediaStorage;
+
+#[derive(Clone)]
+pub struct MediaServiceImpl {
+    pub repository: Arc<dyn Repository>,
+    pub storage: Arc<dyn MediaStorage>,
+}
+
+impl MediaServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>, storage: Arc<dyn MediaStorage>) -> Self {
+        MediaServiceImpl {
+            repository,
+            storage,
+        }
+    }
+}
+
+fn extension_for(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "image/jpeg" => Some("jpg"),
+        "image/png" => Some("png"),
+        "image/gif" => Some("gif"),
+        "image/webp" => Some("webp"),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl MediaService for MediaServiceImpl {
+    async fn upload_item_media(&self, upload: MediaUpload) -> Result<ItemMedia, CommonError> {
+        let extension = extension_for(&upload.content_type).ok_or_else(|| CommonError {
+            message: format!("unsupported content type: {}", upload.content_type),
+            code: CommonErrorKind::InvalidInput,
+        })?;
+
+        let dimensions = imagesize::blob_size(&upload.content).map_err(|e| CommonError {
+            message: format!("can't read image dimensions: {}", e),
+            code: CommonErrorKind::InvalidInput,
+        })?;
+
+        let key = format!(
+            "items/{}/{}.{}",
+            upload.item_id,
+            uuid::Uuid::new_v4(),
+            extension
+        );
+        let url = self.storage.save(key.clone(), upload.content).await?;
+
+        let created = self
+            .repository
+            .create_item_media(NewItemMedia {
+                item_id: upload.item_id,
+                url,
+                path: Some(key.clone()),
+                alt_text: upload.alt_text,
+                position: upload.position,
+                width: i32::try_from(dimensions.width).ok(),
+                height: i32::try_from(dimensions.height).ok(),
+            })
+            .await;
+
+        match created {
+            Ok(media) => Ok(media),
+            Err(err) => {
+                if let Err(e) = self.storage.delete(key.clone()).await {
+                    warn!("failed to remove orphaned media {}: {}", key, e.message);
+                }
+                Err(err.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod media_service_tests {
+    use super::*;
+    use crate::domain::error::{RepositoryError, RepositoryErrorKind};
+    use crate::domain::repositories::items::MockRepository;
+    use crate::domain::storages::media::MockMediaStorage;
+
+    // 1x1 transparent PNG.
+    const PNG: [u8; 67] = [
+        0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f,
+        0x15, 0xc4, 0x89, 0x00, 0x00, 0x00, 0x0a, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x00,
+        0x01, 0x00, 0x00, 0x05, 0x00, 0x01, 0x0d, 0x0a, 0x2d, 0xb4, 0x00, 0x00, 0x00, 0x00, 0x49,
+        0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+    ];
+
+    fn upload() -> MediaUpload {
+        MediaUpload {
+            item_id: 1,
+            content_type: "image/png".to_string(),
+            content: PNG.to_vec(),
+            alt_text: "front".to_string(),
+            position: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn upload_item_media_should_store_file_and_metadata() {
+        let mut mock_repository = MockRepository::new();
+        let mut mock_storage = MockMediaStorage::new();
+
+        mock_storage.expect_save().return_once(|key, content| {
+            assert!(key.starts_with("items/1/"));
+            assert!(key.ends_with(".png"));
+            assert_eq!(content, PNG.to_vec());
+
+            Box::pin(async move { Ok(format!("/media/{}", key)) })
+        });
+
+        mock_repository
+            .expect_create_item_media()
+            .return_once(|media| {
+                assert_eq!(media.item_id, 1);
+                assert_eq!(media.url, format!("/media/{}", media.path.clone().unwrap()));
+                assert_eq!(media.alt_text, "front");
+                assert_eq!(media.position, None);
+                assert_eq!(media.width, Some(1));
+                assert_eq!(media.height, Some(1));
+
+                Box::pin(async move {
+                    Ok(ItemMedia {
+                        id: 7,
+                        item_id: media.item_id,
+                        url: media.url,
+                        path: media.path,
+                        alt_text: media.alt_text,
+                        position: 0,
+                        width: media.width,
+                        height: media.height,
+                    })
+                })
+            });
+
+        let media_service = MediaServiceImpl::new(Arc::new(mock_repository), Arc::new(mock_storage));
+        let res = media_service.upload_item_media(upload()).await.unwrap();
+
+        assert_eq!(res.id, 7);
+        assert_eq!(res.position, 0);
+    }
+
+    #[tokio::test]
+    async fn upload_item_media_should_reject_unsupported_content() {
+        let media_service = MediaServiceImpl::new(
+            Arc::new(MockRepository::new()),
+            Arc::new(MockMediaStorage::new()),
+        );
+
+        let err = media_service
+            .upload_item_media(MediaUpload {
+                content_type: "text/plain".to_string(),
+                ..upload()
+            })
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code, CommonErrorKind::InvalidInput);
+
+        let err = media_service
+            .upload_item_media(MediaUpload {
+                content: vec![1, 2, 3],
+                ..upload()
+            })
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code, CommonErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn upload_item_media_should_remove_file_when_item_is_missing() {
+        let mut mock_repository = MockRepository::new();
+        let mut mock_storage = MockMediaStorage::new();
+
+        mock_storage
+            .expect_save()
+            .return_once(|key, _| Box::pin(async move { Ok(format!("/media/{}", key)) }));
+        mock_storage
+            .expect_delete()
+            .times(1)
+            .return_once(|_| Box::pin(async move { Ok(()) }));
+        mock_repository.expect_create_item_media().return_once(|_| {
+            Box::pin(async move {
+                Err(RepositoryError {
+                    message: "x".to_string(),
+                    code: RepositoryErrorKind::NotFound,
+                })
+            })
+        });
+
+        let media_service = MediaServiceImpl::new(Arc::new(mock_repository), Arc::new(mock_storage));
+        let err = media_service.upload_item_media(upload()).await.err().unwrap();
+
+        assert_eq!(
+            err,
+            CommonError {
+                message: "x".to_string(),
+                code: CommonErrorKind::NotFound
+            }
+        );
+    }
+}
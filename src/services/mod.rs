@@ -1 +1,10 @@
+pub mod currencies;
+pub mod events;
+pub mod health;
 pub mod items;
+pub mod media;
+pub mod prices;
+pub mod promotions;
+pub mod taxes;
+pub mod warehouses;
+pub mod webhooks;
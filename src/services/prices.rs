@@ -0,0 +1,245 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::{error, info};
+
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::prices::{ItemPrices, PriceChange, PriceRecord};
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::prices::PriceService;
+
+#[derive(Clone)]
+pub struct PriceServiceImpl {
+    pub repository: Arc<dyn Repository>,
+}
+
+impl PriceServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>) -> Self {
+        PriceServiceImpl { repository }
+    }
+}
+
+#[async_trait]
+impl PriceService for PriceServiceImpl {
+    async fn get_item_prices(&self, item_id: i64) -> Result<ItemPrices, CommonError> {
+        let history = self.repository.get_price_history(item_id).await?;
+        let scheduled = self.repository.get_scheduled_prices(item_id).await?;
+
+        Ok(ItemPrices {
+            item_id,
+            history,
+            scheduled,
+        })
+    }
+
+    async fn set_item_price(
+        &self,
+        item_id: i64,
+        price: f64,
+        starts_at: Option<DateTime<Utc>>,
+    ) -> Result<PriceChange, CommonError> {
+        if !price.is_finite() || price < 0.0 {
+            return Err(CommonError {
+                message: format!("invalid price: {}", price),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        let now = Utc::now();
+        match starts_at {
+            Some(starts_at) if starts_at > now => Ok(PriceChange::Scheduled(
+                self.repository
+                    .schedule_item_price(item_id, price, starts_at)
+                    .await?,
+            )),
+            _ => Ok(PriceChange::Applied(
+                self.repository.change_item_price(item_id, price, now).await?,
+            )),
+        }
+    }
+
+    async fn cancel_scheduled_price(
+        &self,
+        item_id: i64,
+        schedule_id: i64,
+    ) -> Result<(), CommonError> {
+        self.repository
+            .cancel_scheduled_price(item_id, schedule_id)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn apply_due_prices(&self) -> Result<Vec<PriceRecord>, CommonError> {
+        Ok(self.repository.apply_due_prices(Utc::now()).await?)
+    }
+}
+
+/// Periodically applies scheduled prices whose start time has come.
+pub fn spawn_price_scheduler(
+    price_service: Arc<dyn PriceService>,
+    period: Duration,
+) -> actix_web::rt::task::JoinHandle<()> {
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(period);
+        loop {
+            interval.tick().await;
+
+            match price_service.apply_due_prices().await {
+                Ok(applied) => {
+                    for record in applied {
+                        info!(
+                            "applied scheduled price {} to item {}",
+                            record.price, record.item_id
+                        );
+                    }
+                }
+                Err(e) => error!("failed to apply scheduled prices: {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod price_service_tests {
+    use super::*;
+    use crate::domain::error::{RepositoryError, RepositoryErrorKind};
+    use crate::domain::models::prices::ScheduledPrice;
+    use crate::domain::repositories::items::MockRepository;
+
+    fn record(price: f64, valid_from: DateTime<Utc>) -> PriceRecord {
+        PriceRecord {
+            id: 1,
+            item_id: 1,
+            price,
+            valid_from,
+            valid_to: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn set_item_price_should_apply_immediately_without_start() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_change_item_price()
+            .return_once(|item_id, price, valid_from| {
+                assert_eq!(item_id, 1);
+                assert_eq!(price, 10.0);
+
+                Box::pin(async move { Ok(record(price, valid_from)) })
+            });
+
+        let price_service = PriceServiceImpl::new(Arc::new(mock_repository));
+        let res = price_service.set_item_price(1, 10.0, None).await.unwrap();
+
+        assert!(matches!(res, PriceChange::Applied(r) if r.price == 10.0));
+    }
+
+    #[tokio::test]
+    async fn set_item_price_should_apply_immediately_when_start_passed() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_change_item_price()
+            .return_once(|_, price, valid_from| {
+                Box::pin(async move { Ok(record(price, valid_from)) })
+            });
+
+        let price_service = PriceServiceImpl::new(Arc::new(mock_repository));
+        let res = price_service
+            .set_item_price(1, 10.0, Some(Utc::now() - chrono::Duration::hours(1)))
+            .await
+            .unwrap();
+
+        assert!(matches!(res, PriceChange::Applied(_)));
+    }
+
+    #[tokio::test]
+    async fn set_item_price_should_schedule_future_price() {
+        let mut mock_repository = MockRepository::new();
+        let starts_at = Utc::now() + chrono::Duration::days(1);
+
+        mock_repository
+            .expect_schedule_item_price()
+            .return_once(move |item_id, price, at| {
+                assert_eq!(item_id, 1);
+                assert_eq!(price, 5.0);
+                assert_eq!(at, starts_at);
+
+                Box::pin(async move {
+                    Ok(ScheduledPrice {
+                        id: 3,
+                        item_id,
+                        price,
+                        starts_at: at,
+                        applied_at: None,
+                    })
+                })
+            });
+
+        let price_service = PriceServiceImpl::new(Arc::new(mock_repository));
+        let res = price_service
+            .set_item_price(1, 5.0, Some(starts_at))
+            .await
+            .unwrap();
+
+        assert!(matches!(res, PriceChange::Scheduled(s) if s.id == 3));
+    }
+
+    #[tokio::test]
+    async fn set_item_price_should_reject_negative_price() {
+        let price_service = PriceServiceImpl::new(Arc::new(MockRepository::new()));
+
+        for price in [-1.0, f64::NAN, f64::INFINITY] {
+            let err = price_service.set_item_price(1, price, None).await.err().unwrap();
+            assert_eq!(err.code, CommonErrorKind::InvalidInput);
+        }
+    }
+
+    #[tokio::test]
+    async fn get_item_prices_should_return_history_and_schedule() {
+        let mut mock_repository = MockRepository::new();
+        let now = Utc::now();
+
+        mock_repository
+            .expect_get_price_history()
+            .return_once(move |_| Box::pin(async move { Ok(vec![record(1.0, now)]) }));
+        mock_repository
+            .expect_get_scheduled_prices()
+            .return_once(|_| Box::pin(async move { Ok(vec![]) }));
+
+        let price_service = PriceServiceImpl::new(Arc::new(mock_repository));
+        let res = price_service.get_item_prices(1).await.unwrap();
+
+        assert_eq!(
+            res,
+            ItemPrices {
+                item_id: 1,
+                history: vec![record(1.0, now)],
+                scheduled: vec![],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn get_item_prices_should_fail_for_missing_item() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository.expect_get_price_history().return_once(|_| {
+            Box::pin(async move {
+                Err(RepositoryError {
+                    message: "x".to_string(),
+                    code: RepositoryErrorKind::NotFound,
+                })
+            })
+        });
+
+        let price_service = PriceServiceImpl::new(Arc::new(mock_repository));
+        let err = price_service.get_item_prices(1).await.err().unwrap();
+
+        assert_eq!(err.code, CommonErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::promotions::{DiscountKind, Promotion};
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::promotions::PromotionService;
+
+#[derive(Clone)]
+pub struct PromotionServiceImpl {
+    pub repository: Arc<dyn Repository>,
+}
+
+impl PromotionServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>) -> Self {
+        PromotionServiceImpl { repository }
+    }
+}
+
+fn validate(promotion: &Promotion) -> Result<(), String> {
+    if promotion.name.trim().is_empty() {
+        return Err("promotion name must not be empty".to_string());
+    }
+
+    if !promotion.value.is_finite() || promotion.value < 0.0 {
+        return Err(format!("invalid discount value: {}", promotion.value));
+    }
+
+    if promotion.kind == DiscountKind::Percentage && promotion.value > 100.0 {
+        return Err(format!(
+            "percentage discount can't exceed 100, got {}",
+            promotion.value
+        ));
+    }
+
+    if let (Some(starts_at), Some(ends_at)) = (promotion.starts_at, promotion.ends_at) {
+        if starts_at >= ends_at {
+            return Err("promotion must start before it ends".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl PromotionService for PromotionServiceImpl {
+    async fn get_promotions(&self) -> Result<Vec<Promotion>, CommonError> {
+        Ok(self.repository.get_promotions().await?)
+    }
+
+    async fn create_promotion(&self, promotion: Promotion) -> Result<Promotion, CommonError> {
+        validate(&promotion).map_err(|message| CommonError {
+            message,
+            code: CommonErrorKind::InvalidInput,
+        })?;
+
+        Ok(self.repository.create_promotion(promotion).await?)
+    }
+
+    async fn delete_promotion(&self, promotion_id: i64) -> Result<(), CommonError> {
+        self.repository.delete_promotion(promotion_id).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod promotion_service_tests {
+    use super::*;
+    use crate::domain::models::promotions::PromotionTarget;
+    use crate::domain::repositories::items::MockRepository;
+    use chrono::{Duration, Utc};
+
+    fn promotion() -> Promotion {
+        Promotion {
+            id: 0,
+            name: "Black Friday".to_string(),
+            kind: DiscountKind::Percentage,
+            value: 20.0,
+            target: PromotionTarget::Category("shoes".to_string()),
+            starts_at: None,
+            ends_at: None,
+            priority: 1,
+            active: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_promotion_should_create_valid_promotion() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository.expect_create_promotion().return_once(|p| {
+            assert_eq!(p, promotion());
+
+            Box::pin(async move { Ok(Promotion { id: 5, ..p }) })
+        });
+
+        let promotion_service = PromotionServiceImpl::new(Arc::new(mock_repository));
+        let res = promotion_service.create_promotion(promotion()).await.unwrap();
+
+        assert_eq!(res.id, 5);
+    }
+
+    #[tokio::test]
+    async fn create_promotion_should_reject_invalid_promotions() {
+        let now = Utc::now();
+        let invalid = vec![
+            Promotion {
+                name: " ".to_string(),
+                ..promotion()
+            },
+            Promotion {
+                value: 120.0,
+                ..promotion()
+            },
+            Promotion {
+                kind: DiscountKind::Fixed,
+                value: -1.0,
+                ..promotion()
+            },
+            Promotion {
+                starts_at: Some(now),
+                ends_at: Some(now - Duration::days(1)),
+                ..promotion()
+            },
+        ];
+
+        let promotion_service = PromotionServiceImpl::new(Arc::new(MockRepository::new()));
+
+        for p in invalid {
+            let err = promotion_service.create_promotion(p).await.err().unwrap();
+            assert_eq!(err.code, CommonErrorKind::InvalidInput);
+        }
+    }
+
+    #[tokio::test]
+    async fn create_promotion_should_allow_large_fixed_discount() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_create_promotion()
+            .return_once(|p| Box::pin(async move { Ok(p) }));
+
+        let promotion_service = PromotionServiceImpl::new(Arc::new(mock_repository));
+        let res = promotion_service
+            .create_promotion(Promotion {
+                kind: DiscountKind::Fixed,
+                value: 500.0,
+                ..promotion()
+            })
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
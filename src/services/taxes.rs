@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::taxes::{ItemTax, TaxClass, TaxRate};
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::taxes::TaxService;
+
+#[derive(Clone)]
+pub struct TaxServiceImpl {
+    pub repository: Arc<dyn Repository>,
+}
+
+impl TaxServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>) -> Self {
+        TaxServiceImpl { repository }
+    }
+}
+
+/// Validates a region code (`DE`, `US-CA`, ...) and brings it to upper case.
+pub fn normalize_region(region: &str) -> Result<String, CommonError> {
+    let region = region.trim();
+
+    if region.len() < 2
+        || region.len() > 8
+        || !region.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(CommonError {
+            message: format!("invalid region code: {:?}", region),
+            code: CommonErrorKind::InvalidInput,
+        });
+    }
+
+    Ok(region.to_ascii_uppercase())
+}
+
+/// Splits a net price into tax and gross amounts, rounding the tax to cents.
+pub fn calculate_tax(region: String, rate: f64, net: f64) -> ItemTax {
+    let tax = (net * rate / 100.0 * 100.0).round() / 100.0;
+
+    ItemTax {
+        region,
+        rate,
+        net,
+        tax,
+        gross: net + tax,
+    }
+}
+
+#[async_trait]
+impl TaxService for TaxServiceImpl {
+    async fn get_tax_classes(&self) -> Result<Vec<TaxClass>, CommonError> {
+        Ok(self.repository.get_tax_classes().await?)
+    }
+
+    async fn create_tax_class(&self, name: String) -> Result<TaxClass, CommonError> {
+        let name = name.trim().to_string();
+
+        if name.is_empty() {
+            return Err(CommonError {
+                message: "tax class name must not be empty".to_string(),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(self.repository.create_tax_class(name).await?)
+    }
+
+    async fn set_tax_rate(&self, rate: TaxRate) -> Result<TaxRate, CommonError> {
+        let region = normalize_region(&rate.region)?;
+
+        if !rate.rate.is_finite() || !(0.0..=100.0).contains(&rate.rate) {
+            return Err(CommonError {
+                message: format!("tax rate must be a percentage, got {}", rate.rate),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(self.repository.set_tax_rate(TaxRate { region, ..rate }).await?)
+    }
+
+    async fn delete_tax_rate(&self, tax_class_id: i32, region: String) -> Result<(), CommonError> {
+        let region = normalize_region(&region)?;
+        self.repository.delete_tax_rate(tax_class_id, region).await?;
+
+        Ok(())
+    }
+
+    async fn set_item_tax_class(&self, item_id: i64, tax_class_id: Option<i32>) -> Result<(), CommonError> {
+        self.repository.set_item_tax_class(item_id, tax_class_id).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tax_service_tests {
+    use super::*;
+    use crate::domain::repositories::items::MockRepository;
+
+    #[test]
+    fn calculate_tax_should_round_tax_to_cents() {
+        let tax = calculate_tax("DE".to_string(), 19.0, 9.99);
+
+        assert_eq!(tax.net, 9.99);
+        assert_eq!(tax.tax, 1.9);
+        assert_eq!(tax.gross, 11.89);
+    }
+
+    #[test]
+    fn calculate_tax_should_keep_untaxed_price() {
+        let tax = calculate_tax("US".to_string(), 0.0, 100.0);
+
+        assert_eq!(tax.tax, 0.0);
+        assert_eq!(tax.gross, 100.0);
+    }
+
+    #[test]
+    fn normalize_region_should_validate_codes() {
+        assert_eq!(normalize_region("us-ca").unwrap(), "US-CA");
+        assert!(normalize_region("D").is_err());
+        assert!(normalize_region("DE DE").is_err());
+    }
+
+    #[tokio::test]
+    async fn set_tax_rate_should_store_normalized_region() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository.expect_set_tax_rate().return_once(|rate| {
+            assert_eq!(rate.region, "DE");
+
+            Box::pin(async move { Ok(rate) })
+        });
+
+        let tax_service = TaxServiceImpl::new(Arc::new(mock_repository));
+        let res = tax_service
+            .set_tax_rate(TaxRate {
+                tax_class_id: 1,
+                region: "de".to_string(),
+                rate: 19.0,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(res.rate, 19.0);
+    }
+
+    #[tokio::test]
+    async fn set_tax_rate_should_reject_invalid_rate() {
+        let tax_service = TaxServiceImpl::new(Arc::new(MockRepository::new()));
+
+        for rate in [-1.0, 101.0, f64::INFINITY] {
+            let res = tax_service
+                .set_tax_rate(TaxRate {
+                    tax_class_id: 1,
+                    region: "DE".to_string(),
+                    rate,
+                })
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(res.code, CommonErrorKind::InvalidInput);
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::error::{CommonError, CommonErrorKind};
+use crate::domain::models::backorders::{BackorderPolicy, BackorderRule};
+use crate::domain::models::warehouses::{
+    LowStock, MovementMeta, StockLevel, StockMovement, StockOperation, StockThreshold, Warehouse,
+};
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::warehouses::WarehouseService;
+
+#[derive(Clone)]
+pub struct WarehouseServiceImpl {
+    pub repository: Arc<dyn Repository>,
+}
+
+impl WarehouseServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>) -> Self {
+        WarehouseServiceImpl { repository }
+    }
+}
+
+fn validate_meta(meta: &MovementMeta) -> Result<(), String> {
+    if meta.actor.trim().is_empty() {
+        return Err("stock changes need an actor".to_string());
+    }
+
+    Ok(())
+}
+
+fn validate(levels: &[StockLevel], meta: &MovementMeta) -> Result<(), String> {
+    validate_meta(meta)?;
+
+    let mut seen = HashSet::new();
+
+    for level in levels {
+        if level.size.trim().is_empty() {
+            return Err(format!("empty size for item {}", level.item_id));
+        }
+
+        if level.quantity < 0 {
+            return Err(format!(
+                "negative quantity for item {} size {}",
+                level.item_id, level.size
+            ));
+        }
+
+        if !seen.insert((level.item_id, level.size.as_str())) {
+            return Err(format!(
+                "duplicate stock level for item {} size {}",
+                level.item_id, level.size
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_rule(rule: &BackorderRule) -> Result<(), String> {
+    if rule.size.trim().is_empty() {
+        return Err(format!("empty size for item {}", rule.item_id));
+    }
+
+    if rule.limit.is_some_and(|limit| limit < 0) {
+        return Err(format!(
+            "negative backorder limit for item {} size {}",
+            rule.item_id, rule.size
+        ));
+    }
+
+    if rule.policy == BackorderPolicy::Preorder && rule.expected_date.is_none() {
+        return Err(format!(
+            "pre-order of item {} size {} needs an expected date",
+            rule.item_id, rule.size
+        ));
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl WarehouseService for WarehouseServiceImpl {
+    async fn get_warehouses(&self) -> Result<Vec<Warehouse>, CommonError> {
+        Ok(self.repository.get_warehouses().await?)
+    }
+
+    async fn create_warehouse(&self, name: String) -> Result<Warehouse, CommonError> {
+        let name = name.trim().to_string();
+
+        if name.is_empty() {
+            return Err(CommonError {
+                message: "warehouse name must not be empty".to_string(),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(self.repository.create_warehouse(name).await?)
+    }
+
+    async fn get_item_stock(&self, item_id: i64) -> Result<Vec<StockLevel>, CommonError> {
+        Ok(self.repository.get_item_stock(item_id).await?)
+    }
+
+    async fn set_stock_levels(
+        &self,
+        warehouse_id: i32,
+        levels: Vec<StockLevel>,
+        meta: MovementMeta,
+    ) -> Result<Vec<StockLevel>, CommonError> {
+        validate(&levels, &meta).map_err(|message| CommonError {
+            message,
+            code: CommonErrorKind::InvalidInput,
+        })?;
+
+        Ok(self
+            .repository
+            .set_stock_levels(warehouse_id, levels, meta)
+            .await?)
+    }
+
+    async fn adjust_stock(
+        &self,
+        item_id: i64,
+        size: String,
+        warehouse_id: Option<i32>,
+        operation: StockOperation,
+        meta: MovementMeta,
+    ) -> Result<StockLevel, CommonError> {
+        validate_meta(&meta)
+            .and_then(|_| match operation {
+                _ if size.trim().is_empty() => Err("size must not be empty".to_string()),
+                StockOperation::Set(quantity) if quantity < 0 => {
+                    Err(format!("negative quantity for size {}", size))
+                }
+                StockOperation::Delta(0) => Err("delta must not be zero".to_string()),
+                _ => Ok(()),
+            })
+            .map_err(|message| CommonError {
+                message,
+                code: CommonErrorKind::InvalidInput,
+            })?;
+
+        Ok(self
+            .repository
+            .adjust_stock(item_id, size, warehouse_id, operation, meta)
+            .await?)
+    }
+
+    async fn set_stock_threshold(
+        &self,
+        threshold: StockThreshold,
+    ) -> Result<StockThreshold, CommonError> {
+        if threshold.size.trim().is_empty() || threshold.threshold < 0 {
+            return Err(CommonError {
+                message: format!(
+                    "invalid threshold {} for item {} size '{}'",
+                    threshold.threshold, threshold.item_id, threshold.size
+                ),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(self.repository.set_stock_threshold(threshold).await?)
+    }
+
+    async fn delete_stock_threshold(&self, item_id: i64, size: String) -> Result<(), CommonError> {
+        Ok(self.repository.delete_stock_threshold(item_id, size).await?)
+    }
+
+    async fn get_low_stock(&self) -> Result<Vec<LowStock>, CommonError> {
+        Ok(self.repository.get_low_stock().await?)
+    }
+
+    async fn set_backorder_rule(&self, rule: BackorderRule) -> Result<BackorderRule, CommonError> {
+        validate_rule(&rule).map_err(|message| CommonError {
+            message,
+            code: CommonErrorKind::InvalidInput,
+        })?;
+
+        Ok(self.repository.set_backorder_rule(rule).await?)
+    }
+
+    async fn delete_backorder_rule(&self, item_id: i64, size: String) -> Result<(), CommonError> {
+        Ok(self.repository.delete_backorder_rule(item_id, size).await?)
+    }
+
+    async fn get_stock_movements(
+        &self,
+        item_id: i64,
+        size: String,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<StockMovement>, CommonError> {
+        if offset < 0 || limit <= 0 {
+            return Err(CommonError {
+                message: format!("invalid page: offset {}, limit {}", offset, limit),
+                code: CommonErrorKind::InvalidInput,
+            });
+        }
+
+        Ok(self
+            .repository
+            .get_stock_movements(item_id, size, offset, limit)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod warehouse_service_tests {
+    use super::*;
+    use crate::domain::error::{RepositoryError, RepositoryErrorKind};
+    use crate::domain::models::warehouses::MovementReason;
+    use crate::domain::repositories::items::MockRepository;
+
+    fn meta() -> MovementMeta {
+        MovementMeta {
+            reason: MovementReason::Adjustment,
+            actor: "alice".to_string(),
+            note: None,
+        }
+    }
+
+    fn level(size: &str, quantity: i32) -> StockLevel {
+        StockLevel {
+            warehouse_id: 0,
+            item_id: 1,
+            size: size.to_string(),
+            quantity,
+        }
+    }
+
+    #[tokio::test]
+    async fn set_stock_levels_should_pass_valid_levels() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_set_stock_levels()
+            .return_once(|warehouse_id, levels, m| {
+                assert_eq!(warehouse_id, 2);
+                assert_eq!(levels, vec![level("S", 3), level("M", 0)]);
+                assert_eq!(m, meta());
+
+                Box::pin(async move { Ok(levels) })
+            });
+
+        let warehouse_service = WarehouseServiceImpl::new(Arc::new(mock_repository));
+        let res = warehouse_service
+            .set_stock_levels(2, vec![level("S", 3), level("M", 0)], meta())
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn set_stock_levels_should_reject_invalid_levels() {
+        let warehouse_service = WarehouseServiceImpl::new(Arc::new(MockRepository::new()));
+
+        let anonymous = MovementMeta {
+            actor: " ".to_string(),
+            ..meta()
+        };
+
+        for (levels, meta) in [
+            (vec![level("S", -1)], meta()),
+            (vec![level(" ", 1)], meta()),
+            (vec![level("S", 1), level("S", 2)], meta()),
+            (vec![level("S", 1)], anonymous),
+        ] {
+            let res = warehouse_service
+                .set_stock_levels(1, levels, meta)
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(res.code, CommonErrorKind::InvalidInput);
+        }
+    }
+
+    #[tokio::test]
+    async fn adjust_stock_should_reject_invalid_operations() {
+        let warehouse_service = WarehouseServiceImpl::new(Arc::new(MockRepository::new()));
+
+        for (size, operation) in [
+            (" ", StockOperation::Set(1)),
+            ("M", StockOperation::Set(-1)),
+            ("M", StockOperation::Delta(0)),
+        ] {
+            let res = warehouse_service
+                .adjust_stock(1, size.to_string(), None, operation, meta())
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(res.code, CommonErrorKind::InvalidInput);
+        }
+    }
+
+    #[tokio::test]
+    async fn adjust_stock_should_report_conflicts() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_adjust_stock()
+            .return_once(|item_id, size, warehouse_id, operation, _| {
+                assert_eq!(item_id, 1);
+                assert_eq!(size, "M");
+                assert_eq!(warehouse_id, Some(2));
+                assert_eq!(operation, StockOperation::Delta(-5));
+
+                Box::pin(async move {
+                    Err(RepositoryError {
+                        message: "stock would drop to -3".to_string(),
+                        code: RepositoryErrorKind::Conflict,
+                    })
+                })
+            });
+
+        let warehouse_service = WarehouseServiceImpl::new(Arc::new(mock_repository));
+        let res = warehouse_service
+            .adjust_stock(1, "M".to_string(), Some(2), StockOperation::Delta(-5), meta())
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(res.code, CommonErrorKind::Conflict);
+    }
+
+    #[tokio::test]
+    async fn set_stock_threshold_should_reject_negative_thresholds() {
+        let warehouse_service = WarehouseServiceImpl::new(Arc::new(MockRepository::new()));
+
+        let res = warehouse_service
+            .set_stock_threshold(StockThreshold {
+                item_id: 1,
+                size: "M".to_string(),
+                threshold: -1,
+            })
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(res.code, CommonErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn set_backorder_rule_should_require_preorder_date() {
+        let warehouse_service = WarehouseServiceImpl::new(Arc::new(MockRepository::new()));
+
+        let res = warehouse_service
+            .set_backorder_rule(BackorderRule {
+                item_id: 1,
+                size: "M".to_string(),
+                policy: BackorderPolicy::Preorder,
+                limit: None,
+                expected_date: None,
+                backordered: 0,
+            })
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(res.code, CommonErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn set_backorder_rule_should_store_valid_rules() {
+        let mut mock_repository = MockRepository::new();
+
+        let rule = BackorderRule {
+            item_id: 1,
+            size: "M".to_string(),
+            policy: BackorderPolicy::Backorder,
+            limit: Some(10),
+            expected_date: None,
+            backordered: 0,
+        };
+        let want = BackorderRule {
+            backordered: 3,
+            ..rule.clone()
+        };
+        let stored = want.clone();
+
+        mock_repository
+            .expect_set_backorder_rule()
+            .times(1)
+            .return_once(move |r| {
+                assert_eq!(r.limit, Some(10));
+
+                Box::pin(async move { Ok(stored) })
+            });
+
+        let warehouse_service = WarehouseServiceImpl::new(Arc::new(mock_repository));
+        let res = warehouse_service.set_backorder_rule(rule).await.unwrap();
+
+        assert_eq!(res, want);
+    }
+}
@@ -0,0 +1,530 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use log::{error, warn};
+use sha2::Sha256;
+use url::{Host, Url};
+
+use crate::domain::constants::{
+    WEBHOOK_DELIVERY_HEADER, WEBHOOK_DISPATCH_BATCH_SIZE, WEBHOOK_DISPATCH_LEASE_SECS,
+    WEBHOOK_EVENT_HEADER, WEBHOOK_MAX_ATTEMPTS,
+    WEBHOOK_RETRY_BASE_SECS, WEBHOOK_RETRY_MAX_SECS, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+};
+use crate::domain::error::{CommonError, CommonErrorKind, NotifierError, NotifierErrorKind};
+use crate::domain::models::events::{OutboxEvent, ITEM_EVENT_TYPES};
+use crate::domain::models::webhooks::{
+    is_public_address, DeliveryAttempt, DeliveryStatus, DueDelivery, WebhookDelivery, WebhookRequest,
+    WebhookSubscription,
+};
+use crate::domain::notifiers::events::EventPublisher;
+use crate::domain::notifiers::stock::NotifierResult;
+use crate::domain::notifiers::webhooks::WebhookSender;
+use crate::domain::repositories::items::Repository;
+use crate::domain::services::webhooks::WebhookService;
+
+const SECRET_MIN_LEN: usize = 16;
+
+#[derive(Clone)]
+pub struct WebhookServiceImpl {
+    pub repository: Arc<dyn Repository>,
+    pub sender: Arc<dyn WebhookSender>,
+}
+
+impl WebhookServiceImpl {
+    pub fn new(repository: Arc<dyn Repository>, sender: Arc<dyn WebhookSender>) -> Self {
+        WebhookServiceImpl { repository, sender }
+    }
+
+    /// Sends one delivery and decides what happens to it next.
+    async fn attempt(&self, due: DueDelivery) -> DeliveryAttempt {
+        let now = Utc::now();
+        let body = due.delivery.payload.to_string();
+
+        let request = WebhookRequest {
+            url: due.url,
+            headers: vec![
+                (WEBHOOK_EVENT_HEADER.to_string(), due.delivery.event_type.clone()),
+                (WEBHOOK_DELIVERY_HEADER.to_string(), due.delivery.id.to_string()),
+                (WEBHOOK_TIMESTAMP_HEADER.to_string(), now.timestamp().to_string()),
+                (
+                    WEBHOOK_SIGNATURE_HEADER.to_string(),
+                    sign(&due.secret, now.timestamp(), &body),
+                ),
+            ],
+            body,
+        };
+
+        let (status_code, error) = match self.sender.send(request).await {
+            Ok(code) if (200..300).contains(&code) => (Some(code as i32), None),
+            Ok(code) => (Some(code as i32), Some(format!("receiver answered {}", code))),
+            Err(e) => (None, Some(e.message)),
+        };
+
+        let attempts = due.delivery.attempts + 1;
+        let status = match &error {
+            None => DeliveryStatus::Delivered,
+            Some(_) if attempts >= WEBHOOK_MAX_ATTEMPTS => DeliveryStatus::Dead,
+            Some(_) => DeliveryStatus::Pending,
+        };
+
+        if let Some(error) = &error {
+            warn!(
+                "webhook delivery {} failed on attempt {}: {}",
+                due.delivery.id, attempts, error
+            );
+        }
+
+        DeliveryAttempt {
+            status,
+            status_code,
+            error,
+            next_attempt_at: now + retry_delay(attempts),
+        }
+    }
+}
+
+/// Value of the signature header for `body` sent at `timestamp`.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("hmac takes keys of any size");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Wait after the given number of failed attempts.
+fn retry_delay(attempts: i32) -> chrono::Duration {
+    let factor = 2_i64.saturating_pow(attempts.saturating_sub(1).max(0) as u32);
+
+    chrono::Duration::seconds(
+        WEBHOOK_RETRY_BASE_SECS
+            .saturating_mul(factor)
+            .min(WEBHOOK_RETRY_MAX_SECS),
+    )
+}
+
+fn validate(subscription: &WebhookSubscription) -> Result<(), String> {
+    let url = match Url::parse(subscription.url.trim()) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => url,
+        _ => return Err(format!("webhook url must be http(s): '{}'", subscription.url)),
+    };
+
+    // Keeps subscriptions from reaching into the network the service runs in;
+    // names resolving there are refused by the sender.
+    let internal = match url.host() {
+        Some(Host::Ipv4(ip)) => !is_public_address(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => !is_public_address(IpAddr::V6(ip)),
+        Some(Host::Domain(domain)) => domain == "localhost" || domain.ends_with(".localhost"),
+        None => true,
+    };
+    if internal {
+        return Err(format!("webhook url must point to a public host: '{}'", subscription.url));
+    }
+
+    if let Some(event_type) = subscription
+        .event_types
+        .iter()
+        .find(|t| !ITEM_EVENT_TYPES.contains(&t.as_str()))
+    {
+        return Err(format!("unknown event type: {}", event_type));
+    }
+
+    if !subscription.secret.is_empty() && subscription.secret.len() < SECRET_MIN_LEN {
+        return Err(format!(
+            "webhook secret must have at least {} characters",
+            SECRET_MIN_LEN
+        ));
+    }
+
+    Ok(())
+}
+
+fn invalid(message: String) -> CommonError {
+    CommonError {
+        message,
+        code: CommonErrorKind::InvalidInput,
+    }
+}
+
+#[async_trait]
+impl WebhookService for WebhookServiceImpl {
+    async fn get_subscriptions(&self) -> Result<Vec<WebhookSubscription>, CommonError> {
+        Ok(self.repository.get_webhook_subscriptions().await?)
+    }
+
+    async fn get_subscription(&self, subscription_id: i64) -> Result<WebhookSubscription, CommonError> {
+        Ok(self.repository.get_webhook_subscription(subscription_id).await?)
+    }
+
+    async fn create_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, CommonError> {
+        validate(&subscription).map_err(invalid)?;
+
+        let mut subscription = subscription;
+        if subscription.secret.is_empty() {
+            subscription.secret = format!(
+                "{}{}",
+                uuid::Uuid::new_v4().simple(),
+                uuid::Uuid::new_v4().simple()
+            );
+        }
+
+        Ok(self.repository.create_webhook_subscription(subscription).await?)
+    }
+
+    async fn update_subscription(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, CommonError> {
+        validate(&subscription).map_err(invalid)?;
+
+        let mut subscription = subscription;
+        if subscription.secret.is_empty() {
+            subscription.secret = self
+                .repository
+                .get_webhook_subscription(subscription.id)
+                .await?
+                .secret;
+        }
+
+        Ok(self.repository.update_webhook_subscription(subscription).await?)
+    }
+
+    async fn delete_subscription(&self, subscription_id: i64) -> Result<(), CommonError> {
+        Ok(self.repository.delete_webhook_subscription(subscription_id).await?)
+    }
+
+    async fn get_deliveries(
+        &self,
+        subscription_id: i64,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, CommonError> {
+        if offset < 0 || limit <= 0 {
+            return Err(invalid(format!("invalid page: offset {}, limit {}", offset, limit)));
+        }
+
+        Ok(self
+            .repository
+            .get_webhook_deliveries(subscription_id, offset, limit)
+            .await?)
+    }
+
+    async fn retry_delivery(
+        &self,
+        subscription_id: i64,
+        delivery_id: i64,
+    ) -> Result<WebhookDelivery, CommonError> {
+        Ok(self
+            .repository
+            .retry_webhook_delivery(subscription_id, delivery_id, Utc::now())
+            .await?)
+    }
+
+    async fn dispatch_deliveries(&self) -> Result<usize, CommonError> {
+        // Leased, so replicas dispatching at the same time send each delivery
+        // once; recording the attempt replaces the lease.
+        let now = Utc::now();
+        let due = self
+            .repository
+            .claim_due_webhook_deliveries(
+                now,
+                now + chrono::Duration::seconds(WEBHOOK_DISPATCH_LEASE_SECS),
+                WEBHOOK_DISPATCH_BATCH_SIZE,
+            )
+            .await?;
+        let mut delivered = 0;
+
+        for due in due {
+            let delivery_id = due.delivery.id;
+            let attempt = self.attempt(due).await;
+
+            if attempt.status == DeliveryStatus::Delivered {
+                delivered += 1;
+            }
+
+            self.repository
+                .record_webhook_attempt(delivery_id, attempt)
+                .await?;
+        }
+
+        Ok(delivered)
+    }
+}
+
+/// Fans committed item events out to the matching subscriptions; the
+/// dispatcher sends them from there.
+#[async_trait]
+impl EventPublisher for WebhookServiceImpl {
+    async fn publish(&self, event: OutboxEvent) -> NotifierResult<()> {
+        let payload = serde_json::json!({
+            "id": event.id,
+            "type": event.event.event_type(),
+            "created_at": event.created_at,
+            "data": event.event,
+        });
+
+        self.repository
+            .enqueue_webhook_deliveries(event.id, event.event.event_type().to_string(), payload)
+            .await
+            .map_err(|e| NotifierError {
+                message: e.message,
+                code: NotifierErrorKind::Unknown,
+            })?;
+
+        Ok(())
+    }
+}
+
+/// Periodically sends due webhook deliveries.
+pub fn spawn_webhook_dispatcher(
+    webhook_service: Arc<dyn WebhookService>,
+    period: Duration,
+) -> actix_web::rt::task::JoinHandle<()> {
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(period);
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = webhook_service.dispatch_deliveries().await {
+                error!("failed to dispatch webhooks: {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod webhook_service_tests {
+    use super::*;
+    use crate::domain::models::events::ItemEvent;
+    use crate::domain::notifiers::webhooks::MockWebhookSender;
+    use crate::domain::repositories::items::MockRepository;
+    use mockall::predicate::eq;
+
+    fn subscription(event_types: Vec<&str>) -> WebhookSubscription {
+        WebhookSubscription {
+            id: 0,
+            url: "https://partner.example/hooks".to_string(),
+            secret: "".to_string(),
+            event_types: event_types.into_iter().map(|t| t.to_string()).collect(),
+            active: true,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn due(id: i64, attempts: i32) -> DueDelivery {
+        DueDelivery {
+            delivery: WebhookDelivery {
+                id,
+                subscription_id: 1,
+                event_id: 10,
+                event_type: "price_changed".to_string(),
+                payload: serde_json::json!({ "id": 10 }),
+                status: DeliveryStatus::Pending,
+                attempts,
+                next_attempt_at: Utc::now(),
+                last_status_code: None,
+                last_error: None,
+                created_at: Utc::now(),
+                delivered_at: None,
+            },
+            url: "https://partner.example/hooks".to_string(),
+            secret: "0123456789abcdef".to_string(),
+        }
+    }
+
+    #[test]
+    fn sign_should_hmac_timestamp_and_body() {
+        assert_eq!(
+            sign("0123456789abcdef", 1700000000, r#"{"a":1}"#),
+            "sha256=9eb18f493f8ec135d9eb2dad817c369bb4e9cbfa818657897a7437c1cd8c3a23"
+        );
+    }
+
+    #[test]
+    fn retry_delay_should_double_up_to_the_cap() {
+        assert_eq!(retry_delay(1).num_seconds(), WEBHOOK_RETRY_BASE_SECS);
+        assert_eq!(retry_delay(3).num_seconds(), WEBHOOK_RETRY_BASE_SECS * 4);
+        assert_eq!(retry_delay(40).num_seconds(), WEBHOOK_RETRY_MAX_SECS);
+    }
+
+    #[tokio::test]
+    async fn create_subscription_should_generate_secret() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_create_webhook_subscription()
+            .times(1)
+            .returning(|s| {
+                assert!(s.secret.len() >= SECRET_MIN_LEN);
+
+                Box::pin(async move { Ok(WebhookSubscription { id: 1, ..s }) })
+            });
+
+        let webhook_service =
+            WebhookServiceImpl::new(Arc::new(mock_repository), Arc::new(MockWebhookSender::new()));
+        let res = webhook_service
+            .create_subscription(subscription(vec!["stock_changed"]))
+            .await
+            .unwrap();
+
+        assert_eq!(res.id, 1);
+    }
+
+    #[tokio::test]
+    async fn create_subscription_should_reject_invalid_input() {
+        let webhook_service = WebhookServiceImpl::new(
+            Arc::new(MockRepository::new()),
+            Arc::new(MockWebhookSender::new()),
+        );
+
+        for subscription in [
+            subscription(vec!["item_exploded"]),
+            WebhookSubscription {
+                url: "ftp://partner.example".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                url: "https://".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                url: "http://localhost:8000/v1/admin/maintenance".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                url: "http://169.254.169.254/latest/meta-data".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                url: "http://10.0.0.5/hooks".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                url: "http://2130706433/hooks".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                url: "http://[::ffff:127.0.0.1]/hooks".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                url: "http://[fd00::1]/hooks".to_string(),
+                ..subscription(vec![])
+            },
+            WebhookSubscription {
+                secret: "short".to_string(),
+                ..subscription(vec![])
+            },
+        ] {
+            let res = webhook_service
+                .create_subscription(subscription)
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(res.code, CommonErrorKind::InvalidInput);
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_deliveries_should_sign_retry_and_dead_letter() {
+        let mut mock_repository = MockRepository::new();
+        let mut mock_sender = MockWebhookSender::new();
+
+        mock_repository
+            .expect_claim_due_webhook_deliveries()
+            .withf(|now, leased_until, limit| {
+                *leased_until == *now + chrono::Duration::seconds(WEBHOOK_DISPATCH_LEASE_SECS)
+                    && *limit == WEBHOOK_DISPATCH_BATCH_SIZE
+            })
+            .return_once(|_, _, _| {
+                Box::pin(async move {
+                    Ok(vec![due(1, 0), due(2, 0), due(3, WEBHOOK_MAX_ATTEMPTS - 1)])
+                })
+            });
+        mock_sender.expect_send().times(3).returning(|request| {
+            let header = |name: &str| {
+                request
+                    .headers
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, v)| v.clone())
+                    .unwrap()
+            };
+            let timestamp = header(WEBHOOK_TIMESTAMP_HEADER).parse::<i64>().unwrap();
+            assert_eq!(
+                header(WEBHOOK_SIGNATURE_HEADER),
+                sign("0123456789abcdef", timestamp, &request.body)
+            );
+
+            let code = match header(WEBHOOK_DELIVERY_HEADER).as_str() {
+                "1" => 204,
+                _ => 503,
+            };
+
+            Box::pin(async move { Ok(code) })
+        });
+        mock_repository
+            .expect_record_webhook_attempt()
+            .withf(|id, a| *id == 1 && a.status == DeliveryStatus::Delivered && a.error.is_none())
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_repository
+            .expect_record_webhook_attempt()
+            .withf(|id, a| {
+                *id == 2
+                    && a.status == DeliveryStatus::Pending
+                    && a.status_code == Some(503)
+                    && a.next_attempt_at > Utc::now()
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_repository
+            .expect_record_webhook_attempt()
+            .withf(|id, a| *id == 3 && a.status == DeliveryStatus::Dead)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let webhook_service =
+            WebhookServiceImpl::new(Arc::new(mock_repository), Arc::new(mock_sender));
+
+        assert_eq!(webhook_service.dispatch_deliveries().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn publish_should_enqueue_event_envelope() {
+        let mut mock_repository = MockRepository::new();
+
+        mock_repository
+            .expect_enqueue_webhook_deliveries()
+            .with(
+                eq(7),
+                eq("item_updated".to_string()),
+                mockall::predicate::function(|payload: &serde_json::Value| {
+                    payload["id"] == 7 && payload["data"]["item_id"] == 3
+                }),
+            )
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(2) }));
+
+        let webhook_service =
+            WebhookServiceImpl::new(Arc::new(mock_repository), Arc::new(MockWebhookSender::new()));
+
+        webhook_service
+            .publish(OutboxEvent {
+                id: 7,
+                event: ItemEvent::ItemUpdated { item_id: 3 },
+                created_at: Utc::now(),
+                attempts: 0,
+            })
+            .await
+            .unwrap();
+    }
+}
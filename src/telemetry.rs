@@ -0,0 +1,276 @@
+use crate::config::TracingConfig;
+use actix_web::http::header::HeaderMap;
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::future::BoxFuture;
+use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::{SpanId, Status, TraceContextExt, TraceError, TracerProvider as _};
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+use opentelemetry_sdk::runtime::TokioCurrentThread;
+use opentelemetry_sdk::trace::{self, Sampler, TracerProvider};
+use opentelemetry_sdk::Resource;
+use serde::Deserialize;
+use serde_json::{json, Map};
+use std::io::Write;
+use std::str::FromStr;
+use std::{fmt, io};
+use tracing::subscriber::NoSubscriber;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceExporter {
+    /// Spans are not recorded at all.
+    None,
+    /// One JSON object per span on stdout, for local use.
+    Stdout,
+    /// OTLP over HTTP to a collector.
+    Otlp,
+}
+
+impl FromStr for TraceExporter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(TraceExporter::None),
+            "stdout" => Ok(TraceExporter::Stdout),
+            "otlp" => Ok(TraceExporter::Otlp),
+            _ => Err(format!("unknown trace exporter {:?}", s)),
+        }
+    }
+}
+
+impl fmt::Display for TraceExporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TraceExporter::None => "none",
+            TraceExporter::Stdout => "stdout",
+            TraceExporter::Otlp => "otlp",
+        })
+    }
+}
+
+/// Routes the spans of the `tracing` macros to the configured exporter. Must
+/// run inside the runtime, the OTLP exporter spawns its batch task on it.
+pub fn init(config: &TracingConfig) -> Result<(), TraceError> {
+    let trace_config = trace::config()
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sample_ratio,
+        ))))
+        .with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            config.service_name.clone(),
+        )]));
+
+    let tracer = match config.exporter {
+        // Some dependencies enable `tracing/log`, which turns spans into log
+        // lines while no subscriber is set.
+        TraceExporter::None => return set_subscriber(NoSubscriber::default()),
+        TraceExporter::Stdout => {
+            let provider = TracerProvider::builder()
+                .with_simple_exporter(JsonSpanExporter::new(io::stdout()))
+                .with_config(trace_config)
+                .build();
+            let tracer = provider.tracer(env!("CARGO_PKG_NAME"));
+            global::set_tracer_provider(provider);
+            tracer
+        }
+        TraceExporter::Otlp => opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .http()
+                    .with_endpoint(config.otlp_endpoint.trim_end_matches('/')),
+            )
+            .with_trace_config(trace_config)
+            .install_batch(TokioCurrentThread)?,
+    };
+
+    set_subscriber(
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer)),
+    )
+}
+
+fn set_subscriber(subscriber: impl Subscriber + Send + Sync) -> Result<(), TraceError> {
+    tracing::subscriber::set_global_default(subscriber).map_err(|e| TraceError::from(e.to_string()))
+}
+
+/// Exports the spans still buffered; call once the server stopped.
+pub fn shutdown() {
+    global::shutdown_tracer_provider();
+}
+
+/// Trace id of the current span, so log lines can be matched with traces.
+pub fn current_trace_id() -> Option<String> {
+    let context = tracing::Span::current().context();
+    let span_context = context.span().span_context().clone();
+
+    span_context
+        .is_valid()
+        .then(|| span_context.trace_id().to_string())
+}
+
+/// Reads propagation headers, like `traceparent`, off a request.
+pub struct HeaderExtractor<'a>(pub &'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
+
+/// Writes every finished span as a JSON line.
+pub struct JsonSpanExporter<W> {
+    out: W,
+}
+
+impl<W: Write> JsonSpanExporter<W> {
+    pub fn new(out: W) -> Self {
+        JsonSpanExporter { out }
+    }
+}
+
+impl<W> fmt::Debug for JsonSpanExporter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("JsonSpanExporter")
+    }
+}
+
+impl<W: Write + Send + Sync> SpanExporter for JsonSpanExporter<W> {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+        let result = batch
+            .iter()
+            .try_for_each(|span| writeln!(self.out, "{}", span_to_json(span)))
+            .and_then(|_| self.out.flush())
+            .map_err(|e| TraceError::from(e.to_string()));
+
+        Box::pin(std::future::ready(result))
+    }
+}
+
+fn span_to_json(span: &SpanData) -> serde_json::Value {
+    let mut attributes = Map::new();
+    for kv in &span.attributes {
+        let value = match &kv.value {
+            opentelemetry::Value::Bool(v) => json!(v),
+            opentelemetry::Value::I64(v) => json!(v),
+            opentelemetry::Value::F64(v) => json!(v),
+            v => json!(v.to_string()),
+        };
+        attributes.insert(kv.key.to_string(), value);
+    }
+    let duration = span
+        .end_time
+        .duration_since(span.start_time)
+        .unwrap_or_default();
+
+    let mut entry = Map::new();
+    entry.insert("trace_id".to_string(), json!(span.span_context.trace_id().to_string()));
+    entry.insert("span_id".to_string(), json!(span.span_context.span_id().to_string()));
+    if span.parent_span_id != SpanId::INVALID {
+        entry.insert("parent_span_id".to_string(), json!(span.parent_span_id.to_string()));
+    }
+    entry.insert("name".to_string(), json!(span.name));
+    entry.insert("kind".to_string(), json!(format!("{:?}", span.span_kind).to_lowercase()));
+    entry.insert(
+        "start".to_string(),
+        json!(DateTime::<Utc>::from(span.start_time).to_rfc3339_opts(SecondsFormat::Micros, true)),
+    );
+    entry.insert("duration_ms".to_string(), json!(duration.as_secs_f64() * 1000.0));
+    match &span.status {
+        Status::Unset => {}
+        Status::Ok => {
+            entry.insert("status".to_string(), json!("ok"));
+        }
+        Status::Error { description } => {
+            entry.insert("status".to_string(), json!("error"));
+            entry.insert("status_message".to_string(), json!(description));
+        }
+    }
+    entry.insert("attributes".to_string(), serde_json::Value::Object(attributes));
+
+    serde_json::Value::Object(entry)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Collects exported spans so tests can inspect them.
+    #[derive(Clone, Default)]
+    pub struct SpanBuffer(pub Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SpanBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SpanBuffer {
+        pub fn spans(&self) -> Vec<serde_json::Value> {
+            String::from_utf8(self.0.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        }
+    }
+
+    /// A subscriber exporting into the returned buffer, for use with
+    /// `tracing::subscriber::set_default`.
+    pub fn buffered_subscriber() -> (impl tracing::Subscriber, TracerProvider, SpanBuffer) {
+        let buffer = SpanBuffer::default();
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(JsonSpanExporter::new(buffer.clone()))
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        (subscriber, provider, buffer)
+    }
+
+    #[test]
+    fn test_export_spans_as_json() {
+        let (subscriber, provider, buffer) = buffered_subscriber();
+
+        let trace_id = tracing::subscriber::with_default(subscriber, || {
+            let parent = tracing::info_span!("http.request", otel.name = "GET /v1/items");
+            let _entered = parent.enter();
+            tracing::info_span!(
+                "db.query",
+                db.operation = "get_items",
+                db.rows = 3i64,
+                otel.status_code = "ERROR"
+            )
+            .in_scope(|| {});
+
+            current_trace_id().unwrap()
+        });
+        provider.force_flush();
+
+        let spans = buffer.spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0]["name"], "db.query");
+        assert_eq!(spans[0]["trace_id"], trace_id);
+        assert_eq!(spans[0]["parent_span_id"], spans[1]["span_id"]);
+        assert_eq!(spans[0]["status"], "error");
+        assert_eq!(spans[0]["attributes"]["db.operation"], "get_items");
+        assert_eq!(spans[0]["attributes"]["db.rows"], 3);
+        assert_eq!(spans[1]["name"], "GET /v1/items");
+        assert!(spans[1].get("parent_span_id").is_none());
+        assert_eq!(current_trace_id(), None);
+    }
+}